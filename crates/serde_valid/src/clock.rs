@@ -0,0 +1,210 @@
+//! The current time of the time-relative validations.
+//!
+//! `#[validate(past)]`, `#[validate(future)]` and `#[validate(within = "30d")]`
+//! read the current time through [`now`], which is [`SystemClock`] by default.
+//! Tests can replace it with a [`FixedClock`] by [`with_clock`] on the current thread,
+//! or by [`set_clock`] on the whole process until its guard is dropped.
+//!
+//! [`with_clock`] is not seen by the other threads, like the ones spawned in `f`
+//! or the workers of an async runtime, which read the clock of [`set_clock`] instead.
+//! The clock of [`set_clock`] is seen by all the tests running in parallel in the process,
+//! except the ones in [`with_clock`].
+//!
+//! ```rust
+//! use std::time::{Duration, SystemTime};
+//!
+//! use serde_valid::clock::{with_clock, FixedClock};
+//! use serde_valid::Validate;
+//!
+//! #[derive(Validate)]
+//! struct Token {
+//!     #[validate(future)]
+//!     expires_at: SystemTime,
+//! }
+//!
+//! let now = SystemTime::UNIX_EPOCH + Duration::from_secs(1_700_000_000);
+//! let token = Token {
+//!     expires_at: now + Duration::from_secs(60),
+//! };
+//!
+//! with_clock(FixedClock::new(now), || assert!(token.validate().is_ok()));
+//! with_clock(FixedClock::new(now + Duration::from_secs(120)), || {
+//!     assert!(token.validate().is_err())
+//! });
+//! ```
+
+use std::cell::RefCell;
+use std::sync::{PoisonError, RwLock};
+use std::time::SystemTime;
+
+/// Source of the current time.
+pub trait Clock {
+    fn now(&self) -> SystemTime;
+}
+
+impl<F> Clock for F
+where
+    F: Fn() -> SystemTime,
+{
+    fn now(&self) -> SystemTime {
+        self()
+    }
+}
+
+/// Clock of [`SystemTime::now`].
+#[derive(Debug, Default, Clone, Copy)]
+pub struct SystemClock;
+
+impl Clock for SystemClock {
+    fn now(&self) -> SystemTime {
+        SystemTime::now()
+    }
+}
+
+/// Clock always returning the same instant.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct FixedClock(SystemTime);
+
+impl FixedClock {
+    pub fn new(now: SystemTime) -> Self {
+        Self(now)
+    }
+}
+
+impl Clock for FixedClock {
+    fn now(&self) -> SystemTime {
+        self.0
+    }
+}
+
+thread_local! {
+    static CLOCK: RefCell<Option<Box<dyn Clock>>> = const { RefCell::new(None) };
+}
+
+static PROCESS_CLOCK: RwLock<Option<Box<dyn Clock + Send + Sync>>> = RwLock::new(None);
+
+/// The current time of the clock of this thread by [`with_clock`],
+/// or the one of the process by [`set_clock`].
+pub fn now() -> SystemTime {
+    CLOCK
+        .with(|clock| clock.borrow().as_ref().map(|clock| clock.now()))
+        .or_else(|| {
+            PROCESS_CLOCK
+                .read()
+                .unwrap_or_else(PoisonError::into_inner)
+                .as_ref()
+                .map(|clock| clock.now())
+        })
+        .unwrap_or_else(SystemTime::now)
+}
+
+/// Read the current time from `clock` on all the threads of the process,
+/// except in [`with_clock`] which takes precedence on its thread.
+///
+/// The previous clock of the process is restored when the returned guard is dropped,
+/// also on panic, so the guards are dropped in the reverse order when nested.
+///
+/// ```rust
+/// use std::time::SystemTime;
+///
+/// use serde_valid::clock::{now, set_clock, FixedClock};
+///
+/// let clock = set_clock(FixedClock::new(SystemTime::UNIX_EPOCH));
+/// assert_eq!(
+///     std::thread::spawn(now).join().unwrap(),
+///     SystemTime::UNIX_EPOCH
+/// );
+///
+/// drop(clock);
+/// assert!(now() > SystemTime::UNIX_EPOCH);
+/// ```
+pub fn set_clock<C>(clock: C) -> ClockGuard
+where
+    C: Clock + Send + Sync + 'static,
+{
+    let previous = PROCESS_CLOCK
+        .write()
+        .unwrap_or_else(PoisonError::into_inner)
+        .replace(Box::new(clock));
+    ClockGuard(previous)
+}
+
+/// Guard of [`set_clock`], which restores the previous clock of the process when dropped.
+#[must_use = "the clock is restored when the guard is dropped"]
+pub struct ClockGuard(Option<Box<dyn Clock + Send + Sync>>);
+
+impl Drop for ClockGuard {
+    fn drop(&mut self) {
+        *PROCESS_CLOCK
+            .write()
+            .unwrap_or_else(PoisonError::into_inner) = self.0.take();
+    }
+}
+
+impl std::fmt::Debug for ClockGuard {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ClockGuard").finish_non_exhaustive()
+    }
+}
+
+/// Run `f` reading the current time from `clock` on this thread.
+///
+/// The previous clock is restored when `f` returns or panics,
+/// so the calls can be nested.
+/// The other threads do not see `clock`, where [`set_clock`] is used for the whole process.
+pub fn with_clock<C, F, R>(clock: C, f: F) -> R
+where
+    C: Clock + 'static,
+    F: FnOnce() -> R,
+{
+    struct Restore(Option<Box<dyn Clock>>);
+
+    impl Drop for Restore {
+        fn drop(&mut self) {
+            let previous = self.0.take();
+            CLOCK.with(|clock| *clock.borrow_mut() = previous);
+        }
+    }
+
+    let previous = CLOCK.with(|current| current.borrow_mut().replace(Box::new(clock)));
+    let _restore = Restore(previous);
+    f()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::time::Duration;
+
+    // the outer clock is the one of this thread, so that the clock of the process is not read.
+
+    #[test]
+    fn test_with_clock_is_restored() {
+        let outer = SystemTime::UNIX_EPOCH;
+        let first = SystemTime::UNIX_EPOCH + Duration::from_secs(1);
+        let second = SystemTime::UNIX_EPOCH + Duration::from_secs(2);
+
+        with_clock(FixedClock::new(outer), || {
+            with_clock(FixedClock::new(first), || {
+                assert_eq!(now(), first);
+                with_clock(move || second, || assert_eq!(now(), second));
+                assert_eq!(now(), first);
+            });
+            assert_eq!(now(), outer);
+        });
+    }
+
+    #[test]
+    fn test_with_clock_is_restored_after_panic() {
+        let outer = SystemTime::UNIX_EPOCH;
+        let fixed = SystemTime::UNIX_EPOCH + Duration::from_secs(1);
+
+        with_clock(FixedClock::new(outer), || {
+            let result =
+                std::panic::catch_unwind(|| with_clock(FixedClock::new(fixed), || panic!()));
+
+            assert!(result.is_err());
+            assert_eq!(now(), outer);
+        });
+    }
+}
@@ -0,0 +1,14 @@
+//! Layered configuration with the source of each value in the validation errors.
+//!
+//! [`ConfigLoader`] merges the layers in order, like `defaults.toml` overridden by `prod.yaml`
+//! overridden by the environment variables, and validates the merged result.
+//! Each validation error is annotated with the [`Provenance`] of the failing value,
+//! which is the file and the line, or the environment variable, that supplied it.
+
+mod error;
+mod loader;
+mod provenance;
+
+pub use error::{Error, SourcedError, SourcedErrors};
+pub use loader::ConfigLoader;
+pub use provenance::Provenance;
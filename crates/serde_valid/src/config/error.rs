@@ -0,0 +1,147 @@
+use super::provenance::ProvenanceMap;
+use super::Provenance;
+use crate::flatten::{FlatError, IntoFlat};
+use crate::format::DynFormatError;
+
+/// Error of loading the configuration.
+#[derive(Debug, thiserror::Error)]
+pub enum Error {
+    #[error("failed to read {}: {source}", path.display())]
+    Io {
+        path: std::path::PathBuf,
+        source: std::io::Error,
+    },
+
+    #[error("unknown format of {}", .0.display())]
+    UnknownFormat(std::path::PathBuf),
+
+    #[error("failed to parse {origin}: {source}")]
+    Parse {
+        origin: String,
+        source: DynFormatError,
+    },
+
+    #[error("error at {pointer}: {source}{}", fmt_provenance(provenance.as_ref()))]
+    DeserializeError {
+        /// [JSON Pointer](https://datatracker.ietf.org/doc/html/rfc6901) to the value.
+        pointer: String,
+        provenance: Option<Provenance>,
+        source: serde_json::Error,
+    },
+
+    #[error("{0}")]
+    ValidationError(Box<SourcedErrors>),
+}
+
+impl Error {
+    pub fn is_validation_errors(&self) -> bool {
+        matches!(self, Self::ValidationError(_))
+    }
+
+    pub fn as_validation_errors(&self) -> Option<&SourcedErrors> {
+        match self {
+            Self::ValidationError(errors) => Some(errors),
+            _ => None,
+        }
+    }
+}
+
+fn fmt_provenance(provenance: Option<&Provenance>) -> String {
+    match provenance {
+        Some(provenance) => format!("\n --> {provenance}"),
+        None => String::new(),
+    }
+}
+
+/// Validation error with the source of the failing value.
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize)]
+pub struct SourcedError {
+    /// [JSON Pointer](https://datatracker.ietf.org/doc/html/rfc6901) to the invalid value.
+    pub pointer: String,
+    pub message: String,
+    /// `None` if no layer supplied the value, such as the missing field.
+    pub provenance: Option<Provenance>,
+}
+
+/// Validation errors of the merged configuration with the sources of the values.
+///
+/// [`Display`](std::fmt::Display) renders each error with its source.
+///
+/// ```text
+/// error at /db/pool_size: The number must be `<= 100`.
+///  --> prod.yaml:3:14
+/// ```
+#[derive(Debug, Clone)]
+pub struct SourcedErrors {
+    errors: crate::validation::Errors,
+    sourced_errors: Vec<SourcedError>,
+}
+
+impl SourcedErrors {
+    pub(crate) fn new(errors: crate::validation::Errors, provenances: &ProvenanceMap) -> Self {
+        let sourced_errors = (&errors)
+            .into_flat()
+            .into_iter()
+            .map(|FlatError { pointer, error }| SourcedError {
+                provenance: provenances.get(&pointer).cloned(),
+                pointer,
+                message: error.to_string(),
+            })
+            .collect();
+
+        Self {
+            errors,
+            sourced_errors,
+        }
+    }
+
+    pub fn errors(&self) -> &crate::validation::Errors {
+        &self.errors
+    }
+
+    pub fn into_errors(self) -> crate::validation::Errors {
+        self.errors
+    }
+
+    pub fn len(&self) -> usize {
+        self.sourced_errors.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.sourced_errors.is_empty()
+    }
+
+    pub fn iter(&self) -> std::slice::Iter<'_, SourcedError> {
+        self.sourced_errors.iter()
+    }
+}
+
+impl<'a> IntoIterator for &'a SourcedErrors {
+    type Item = &'a SourcedError;
+    type IntoIter = std::slice::Iter<'a, SourcedError>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.sourced_errors.iter()
+    }
+}
+
+impl std::fmt::Display for SourcedErrors {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for (index, error) in self.sourced_errors.iter().enumerate() {
+            if index != 0 {
+                writeln!(f)?;
+            }
+            if error.pointer.is_empty() {
+                writeln!(f, "error: {}", error.message)?;
+            } else {
+                writeln!(f, "error at {}: {}", error.pointer, error.message)?;
+            }
+            if let Some(provenance) = &error.provenance {
+                writeln!(f, " --> {provenance}")?;
+            }
+        }
+        Ok(())
+    }
+}
+
+impl std::error::Error for SourcedErrors {}
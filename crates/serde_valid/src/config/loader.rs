@@ -0,0 +1,545 @@
+use std::path::PathBuf;
+
+use indexmap::IndexMap;
+use serde_json::{Map, Value};
+
+use super::provenance::ProvenanceMap;
+use super::{Error, Provenance, SourcedErrors};
+use crate::env_vars::{field_key, Node};
+use crate::flatten::escape_pointer_token;
+use crate::format::{DataFormat, DynFormat, DynFormatError};
+use crate::json::lenient::deserializer::{Error as ValueError, ValueDeserializer};
+use crate::location::{Location, SpanMap};
+
+/// Loader of the configuration merged from the layers in order.
+///
+/// The objects of the later layers are merged into the earlier ones key by key,
+/// and the other values, including the arrays, replace the earlier ones.
+///
+/// ```rust
+/// use serde::Deserialize;
+/// use serde_valid::Validate;
+/// use serde_valid::config::ConfigLoader;
+/// use serde_valid::format::DynFormat;
+///
+/// #[derive(Debug, Validate, Deserialize)]
+/// struct Config {
+///     #[validate]
+///     db: Db,
+/// }
+///
+/// #[derive(Debug, Validate, Deserialize)]
+/// struct Db {
+///     host: String,
+///     #[validate(maximum = 100)]
+///     pool_size: u32,
+/// }
+///
+/// let defaults = r#"{
+///   "db": { "host": "localhost", "pool_size": 10 }
+/// }"#;
+///
+/// let err = ConfigLoader::new()
+///     .add_str("defaults.json", DynFormat::Json, defaults)
+///     .add_vars_prefixed([("APP_DB__POOL_SIZE", "200")], "APP_")
+///     .load::<Config>()
+///     .unwrap_err();
+///
+/// assert_eq!(
+///     err.to_string(),
+///     concat!(
+///         "error at /db/pool_size: The number must be `<= 100`.\n",
+///         " --> environment variable APP_DB__POOL_SIZE\n",
+///     )
+/// );
+/// ```
+#[derive(Debug, Default)]
+pub struct ConfigLoader {
+    layers: Vec<Layer>,
+}
+
+#[derive(Debug)]
+enum Layer {
+    File {
+        path: PathBuf,
+        required: bool,
+    },
+    Str {
+        origin: String,
+        format: DynFormat,
+        source: String,
+    },
+    Env {
+        vars: Vec<(String, String)>,
+        prefix: String,
+    },
+}
+
+impl ConfigLoader {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Add the file, whose format is picked by the extension with [`DynFormat::from_path`].
+    pub fn add_file(mut self, path: impl Into<PathBuf>) -> Self {
+        self.layers.push(Layer::File {
+            path: path.into(),
+            required: true,
+        });
+        self
+    }
+
+    /// Add the file in the same way as [`ConfigLoader::add_file`], skipping it if it does not exist.
+    pub fn add_optional_file(mut self, path: impl Into<PathBuf>) -> Self {
+        self.layers.push(Layer::File {
+            path: path.into(),
+            required: false,
+        });
+        self
+    }
+
+    /// Add the text of the format, where `origin` (e.g. file path) is shown as its source.
+    pub fn add_str(
+        mut self,
+        origin: impl Into<String>,
+        format: DynFormat,
+        source: impl Into<String>,
+    ) -> Self {
+        self.layers.push(Layer::Str {
+            origin: origin.into(),
+            format,
+            source: source.into(),
+        });
+        self
+    }
+
+    /// Add the environment variables starting with `prefix`.
+    ///
+    /// The nested keys are separated by `__`, like `APP_DB__HOST` for `db.host`,
+    /// and matched to the keys and the fields case-insensitively, like `APP_MAXWORKERS` for `maxWorkers`.
+    /// The values are read as json if they can be, like `10`, `true` or `["a", "b"]`,
+    /// and as strings otherwise, or where the target does not accept the json value,
+    /// like `APP_PASSWORD=12345` for a string.
+    pub fn add_env_prefixed(self, prefix: &str) -> Self {
+        self.add_vars_prefixed(
+            std::env::vars_os().filter_map(|(key, value)| {
+                Some((key.into_string().ok()?, value.into_string().ok()?))
+            }),
+            prefix,
+        )
+    }
+
+    /// Add the given pairs of the variable names and values, in the same way as
+    /// [`ConfigLoader::add_env_prefixed`].
+    pub fn add_vars_prefixed<I, K, V>(mut self, vars: I, prefix: &str) -> Self
+    where
+        I: IntoIterator<Item = (K, V)>,
+        K: Into<String>,
+        V: Into<String>,
+    {
+        let vars = vars
+            .into_iter()
+            .map(|(key, value)| (key.into(), value.into()))
+            .filter(|(key, _): &(String, String)| {
+                key.len() > prefix.len() && key.starts_with(prefix)
+            })
+            .collect::<Vec<_>>();
+
+        self.layers.push(Layer::Env {
+            vars,
+            prefix: prefix.to_string(),
+        });
+        self
+    }
+
+    /// Merge the layers, deserialize the result, and validate it.
+    pub fn load<T>(&self) -> Result<T, Error>
+    where
+        T: serde::de::DeserializeOwned + crate::Validate,
+    {
+        let mut value = Value::Object(Map::new());
+        let mut provenances = ProvenanceMap::default();
+        let mut env_values = IndexMap::new();
+
+        for layer in &self.layers {
+            match layer {
+                Layer::File { path, required } => {
+                    let format = DynFormat::from_path(path)
+                        .ok_or_else(|| Error::UnknownFormat(path.clone()))?;
+                    let source = match std::fs::read_to_string(path) {
+                        Ok(source) => source,
+                        Err(error) if !required && error.kind() == std::io::ErrorKind::NotFound => {
+                            continue
+                        }
+                        Err(source) => {
+                            return Err(Error::Io {
+                                path: path.clone(),
+                                source,
+                            })
+                        }
+                    };
+                    let origin = path.display().to_string();
+                    merge_source(&mut value, &mut provenances, &origin, format, &source)?;
+                }
+                Layer::Str {
+                    origin,
+                    format,
+                    source,
+                } => merge_source(&mut value, &mut provenances, origin, *format, source)?,
+                Layer::Env { vars, prefix } => {
+                    env_values.extend(vars.iter().cloned());
+                    let mut variables = IndexMap::new();
+                    let layer = env_layer(
+                        Node::from_vars(vars.iter().cloned(), prefix),
+                        Some(&value),
+                        "",
+                        &mut variables,
+                    );
+                    let provenance = |pointer: &str| {
+                        variables.get(pointer).map(|variable| Provenance::Env {
+                            variable: variable.to_string(),
+                        })
+                    };
+                    merge(&mut value, layer, "", &provenance, &mut provenances);
+                }
+            }
+        }
+
+        let model = loop {
+            match serde_path_to_error::deserialize::<_, T>(ValueDeserializer(&value)) {
+                Ok(model) => break model,
+                Err(error) => {
+                    let pointer = error_pointer(error.path());
+                    if let ValueError::MissingField(field) = error.inner() {
+                        if rename_env_key(&mut value, &pointer, field, &mut provenances) {
+                            continue;
+                        }
+                    }
+                    if restore_env_string(&mut value, &pointer, &provenances, &mut env_values) {
+                        continue;
+                    }
+                    return Err(Error::DeserializeError {
+                        provenance: provenances.get(&pointer).cloned(),
+                        pointer,
+                        source: serde::de::Error::custom(error.into_inner()),
+                    });
+                }
+            }
+        };
+        model.validate().map_err(|errors| {
+            Error::ValidationError(Box::new(SourcedErrors::new(errors, &provenances)))
+        })?;
+        Ok(model)
+    }
+}
+
+fn merge_source(
+    value: &mut Value,
+    provenances: &mut ProvenanceMap,
+    origin: &str,
+    format: DynFormat,
+    source: &str,
+) -> Result<(), Error> {
+    let (layer, span_map) = parse(format, source).map_err(|source| Error::Parse {
+        origin: origin.to_string(),
+        source,
+    })?;
+    let provenance = |pointer: &str| {
+        Some(Provenance::File {
+            origin: origin.to_string(),
+            location: span_map
+                .as_ref()
+                .and_then(|span_map| span_map.get(pointer))
+                .map(|span| Location::from_offset(source, span.start)),
+        })
+    };
+    merge(value, layer, "", &provenance, provenances);
+    Ok(())
+}
+
+/// Parse the text into the value, with the spans if the format records them.
+fn parse(format: DynFormat, source: &str) -> Result<(Value, Option<SpanMap>), DynFormatError> {
+    match format {
+        DynFormat::Json => Ok((
+            deserialize::<crate::format::Json>(source)?,
+            Some(crate::json::span_map_from_json_str(source)),
+        )),
+        #[cfg(feature = "json5")]
+        DynFormat::Json5 => Ok((deserialize::<crate::format::Json5>(source)?, None)),
+        #[cfg(feature = "ron")]
+        DynFormat::Ron => Ok((deserialize::<crate::format::Ron>(source)?, None)),
+        #[cfg(feature = "toml")]
+        DynFormat::Toml => Ok((
+            deserialize::<crate::format::Toml>(source)?,
+            Some(crate::toml::span_map_from_toml_str(source)),
+        )),
+        #[cfg(feature = "xml")]
+        DynFormat::Xml => Ok((deserialize::<crate::format::Xml>(source)?, None)),
+        #[cfg(feature = "yaml")]
+        DynFormat::Yaml => Ok((
+            deserialize::<crate::format::Yaml>(source)?,
+            Some(crate::yaml::span_map_from_yaml_str(source)),
+        )),
+    }
+}
+
+fn deserialize<F>(source: &str) -> Result<Value, DynFormatError>
+where
+    F: DataFormat,
+    F::DeserializeError: Send + Sync,
+{
+    F::deserialize_str(source).map_err(DynFormatError::new)
+}
+
+fn merge(
+    target: &mut Value,
+    layer: Value,
+    pointer: &str,
+    provenance: &dyn Fn(&str) -> Option<Provenance>,
+    provenances: &mut ProvenanceMap,
+) {
+    match (target, layer) {
+        (Value::Object(target), Value::Object(layer)) => {
+            if let Some(provenance) = provenance(pointer) {
+                provenances.insert(pointer, Some(provenance));
+            }
+            for (key, value) in layer {
+                let child = format!("{pointer}/{}", escape_pointer_token(&key));
+                match target.get_mut(&key) {
+                    Some(target) => merge(target, value, &child, provenance, provenances),
+                    None => {
+                        record(&value, &child, provenance, provenances);
+                        target.insert(key, value);
+                    }
+                }
+            }
+        }
+        (target, layer) => {
+            provenances.remove_children(pointer);
+            record(&layer, pointer, provenance, provenances);
+            *target = layer;
+        }
+    }
+}
+
+/// Record the provenances of the value and its children.
+fn record(
+    value: &Value,
+    pointer: &str,
+    provenance: &dyn Fn(&str) -> Option<Provenance>,
+    provenances: &mut ProvenanceMap,
+) {
+    provenances.insert(pointer, provenance(pointer));
+    match value {
+        Value::Object(object) => {
+            for (key, value) in object {
+                let child = format!("{pointer}/{}", escape_pointer_token(key));
+                record(value, &child, provenance, provenances);
+            }
+        }
+        Value::Array(array) => {
+            for (index, value) in array.iter().enumerate() {
+                record(
+                    value,
+                    &format!("{pointer}/{index}"),
+                    provenance,
+                    provenances,
+                );
+            }
+        }
+        _ => {}
+    }
+}
+
+/// Build the value of the environment variables, with the variables keyed by the pointers.
+///
+/// The keys are given as the ones of the target which match them case-insensitively,
+/// so that the variables override the renamed fields, like `APP_MAXWORKERS` for `maxWorkers`.
+/// The values are read as json if they can be, and as strings otherwise.
+fn env_layer(
+    node: Node,
+    target: Option<&Value>,
+    pointer: &str,
+    variables: &mut IndexMap<String, String>,
+) -> Value {
+    match node {
+        Node::Value { variable, value } => {
+            variables.insert(pointer.to_string(), variable);
+            match serde_json::from_str(&value) {
+                Ok(value) => value,
+                Err(_) => Value::String(value),
+            }
+        }
+        Node::Map { entries, .. } => {
+            let target = target.and_then(Value::as_object);
+            let object = entries
+                .into_iter()
+                .map(|(key, node)| {
+                    let key = match target {
+                        Some(target) if !target.contains_key(&key) => target
+                            .keys()
+                            .find(|other| field_key(other) == key)
+                            .cloned()
+                            .unwrap_or(key),
+                        _ => key,
+                    };
+                    let child = format!("{pointer}/{}", escape_pointer_token(&key));
+                    let value = env_layer(
+                        node,
+                        target.and_then(|target| target.get(&key)),
+                        &child,
+                        variables,
+                    );
+                    (key, value)
+                })
+                .collect();
+            Value::Object(object)
+        }
+    }
+}
+
+/// Rename the key of the environment variable to the missing field which it matches
+/// case-insensitively, like `maxworkers` to `maxWorkers`, where no other layer has the field.
+fn rename_env_key(
+    value: &mut Value,
+    pointer: &str,
+    field: &str,
+    provenances: &mut ProvenanceMap,
+) -> bool {
+    let key = field_key(field);
+    if key == field {
+        return false;
+    }
+    let Some(Value::Object(object)) = value.pointer_mut(pointer) else {
+        return false;
+    };
+    let Some(child) = object.remove(&key) else {
+        return false;
+    };
+    object.insert(field.to_string(), child);
+    provenances.rename(
+        &format!("{pointer}/{}", escape_pointer_token(&key)),
+        &format!("{pointer}/{}", escape_pointer_token(field)),
+    );
+    true
+}
+
+/// Replace the json value of the environment variable with the string,
+/// where the target does not accept the json value, like `APP_PASSWORD=12345` for a string.
+///
+/// The value of the variable is given back as its raw string, and the item inside it,
+/// like `APP_TAGS=[1]` for the strings, as its json text.
+/// Each value is restored at most once, so that the retries end.
+fn restore_env_string(
+    value: &mut Value,
+    pointer: &str,
+    provenances: &ProvenanceMap,
+    env_values: &mut IndexMap<String, String>,
+) -> bool {
+    let Some((ancestor, variable)) = env_ancestor(pointer, provenances) else {
+        return false;
+    };
+    let Some(target) = value.pointer_mut(pointer) else {
+        return false;
+    };
+    // the objects fail by their fields, like the missing ones, and the arrays by their items.
+    if target.is_string() || target.is_object() || (target.is_array() && ancestor != pointer) {
+        return false;
+    }
+    if ancestor == pointer {
+        let Some(raw) = env_values.shift_remove(variable) else {
+            return false;
+        };
+        *target = Value::String(raw);
+    } else {
+        *target = Value::String(target.to_string());
+    }
+    true
+}
+
+/// Pointer and name of the environment variable which supplied the value at the pointer,
+/// which may be the one of its ancestors, like `/tags` for `/tags/0`.
+fn env_ancestor<'a>(
+    pointer: &'a str,
+    provenances: &'a ProvenanceMap,
+) -> Option<(&'a str, &'a str)> {
+    let mut ancestor = pointer;
+    loop {
+        if let Some(Provenance::Env { variable }) = provenances.get(ancestor) {
+            return Some((ancestor, variable));
+        }
+        ancestor = &ancestor[..ancestor.rfind('/')?];
+    }
+}
+
+fn error_pointer(path: &serde_path_to_error::Path) -> String {
+    path.iter()
+        .filter_map(|segment| match segment {
+            serde_path_to_error::Segment::Map { key } => {
+                Some(format!("/{}", escape_pointer_token(key)))
+            }
+            serde_path_to_error::Segment::Seq { index } => Some(format!("/{index}")),
+            serde_path_to_error::Segment::Enum { .. } | serde_path_to_error::Segment::Unknown => {
+                None
+            }
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_env_layer() {
+        let vars = [
+            ("APP_DB", "x"),
+            ("APP_DB__POOL_SIZE", "10"),
+            ("APP_NAME", "taro"),
+        ];
+        let mut variables = IndexMap::new();
+
+        let layer = env_layer(Node::from_vars(vars, "APP_"), None, "", &mut variables);
+
+        assert_eq!(layer, json!({ "db": { "pool_size": 10 }, "name": "taro" }));
+        assert_eq!(variables["/db/pool_size"], "APP_DB__POOL_SIZE");
+    }
+
+    #[test]
+    fn test_env_layer_matches_target_keys() {
+        let vars = [("APP_DB__MAXSIZE", "10"), ("APP_NAME", "taro")];
+        let target = json!({ "db": { "maxSize": 1 } });
+        let mut variables = IndexMap::new();
+
+        let layer = env_layer(
+            Node::from_vars(vars, "APP_"),
+            Some(&target),
+            "",
+            &mut variables,
+        );
+
+        assert_eq!(layer, json!({ "db": { "maxSize": 10 }, "name": "taro" }));
+        assert_eq!(variables["/db/maxSize"], "APP_DB__MAXSIZE");
+    }
+
+    #[test]
+    fn test_merge_replaces_arrays() {
+        let provenance = |_: &str| None;
+        let mut provenances = ProvenanceMap::default();
+        let mut value = json!({ "hosts": ["a", "b"], "db": { "host": "a", "port": 1 } });
+
+        merge(
+            &mut value,
+            json!({ "hosts": ["c"], "db": { "port": 2 } }),
+            "",
+            &provenance,
+            &mut provenances,
+        );
+
+        assert_eq!(
+            value,
+            json!({ "hosts": ["c"], "db": { "host": "a", "port": 2 } })
+        );
+    }
+}
@@ -0,0 +1,98 @@
+use indexmap::IndexMap;
+
+use crate::location::Location;
+
+/// Source of the value in the merged configuration.
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Provenance {
+    /// The value was read from the file, or the text given with its origin.
+    File {
+        origin: String,
+        location: Option<Location>,
+    },
+    /// The value was read from the environment variable.
+    Env { variable: String },
+}
+
+impl std::fmt::Display for Provenance {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::File {
+                origin,
+                location: Some(location),
+            } => write!(f, "{origin}:{location}"),
+            Self::File {
+                origin,
+                location: None,
+            } => write!(f, "{origin}"),
+            Self::Env { variable } => write!(f, "environment variable {variable}"),
+        }
+    }
+}
+
+/// Provenances of the merged values keyed by [JSON Pointer](https://datatracker.ietf.org/doc/html/rfc6901).
+#[derive(Debug, Default)]
+pub(crate) struct ProvenanceMap(IndexMap<String, Provenance>);
+
+impl ProvenanceMap {
+    pub fn get(&self, pointer: &str) -> Option<&Provenance> {
+        self.0.get(pointer)
+    }
+
+    /// Forget the provenances of the values under the pointer, which are replaced.
+    pub fn remove_children(&mut self, pointer: &str) {
+        let prefix = format!("{pointer}/");
+        self.0.retain(|other, _| !other.starts_with(&prefix));
+    }
+
+    /// Move the provenances of the value and its children to the pointer of the renamed key.
+    pub fn rename(&mut self, from: &str, to: &str) {
+        let prefix = format!("{from}/");
+        self.0 = std::mem::take(&mut self.0)
+            .into_iter()
+            .map(
+                |(pointer, provenance)| match pointer.strip_prefix(&prefix) {
+                    Some(rest) => (format!("{to}/{rest}"), provenance),
+                    None if pointer == from => (to.to_string(), provenance),
+                    None => (pointer, provenance),
+                },
+            )
+            .collect();
+    }
+
+    pub fn insert(&mut self, pointer: &str, provenance: Option<Provenance>) {
+        match provenance {
+            Some(provenance) => {
+                self.0.insert(pointer.to_string(), provenance);
+            }
+            None => {
+                self.0.shift_remove(pointer);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_remove_children() {
+        let env = |variable: &str| {
+            Some(Provenance::Env {
+                variable: variable.to_string(),
+            })
+        };
+        let mut map = ProvenanceMap::default();
+        map.insert("/hosts", env("A"));
+        map.insert("/hosts/0", env("A"));
+        map.insert("/hosts_v2", env("A"));
+
+        map.remove_children("/hosts");
+
+        assert_eq!(map.get("/hosts"), env("A").as_ref());
+        assert_eq!(map.get("/hosts/0"), None);
+        assert_eq!(map.get("/hosts_v2"), env("A").as_ref());
+    }
+}
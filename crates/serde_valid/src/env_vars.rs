@@ -0,0 +1,113 @@
+use std::collections::BTreeMap;
+
+/// Separator of the nested keys, like `APP_DB__HOST`.
+pub(crate) const SEPARATOR: &str = "__";
+
+/// Tree of the environment variables, split by [`SEPARATOR`] and lowercased.
+///
+/// The keys are matched to the fields of the structs case-insensitively, with [`field_key`].
+#[derive(Debug, Clone)]
+pub(crate) enum Node {
+    Value {
+        variable: String,
+        value: String,
+    },
+    Map {
+        prefix: String,
+        entries: BTreeMap<String, Node>,
+    },
+}
+
+/// Key of the field in the tree, like `maxworkers` for `maxWorkers`.
+pub(crate) fn field_key(field: &str) -> String {
+    field.to_lowercase()
+}
+
+impl Node {
+    pub(crate) fn from_vars<I, K, V>(vars: I, prefix: &str) -> Self
+    where
+        I: IntoIterator<Item = (K, V)>,
+        K: Into<String>,
+        V: Into<String>,
+    {
+        let mut root = Node::Map {
+            prefix: prefix.to_string(),
+            entries: BTreeMap::new(),
+        };
+        for (variable, value) in vars {
+            let variable = variable.into();
+            let Some(key) = variable.strip_prefix(prefix) else {
+                continue;
+            };
+            let segments = key.split(SEPARATOR).collect::<Vec<_>>();
+            if segments.iter().any(|segment| segment.is_empty()) {
+                continue;
+            }
+            root.insert(&segments, variable.clone(), value.into());
+        }
+        root
+    }
+
+    fn insert(&mut self, segments: &[&str], variable: String, value: String) {
+        let Node::Map { prefix, entries } = self else {
+            return;
+        };
+        let key = field_key(segments[0]);
+        if segments.len() == 1 {
+            // the nested keys take precedence over the value of the same name.
+            entries
+                .entry(key)
+                .or_insert(Node::Value { variable, value });
+            return;
+        }
+
+        let child_prefix = format!("{prefix}{}{SEPARATOR}", segments[0]);
+        let child = entries.entry(key).or_insert_with(|| Node::Map {
+            prefix: child_prefix.clone(),
+            entries: BTreeMap::new(),
+        });
+        if let Node::Value { .. } = child {
+            *child = Node::Map {
+                prefix: child_prefix,
+                entries: BTreeMap::new(),
+            };
+        }
+        child.insert(&segments[1..], variable, value);
+    }
+
+    /// Name of the environment variable of the field, which is the one given
+    /// when the field is set, or the one to set it.
+    #[cfg(feature = "env")]
+    pub(crate) fn field_variable(&self, field: &str) -> String {
+        match self.field(field) {
+            Some(Node::Value { variable, .. }) => variable.clone(),
+            Some(Node::Map { prefix, .. }) => {
+                prefix.strip_suffix(SEPARATOR).unwrap_or(prefix).to_string()
+            }
+            None => match self {
+                Node::Map { prefix, .. } => format!("{prefix}{}", field.to_uppercase()),
+                Node::Value { variable, .. } => variable.clone(),
+            },
+        }
+    }
+
+    /// Node of the nested fields of the field, which is empty when the field is not set.
+    #[cfg(feature = "env")]
+    pub(crate) fn field_node(&self, field: &str) -> Node {
+        match self.field(field) {
+            Some(node @ Node::Map { .. }) => node.clone(),
+            _ => Node::Map {
+                prefix: format!("{}{SEPARATOR}", self.field_variable(field)),
+                entries: BTreeMap::new(),
+            },
+        }
+    }
+
+    #[cfg(feature = "env")]
+    fn field(&self, field: &str) -> Option<&Node> {
+        match self {
+            Node::Map { entries, .. } => entries.get(&field_key(field)),
+            Node::Value { .. } => None,
+        }
+    }
+}
@@ -1,8 +1,8 @@
 use itertools::Itertools;
-use serde_valid_literal::Literal;
+use serde_valid_literal::{Duration, Literal, Period};
 
 use crate::validation::error::FormatDefault;
-use crate::validation::Number;
+use crate::validation::{Number, RangeLimit};
 
 #[derive(Debug, thiserror::Error)]
 pub enum Error<E>
@@ -84,6 +84,40 @@ macro_rules! struct_error_params {
         }
     };
 
+    (
+        #[derive(Debug, Clone)]
+        #[default_message=$default_message:literal]
+        #[date_time_message=$date_time_message:literal]
+        pub struct $Error:ident {
+            pub $limit:ident: RangeLimit,
+        }
+    ) => {
+        #[derive(Debug, Clone)]
+        pub struct $Error {
+            pub $limit: RangeLimit,
+        }
+
+        impl $Error {
+            pub fn new<N: Into<RangeLimit>>($limit: N) -> Self {
+                Self {
+                    $limit: $limit.into(),
+                }
+            }
+        }
+
+        impl FormatDefault for $Error {
+            #[inline]
+            fn format_default(&self) -> String {
+                match self.$limit {
+                    RangeLimit::Number(_) => format!($default_message, self.$limit),
+                    RangeLimit::Date(_)
+                    | RangeLimit::NaiveDateTime(_)
+                    | RangeLimit::DateTime(_) => format!($date_time_message, self.$limit),
+                }
+            }
+        }
+    };
+
     (
         #[derive(Debug, Clone)]
         #[default_message=$default_message:literal]
@@ -133,32 +167,36 @@ macro_rules! struct_error_params {
 struct_error_params!(
     #[derive(Debug, Clone)]
     #[default_message = "The number must be `>= {}`."]
+    #[date_time_message = "The date must be `>= {}`."]
     pub struct MinimumError {
-        pub minimum: Number,
+        pub minimum: RangeLimit,
     }
 );
 
 struct_error_params!(
     #[derive(Debug, Clone)]
     #[default_message = "The number must be `<= {}`."]
+    #[date_time_message = "The date must be `<= {}`."]
     pub struct MaximumError {
-        pub maximum: Number,
+        pub maximum: RangeLimit,
     }
 );
 
 struct_error_params!(
     #[derive(Debug, Clone)]
     #[default_message = "The number must be `> {}`."]
+    #[date_time_message = "The date must be `> {}`."]
     pub struct ExclusiveMinimumError {
-        pub exclusive_minimum: Number,
+        pub exclusive_minimum: RangeLimit,
     }
 );
 
 struct_error_params!(
     #[derive(Debug, Clone)]
     #[default_message = "The number must be `< {}`."]
+    #[date_time_message = "The date must be `< {}`."]
     pub struct ExclusiveMaximumError {
-        pub exclusive_maximum: Number,
+        pub exclusive_maximum: RangeLimit,
     }
 );
 
@@ -170,6 +208,35 @@ struct_error_params!(
     }
 );
 
+// Time
+struct_error_params!(
+    #[derive(Debug, Clone)]
+    #[default_message = "The date must be in the past."]
+    pub struct PastError;
+);
+
+struct_error_params!(
+    #[derive(Debug, Clone)]
+    #[default_message = "The date must be in the future."]
+    pub struct FutureError;
+);
+
+struct_error_params!(
+    #[derive(Debug, Clone)]
+    #[default_message = "The date must be within `{}` from now."]
+    pub struct WithinError {
+        pub within: Duration,
+    }
+);
+
+struct_error_params!(
+    #[derive(Debug, Clone)]
+    #[default_message = "The date must be at least `{}` ago."]
+    pub struct OlderThanError {
+        pub older_than: Period,
+    }
+);
+
 // String
 struct_error_params!(
     #[derive(Debug, Clone)]
@@ -244,5 +311,20 @@ struct_error_params!(
     }
 );
 
+// Deserialize
+struct_error_params!(
+    #[derive(Debug, Clone)]
+    #[default_message = "The value cannot be deserialized: {}."]
+    pub struct TypeError {
+        pub reason: String,
+    }
+);
+
+struct_error_params!(
+    #[derive(Debug, Clone)]
+    #[default_message = "The value is required."]
+    pub struct RequiredError;
+);
+
 #[deprecated(since = "2.0.2", note = "use `EnumError` instead")]
 pub type EnumerateError = EnumError;
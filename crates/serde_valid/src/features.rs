@@ -1,8 +1,46 @@
+#[cfg(feature = "actix")]
+pub mod actix;
+
+#[cfg(feature = "axum")]
+pub mod axum;
+
+#[cfg(feature = "cbor")]
+pub mod cbor;
+
+#[cfg(feature = "clap")]
+pub mod clap;
+
+#[cfg(feature = "csv")]
+pub mod csv;
+
+#[cfg(feature = "env")]
+pub mod env;
+
+pub mod flatten;
+
 #[cfg(feature = "fluent")]
 pub mod fluent;
 
+#[cfg(feature = "json5")]
+pub mod json5;
+
+#[cfg(feature = "msgpack")]
+pub mod msgpack;
+
+#[cfg(feature = "problem_details")]
+pub mod problem_details;
+
+#[cfg(feature = "ron")]
+pub mod ron;
+
 #[cfg(feature = "toml")]
 pub mod toml;
 
+#[cfg(feature = "urlencoded")]
+pub mod urlencoded;
+
+#[cfg(feature = "xml")]
+pub mod xml;
+
 #[cfg(feature = "yaml")]
 pub mod yaml;
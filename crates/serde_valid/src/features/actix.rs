@@ -0,0 +1,7 @@
+//! [actix-web](https://actix.rs/) extractors which validate the extracted values.
+
+mod error;
+mod valid;
+
+pub use error::{ValidRejection, ValidationErrorHandler};
+pub use valid::{HasValidate, Valid};
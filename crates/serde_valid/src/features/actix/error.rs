@@ -0,0 +1,102 @@
+use std::sync::Arc;
+
+use actix_web::http::{header, StatusCode};
+use actix_web::{HttpRequest, HttpResponse, ResponseError};
+
+use crate::validation::Errors;
+
+/// Error of the [`Valid`](super::Valid) extractor when the validation failed.
+///
+/// Responded as `422 Unprocessable Entity` with the json body of the errors,
+/// which is the `ProblemDetails` of the `problem_details` feature.
+#[derive(Debug, thiserror::Error)]
+#[error("{0}")]
+pub struct ValidRejection(Errors);
+
+impl ValidRejection {
+    pub fn new(errors: Errors) -> Self {
+        Self(errors)
+    }
+
+    pub fn errors(&self) -> &Errors {
+        &self.0
+    }
+
+    pub fn into_errors(self) -> Errors {
+        self.0
+    }
+}
+
+impl ResponseError for ValidRejection {
+    fn status_code(&self) -> StatusCode {
+        StatusCode::UNPROCESSABLE_ENTITY
+    }
+
+    #[cfg(not(feature = "problem_details"))]
+    fn error_response(&self) -> HttpResponse {
+        HttpResponse::build(self.status_code())
+            .insert_header((header::CONTENT_TYPE, "application/json"))
+            .json(&self.0)
+    }
+
+    #[cfg(feature = "problem_details")]
+    fn error_response(&self) -> HttpResponse {
+        HttpResponse::build(self.status_code())
+            .insert_header((header::CONTENT_TYPE, crate::problem_details::CONTENT_TYPE))
+            .json(crate::problem_details::ProblemDetails::from(&self.0))
+    }
+}
+
+/// Conversion of the validation errors into the error response, which is set to the app data.
+///
+/// Without the handler, the errors are responded by [`ValidRejection`].
+///
+/// ```rust
+/// use actix_web::{error, web, App, HttpResponse};
+/// use serde::Deserialize;
+/// use serde_valid::Validate;
+/// use serde_valid::actix::{Valid, ValidationErrorHandler};
+///
+/// #[derive(Debug, Validate, Deserialize)]
+/// struct CreateUser {
+///     #[validate(min_length = 1)]
+///     name: String,
+/// }
+///
+/// async fn create_user(Valid(web::Json(user)): Valid<web::Json<CreateUser>>) -> String {
+///     user.name
+/// }
+///
+/// let app = App::new()
+///     .app_data(ValidationErrorHandler::new(|errors, _req| {
+///         error::InternalError::from_response(
+///             "invalid request",
+///             HttpResponse::BadRequest().body(errors.to_string()),
+///         )
+///         .into()
+///     }))
+///     .route("/users", web::post().to(create_user));
+/// ```
+#[derive(Clone)]
+pub struct ValidationErrorHandler(Arc<HandlerFn>);
+
+type HandlerFn = dyn Fn(Errors, &HttpRequest) -> actix_web::Error + Send + Sync;
+
+impl ValidationErrorHandler {
+    pub fn new<F>(handler: F) -> Self
+    where
+        F: Fn(Errors, &HttpRequest) -> actix_web::Error + Send + Sync + 'static,
+    {
+        Self(Arc::new(handler))
+    }
+
+    pub fn call(&self, errors: Errors, req: &HttpRequest) -> actix_web::Error {
+        (self.0)(errors, req)
+    }
+}
+
+impl std::fmt::Debug for ValidationErrorHandler {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_tuple("ValidationErrorHandler").finish()
+    }
+}
@@ -0,0 +1,132 @@
+use std::future::Future;
+use std::pin::Pin;
+
+use actix_web::dev::Payload;
+use actix_web::{web, FromRequest, HttpRequest};
+
+use super::{ValidRejection, ValidationErrorHandler};
+
+/// Extractor which validates the value extracted by the inner extractor.
+///
+/// The inner extractor is one of [`web::Json`], [`web::Query`], [`web::Form`] and [`web::Path`],
+/// or any extractor implementing [`HasValidate`].
+/// The validation errors are converted by [`ValidationErrorHandler`] in the app data,
+/// or responded by [`ValidRejection`] without it.
+///
+/// ```rust
+/// use actix_web::{web, App};
+/// use serde::Deserialize;
+/// use serde_valid::Validate;
+/// use serde_valid::actix::Valid;
+///
+/// #[derive(Debug, Validate, Deserialize)]
+/// struct CreateUser {
+///     #[validate(min_length = 1)]
+///     name: String,
+/// }
+///
+/// async fn create_user(Valid(web::Json(user)): Valid<web::Json<CreateUser>>) -> String {
+///     user.name
+/// }
+///
+/// let app = App::new().route("/users", web::post().to(create_user));
+/// ```
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Valid<E>(pub E);
+
+impl<E> Valid<E> {
+    pub fn into_inner(self) -> E {
+        self.0
+    }
+}
+
+impl<E> std::ops::Deref for Valid<E> {
+    type Target = E;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl<E> std::ops::DerefMut for Valid<E> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.0
+    }
+}
+
+/// Extractor which holds the value to be validated by [`Valid`].
+pub trait HasValidate {
+    type Validate: crate::Validate;
+
+    fn get_validate(&self) -> &Self::Validate;
+}
+
+impl<T> HasValidate for web::Json<T>
+where
+    T: crate::Validate,
+{
+    type Validate = T;
+
+    fn get_validate(&self) -> &T {
+        &self.0
+    }
+}
+
+impl<T> HasValidate for web::Form<T>
+where
+    T: crate::Validate,
+{
+    type Validate = T;
+
+    fn get_validate(&self) -> &T {
+        &self.0
+    }
+}
+
+impl<T> HasValidate for web::Query<T>
+where
+    T: crate::Validate,
+{
+    type Validate = T;
+
+    fn get_validate(&self) -> &T {
+        &self.0
+    }
+}
+
+impl<T> HasValidate for web::Path<T>
+where
+    T: crate::Validate,
+{
+    type Validate = T;
+
+    fn get_validate(&self) -> &T {
+        self
+    }
+}
+
+impl<E> FromRequest for Valid<E>
+where
+    E: FromRequest + HasValidate + 'static,
+{
+    type Error = actix_web::Error;
+    type Future = Pin<Box<dyn Future<Output = Result<Self, Self::Error>>>>;
+
+    fn from_request(req: &HttpRequest, payload: &mut Payload) -> Self::Future {
+        use crate::Validate;
+
+        let req = req.clone();
+        let inner = E::from_request(&req, payload);
+
+        Box::pin(async move {
+            let inner = inner.await.map_err(Into::into)?;
+            match inner.get_validate().validate() {
+                Ok(()) => Ok(Valid(inner)),
+                Err(errors) => Err(match req.app_data::<ValidationErrorHandler>() {
+                    Some(handler) => handler.call(errors, &req),
+                    None => ValidRejection::new(errors).into(),
+                }),
+            }
+        })
+    }
+}
@@ -0,0 +1,7 @@
+//! [axum](https://github.com/tokio-rs/axum) extractors which validate the extracted values.
+
+mod rejection;
+mod valid;
+
+pub use rejection::{ValidRejection, ValidationErrorHandler};
+pub use valid::{HasValidate, Valid};
@@ -0,0 +1,106 @@
+use std::sync::Arc;
+
+use axum::http::{header, StatusCode};
+use axum::response::{IntoResponse, Response};
+
+use crate::validation::Errors;
+
+/// Rejection of the [`Valid`](super::Valid) extractor.
+#[derive(Debug)]
+pub enum ValidRejection<R> {
+    /// The inner extractor failed.
+    Extractor(R),
+    /// The validation failed.
+    Validation {
+        errors: Errors,
+        handler: Option<ValidationErrorHandler>,
+    },
+}
+
+impl<R> IntoResponse for ValidRejection<R>
+where
+    R: IntoResponse,
+{
+    fn into_response(self) -> Response {
+        match self {
+            Self::Extractor(rejection) => rejection.into_response(),
+            Self::Validation {
+                errors,
+                handler: Some(handler),
+            } => handler.call(errors),
+            Self::Validation {
+                errors,
+                handler: None,
+            } => default_response(errors),
+        }
+    }
+}
+
+/// Response of the validation errors, which is set to the router as [`Extension`](axum::Extension).
+///
+/// Without the handler, the errors are responded as `422 Unprocessable Entity` with the json body,
+/// which is the `ProblemDetails` of the `problem_details` feature.
+///
+/// ```rust
+/// use axum::{http::StatusCode, response::IntoResponse, routing::post, Extension, Json, Router};
+/// use serde::Deserialize;
+/// use serde_valid::Validate;
+/// use serde_valid::axum::{Valid, ValidationErrorHandler};
+///
+/// #[derive(Debug, Validate, Deserialize)]
+/// struct CreateUser {
+///     #[validate(min_length = 1)]
+///     name: String,
+/// }
+///
+/// async fn create_user(Valid(Json(user)): Valid<Json<CreateUser>>) -> String {
+///     user.name
+/// }
+///
+/// let app: Router = Router::new()
+///     .route("/users", post(create_user))
+///     .layer(Extension(ValidationErrorHandler::new(|errors| {
+///         (StatusCode::BAD_REQUEST, errors.to_string()).into_response()
+///     })));
+/// ```
+#[derive(Clone)]
+pub struct ValidationErrorHandler(Arc<dyn Fn(Errors) -> Response + Send + Sync>);
+
+impl ValidationErrorHandler {
+    pub fn new<F>(handler: F) -> Self
+    where
+        F: Fn(Errors) -> Response + Send + Sync + 'static,
+    {
+        Self(Arc::new(handler))
+    }
+
+    pub fn call(&self, errors: Errors) -> Response {
+        (self.0)(errors)
+    }
+}
+
+impl std::fmt::Debug for ValidationErrorHandler {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_tuple("ValidationErrorHandler").finish()
+    }
+}
+
+fn default_response(errors: Errors) -> Response {
+    #[cfg(feature = "problem_details")]
+    let (body, content_type) = (
+        serde_json::to_vec(&crate::problem_details::ProblemDetails::from(&errors)),
+        crate::problem_details::CONTENT_TYPE,
+    );
+    #[cfg(not(feature = "problem_details"))]
+    let (body, content_type) = (serde_json::to_vec(&errors), "application/json");
+
+    match body {
+        Ok(body) => (
+            StatusCode::UNPROCESSABLE_ENTITY,
+            [(header::CONTENT_TYPE, content_type)],
+            body,
+        )
+            .into_response(),
+        Err(_) => StatusCode::UNPROCESSABLE_ENTITY.into_response(),
+    }
+}
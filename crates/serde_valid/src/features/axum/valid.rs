@@ -0,0 +1,149 @@
+use axum::extract::{FromRequest, FromRequestParts, Request};
+use axum::http::request::Parts;
+
+use super::{ValidRejection, ValidationErrorHandler};
+
+/// Extractor which validates the value extracted by the inner extractor.
+///
+/// The inner extractor is one of [`Json`](axum::Json), [`Query`](axum::extract::Query),
+/// [`Form`](axum::Form) and [`Path`](axum::extract::Path), or any extractor implementing [`HasValidate`].
+/// The validation errors are rejected by [`ValidRejection`].
+///
+/// ```rust
+/// use axum::{routing::post, Json, Router};
+/// use serde::Deserialize;
+/// use serde_valid::Validate;
+/// use serde_valid::axum::Valid;
+///
+/// #[derive(Debug, Validate, Deserialize)]
+/// struct CreateUser {
+///     #[validate(min_length = 1)]
+///     name: String,
+/// }
+///
+/// async fn create_user(Valid(Json(user)): Valid<Json<CreateUser>>) -> String {
+///     user.name
+/// }
+///
+/// let app: Router = Router::new().route("/users", post(create_user));
+/// ```
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Valid<E>(pub E);
+
+impl<E> Valid<E> {
+    pub fn into_inner(self) -> E {
+        self.0
+    }
+}
+
+impl<E> std::ops::Deref for Valid<E> {
+    type Target = E;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl<E> std::ops::DerefMut for Valid<E> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.0
+    }
+}
+
+/// Extractor which holds the value to be validated by [`Valid`].
+pub trait HasValidate {
+    type Validate: crate::Validate;
+
+    fn get_validate(&self) -> &Self::Validate;
+}
+
+impl<T> HasValidate for axum::Json<T>
+where
+    T: crate::Validate,
+{
+    type Validate = T;
+
+    fn get_validate(&self) -> &T {
+        &self.0
+    }
+}
+
+impl<T> HasValidate for axum::Form<T>
+where
+    T: crate::Validate,
+{
+    type Validate = T;
+
+    fn get_validate(&self) -> &T {
+        &self.0
+    }
+}
+
+impl<T> HasValidate for axum::extract::Query<T>
+where
+    T: crate::Validate,
+{
+    type Validate = T;
+
+    fn get_validate(&self) -> &T {
+        &self.0
+    }
+}
+
+impl<T> HasValidate for axum::extract::Path<T>
+where
+    T: crate::Validate,
+{
+    type Validate = T;
+
+    fn get_validate(&self) -> &T {
+        &self.0
+    }
+}
+
+impl<S, E> FromRequest<S> for Valid<E>
+where
+    S: Send + Sync,
+    E: FromRequest<S> + HasValidate,
+{
+    type Rejection = ValidRejection<E::Rejection>;
+
+    async fn from_request(req: Request, state: &S) -> Result<Self, Self::Rejection> {
+        let handler = req.extensions().get::<ValidationErrorHandler>().cloned();
+        let inner = E::from_request(req, state)
+            .await
+            .map_err(ValidRejection::Extractor)?;
+        validate(inner, handler)
+    }
+}
+
+impl<S, E> FromRequestParts<S> for Valid<E>
+where
+    S: Send + Sync,
+    E: FromRequestParts<S> + HasValidate,
+{
+    type Rejection = ValidRejection<E::Rejection>;
+
+    async fn from_request_parts(parts: &mut Parts, state: &S) -> Result<Self, Self::Rejection> {
+        let handler = parts.extensions.get::<ValidationErrorHandler>().cloned();
+        let inner = E::from_request_parts(parts, state)
+            .await
+            .map_err(ValidRejection::Extractor)?;
+        validate(inner, handler)
+    }
+}
+
+fn validate<E, R>(
+    inner: E,
+    handler: Option<ValidationErrorHandler>,
+) -> Result<Valid<E>, ValidRejection<R>>
+where
+    E: HasValidate,
+{
+    use crate::Validate;
+
+    match inner.get_validate().validate() {
+        Ok(()) => Ok(Valid(inner)),
+        Err(errors) => Err(ValidRejection::Validation { errors, handler }),
+    }
+}
@@ -0,0 +1,17 @@
+mod from_cbor_reader;
+mod from_cbor_slice;
+mod to_cbor_vec;
+mod to_cbor_writer;
+
+pub use ciborium::Value;
+
+pub use from_cbor_reader::FromCborReader;
+pub use from_cbor_slice::FromCborSlice;
+pub use to_cbor_vec::ToCborVec;
+pub use to_cbor_writer::ToCborWriter;
+
+/// Error of the cbor deserialization.
+pub type DeserializeError = ciborium::de::Error<std::io::Error>;
+
+/// Error of the cbor serialization.
+pub type SerializeError = ciborium::ser::Error<std::io::Error>;
@@ -0,0 +1,40 @@
+pub trait FromCborReader
+where
+    Self: Sized,
+{
+    /// Convert from cbor reader.
+    ///
+    /// ```should_panic
+    /// use std::fs::File;
+    /// use serde::Deserialize;
+    /// use serde_valid::Validate;
+    /// use serde_valid::cbor::FromCborReader;
+    ///
+    /// #[derive(Debug, Validate, Deserialize)]
+    /// struct TestStruct {
+    ///     #[validate(maximum = 2000)]
+    ///     val: i32,
+    /// }
+    ///
+    /// let s = TestStruct::from_cbor_reader(File::open("foo.cbor").unwrap());
+    ///
+    /// assert!(s.is_ok())
+    /// ```
+    fn from_cbor_reader<R>(reader: R) -> Result<Self, crate::Error<super::DeserializeError>>
+    where
+        R: std::io::Read;
+}
+
+impl<T> FromCborReader for T
+where
+    T: serde::de::DeserializeOwned + crate::Validate,
+{
+    fn from_cbor_reader<R>(reader: R) -> Result<Self, crate::Error<super::DeserializeError>>
+    where
+        R: std::io::Read,
+    {
+        let model: T = ciborium::from_reader(reader)?;
+        model.validate().map_err(crate::Error::ValidationError)?;
+        Ok(model)
+    }
+}
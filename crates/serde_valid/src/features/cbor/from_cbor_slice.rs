@@ -0,0 +1,37 @@
+pub trait FromCborSlice
+where
+    Self: Sized,
+{
+    /// Convert from cbor slice.
+    ///
+    /// The borrowed fields are not supported, because cbor is decoded into owned values.
+    ///
+    /// ```rust
+    /// use serde::{Deserialize, Serialize};
+    /// use serde_valid::Validate;
+    /// use serde_valid::cbor::{FromCborSlice, ToCborVec};
+    ///
+    /// #[derive(Debug, Validate, Deserialize, Serialize)]
+    /// struct TestStruct {
+    ///     #[validate(min_length = 1)]
+    ///     val: String,
+    /// }
+    ///
+    /// let bytes = TestStruct { val: "abcde".to_string() }.to_cbor_vec().unwrap();
+    /// let s = TestStruct::from_cbor_slice(&bytes);
+    ///
+    /// assert!(s.is_ok())
+    /// ```
+    fn from_cbor_slice(slice: &[u8]) -> Result<Self, crate::Error<super::DeserializeError>>;
+}
+
+impl<T> FromCborSlice for T
+where
+    T: serde::de::DeserializeOwned + crate::Validate,
+{
+    fn from_cbor_slice(slice: &[u8]) -> Result<Self, crate::Error<super::DeserializeError>> {
+        let model: T = ciborium::from_reader(slice)?;
+        model.validate().map_err(crate::Error::ValidationError)?;
+        Ok(model)
+    }
+}
@@ -0,0 +1,62 @@
+pub trait ToCborVec {
+    /// Convert to cbor bytes.
+    ///
+    /// ```rust
+    /// use serde::Serialize;
+    /// use serde_valid::cbor::ToCborVec;
+    /// use serde_valid::Validate;
+    ///
+    /// #[derive(Debug, Validate, Serialize)]
+    /// struct TestStruct {
+    ///     #[validate(maximum = 100)]
+    ///     val: i32,
+    /// }
+    /// let s = TestStruct { val: 10 };
+    ///
+    /// assert_eq!(s.to_cbor_vec().unwrap(), b"\xa1\x63val\x0a");
+    /// ```
+    fn to_cbor_vec(&self) -> Result<Vec<u8>, super::SerializeError>;
+
+    /// Convert to cbor bytes after validation.
+    ///
+    /// ```rust
+    /// use serde::Serialize;
+    /// use serde_valid::cbor::ToCborVec;
+    /// use serde_valid::Validate;
+    ///
+    /// #[derive(Debug, Validate, Serialize)]
+    /// struct TestStruct {
+    ///     #[validate(maximum = 100)]
+    ///     val: i32,
+    /// }
+    /// let s = TestStruct { val: 1000 };
+    ///
+    /// assert!(s.to_cbor_vec_validated().unwrap_err().is_validation_errors());
+    /// ```
+    fn to_cbor_vec_validated(&self) -> Result<Vec<u8>, crate::Error<super::SerializeError>>
+    where
+        Self: crate::Validate,
+    {
+        self.validate().map_err(crate::Error::ValidationError)?;
+        Ok(self.to_cbor_vec()?)
+    }
+}
+
+impl<T> ToCborVec for T
+where
+    T: serde::Serialize + crate::Validate,
+{
+    fn to_cbor_vec(&self) -> Result<Vec<u8>, super::SerializeError> {
+        let mut buffer = Vec::new();
+        ciborium::into_writer(self, &mut buffer)?;
+        Ok(buffer)
+    }
+}
+
+impl ToCborVec for ciborium::Value {
+    fn to_cbor_vec(&self) -> Result<Vec<u8>, super::SerializeError> {
+        let mut buffer = Vec::new();
+        ciborium::into_writer(self, &mut buffer)?;
+        Ok(buffer)
+    }
+}
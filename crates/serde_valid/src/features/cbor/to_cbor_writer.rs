@@ -0,0 +1,75 @@
+pub trait ToCborWriter {
+    /// Convert to cbor writer.
+    ///
+    /// ```should_panic
+    /// use std::fs::File;
+    /// use serde::Serialize;
+    /// use serde_valid::cbor::ToCborWriter;
+    /// use serde_valid::Validate;
+    ///
+    /// #[derive(Debug, Validate, Serialize)]
+    /// struct TestStruct {
+    ///     #[validate(maximum = 100)]
+    ///     val: i32,
+    /// }
+    /// let s = TestStruct { val: 10 };
+    ///
+    /// assert!(s.to_cbor_writer(File::open("foo.cbor").unwrap()).is_ok());
+    /// ```
+    fn to_cbor_writer<W>(&self, writer: W) -> Result<(), super::SerializeError>
+    where
+        W: std::io::Write;
+
+    /// Convert to cbor writer after validation.
+    ///
+    /// Nothing is written when the validation fails.
+    ///
+    /// ```rust
+    /// use serde::Serialize;
+    /// use serde_valid::cbor::ToCborWriter;
+    /// use serde_valid::Validate;
+    ///
+    /// #[derive(Debug, Validate, Serialize)]
+    /// struct TestStruct {
+    ///     #[validate(maximum = 100)]
+    ///     val: i32,
+    /// }
+    /// let s = TestStruct { val: 1000 };
+    /// let mut buffer = Vec::new();
+    ///
+    /// assert!(s.to_cbor_writer_validated(&mut buffer).unwrap_err().is_validation_errors());
+    /// assert!(buffer.is_empty());
+    /// ```
+    fn to_cbor_writer_validated<W>(
+        &self,
+        writer: W,
+    ) -> Result<(), crate::Error<super::SerializeError>>
+    where
+        Self: crate::Validate,
+        W: std::io::Write,
+    {
+        self.validate().map_err(crate::Error::ValidationError)?;
+        Ok(self.to_cbor_writer(writer)?)
+    }
+}
+
+impl<T> ToCborWriter for T
+where
+    T: serde::Serialize + crate::Validate,
+{
+    fn to_cbor_writer<W>(&self, writer: W) -> Result<(), super::SerializeError>
+    where
+        W: std::io::Write,
+    {
+        ciborium::into_writer(self, writer)
+    }
+}
+
+impl ToCborWriter for ciborium::Value {
+    fn to_cbor_writer<W>(&self, writer: W) -> Result<(), super::SerializeError>
+    where
+        W: std::io::Write,
+    {
+        ciborium::into_writer(self, writer)
+    }
+}
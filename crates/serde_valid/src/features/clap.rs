@@ -0,0 +1,7 @@
+//! Validation of the command line arguments parsed by [clap](https://github.com/clap-rs/clap).
+
+mod error;
+mod parse_validated;
+
+pub use error::into_clap_error;
+pub use parse_validated::ParseValidated;
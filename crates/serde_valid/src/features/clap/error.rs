@@ -0,0 +1,88 @@
+use clap::error::ErrorKind;
+use clap::{Arg, Command};
+
+use crate::validation::{Errors, ObjectErrors};
+
+/// Convert the validation errors into the clap error, naming the arguments by the flags.
+///
+/// The errors of the fields are reported by the flag of the argument with the same id,
+/// like `--max-workers` for `max_workers`, and the fields of `#[command(flatten)]` groups are
+/// looked up in the same way. The error is formatted with the usage of the command,
+/// and exits with the usage error code.
+///
+/// ```rust
+/// use clap::{CommandFactory, Parser};
+/// use serde_valid::Validate;
+/// use serde_valid::clap::into_clap_error;
+///
+/// #[derive(Debug, Parser, Validate)]
+/// struct Cli {
+///     #[arg(long)]
+///     #[validate(maximum = 64)]
+///     max_workers: u32,
+/// }
+///
+/// let cli = Cli::parse_from(["app", "--max-workers", "100"]);
+/// let err = into_clap_error(cli.validate().unwrap_err(), &mut Cli::command());
+///
+/// assert_eq!(err.kind(), clap::error::ErrorKind::ValueValidation);
+/// assert!(err
+///     .to_string()
+///     .contains("invalid value for '--max-workers': The number must be `<= 64`."));
+/// ```
+pub fn into_clap_error(errors: Errors, cmd: &mut Command) -> clap::Error {
+    let mut lines = vec![];
+    collect_lines(errors, None, cmd, &mut lines);
+
+    clap::Error::raw(ErrorKind::ValueValidation, lines.join("\n")).format(cmd)
+}
+
+fn collect_lines(errors: Errors, name: Option<&str>, cmd: &Command, lines: &mut Vec<String>) {
+    match errors {
+        Errors::Object(ObjectErrors { errors, properties }) => {
+            push_lines(errors, name, lines);
+            for (field, errors) in properties {
+                match (find_arg(cmd, &field), name) {
+                    (Some(arg), _) => collect_lines(errors, Some(&arg_name(arg)), cmd, lines),
+                    // the arguments of the flattened groups are in the same command.
+                    (None, None) => collect_lines(errors, None, cmd, lines),
+                    (None, Some(_)) => collect_lines(errors, name, cmd, lines),
+                }
+            }
+        }
+        Errors::Array(array) => {
+            push_lines(array.errors, name, lines);
+            for (_, errors) in array.items {
+                collect_lines(errors, name, cmd, lines);
+            }
+        }
+        Errors::NewType(errors) => push_lines(errors, name, lines),
+    }
+}
+
+fn push_lines(errors: Vec<crate::validation::Error>, name: Option<&str>, lines: &mut Vec<String>) {
+    for error in errors {
+        lines.push(match name {
+            Some(name) => format!("invalid value for '{name}': {error}"),
+            None => error.to_string(),
+        });
+    }
+}
+
+fn find_arg<'a>(cmd: &'a Command, field: &str) -> Option<&'a Arg> {
+    cmd.get_arguments()
+        .find(|arg| arg.get_id() == field || arg.get_id() == field.replace('-', "_").as_str())
+}
+
+fn arg_name(arg: &Arg) -> String {
+    if let Some(long) = arg.get_long() {
+        format!("--{long}")
+    } else if let Some(short) = arg.get_short() {
+        format!("-{short}")
+    } else {
+        match arg.get_value_names().and_then(|names| names.first()) {
+            Some(name) => format!("<{name}>"),
+            None => format!("<{}>", arg.get_id().as_str().to_uppercase()),
+        }
+    }
+}
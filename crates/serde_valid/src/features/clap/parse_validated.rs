@@ -0,0 +1,83 @@
+use super::into_clap_error;
+
+/// Parse the command line arguments with [`clap::Parser`], and validate them.
+///
+/// The validation errors are reported by the flag names through [`into_clap_error`].
+pub trait ParseValidated
+where
+    Self: Sized,
+{
+    /// Parse and validate the arguments of the process, and exit on the error.
+    ///
+    /// ```rust,no_run
+    /// use clap::Parser;
+    /// use serde_valid::Validate;
+    /// use serde_valid::clap::ParseValidated;
+    ///
+    /// #[derive(Debug, Parser, Validate)]
+    /// struct Cli {
+    ///     #[arg(long, default_value_t = 4)]
+    ///     #[validate(minimum = 1)]
+    ///     #[validate(maximum = 64)]
+    ///     max_workers: u32,
+    /// }
+    ///
+    /// let cli = Cli::parse_validated();
+    /// ```
+    fn parse_validated() -> Self {
+        Self::try_parse_validated().unwrap_or_else(|error| error.exit())
+    }
+
+    /// Parse and validate the arguments of the process.
+    fn try_parse_validated() -> Result<Self, clap::Error> {
+        Self::try_parse_validated_from(std::env::args_os())
+    }
+
+    /// Parse and validate the given arguments, and exit on the error.
+    fn parse_validated_from<I, T>(itr: I) -> Self
+    where
+        I: IntoIterator<Item = T>,
+        T: Into<std::ffi::OsString> + Clone,
+    {
+        Self::try_parse_validated_from(itr).unwrap_or_else(|error| error.exit())
+    }
+
+    /// Parse and validate the given arguments.
+    ///
+    /// ```rust
+    /// use clap::Parser;
+    /// use serde_valid::Validate;
+    /// use serde_valid::clap::ParseValidated;
+    ///
+    /// #[derive(Debug, Parser, Validate)]
+    /// struct Cli {
+    ///     #[arg(long)]
+    ///     #[validate(maximum = 64)]
+    ///     max_workers: u32,
+    /// }
+    ///
+    /// let err = Cli::try_parse_validated_from(["app", "--max-workers", "100"]).unwrap_err();
+    ///
+    /// assert_eq!(err.exit_code(), 2);
+    /// ```
+    fn try_parse_validated_from<I, T>(itr: I) -> Result<Self, clap::Error>
+    where
+        I: IntoIterator<Item = T>,
+        T: Into<std::ffi::OsString> + Clone;
+}
+
+impl<P> ParseValidated for P
+where
+    P: clap::Parser + crate::Validate,
+{
+    fn try_parse_validated_from<I, T>(itr: I) -> Result<Self, clap::Error>
+    where
+        I: IntoIterator<Item = T>,
+        T: Into<std::ffi::OsString> + Clone,
+    {
+        let cli = P::try_parse_from(itr)?;
+        cli.validate()
+            .map_err(|errors| into_clap_error(errors, &mut P::command()))?;
+        Ok(cli)
+    }
+}
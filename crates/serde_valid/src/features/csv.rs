@@ -0,0 +1,4 @@
+mod from_csv_reader;
+mod missing_field;
+
+pub use from_csv_reader::{from_csv_reader, from_csv_reader_skip_invalid, CsvRecords};
@@ -0,0 +1,193 @@
+use indexmap::IndexMap;
+
+use crate::validation::error::Format;
+use crate::validation::{ArrayErrors, Errors, ItemErrorsMap, ObjectErrors};
+use crate::{RequiredError, TypeError};
+
+use super::missing_field::MissingField;
+
+/// Deserialize and validate all the records of the csv with the header row.
+///
+/// The errors of all the records are reported as [`ArrayErrors`] keyed by the line number
+/// of the record, where the header is the line 1, like the row number of the spreadsheets.
+/// The errors of the fields are keyed by the header name.
+///
+/// ```rust
+/// use serde::Deserialize;
+/// use serde_json::json;
+/// use serde_valid::Validate;
+/// use serde_valid::csv::from_csv_reader;
+///
+/// #[derive(Debug, Validate, Deserialize)]
+/// struct Customer {
+///     #[validate(min_length = 1)]
+///     name: String,
+///     #[validate(maximum = 150)]
+///     age: u32,
+/// }
+///
+/// let input = "name,age\nTaro,20\nJiro,200\n";
+/// let err = from_csv_reader::<Customer, _>(input.as_bytes()).unwrap_err();
+///
+/// assert_eq!(
+///     serde_json::to_value(err.as_validation_errors().unwrap()).unwrap(),
+///     json!({
+///         "errors": [],
+///         "items": {
+///             "3": {
+///                 "errors": [],
+///                 "properties": {
+///                     "age": {
+///                         "errors": ["The number must be `<= 150`."]
+///                     }
+///                 }
+///             }
+///         }
+///     })
+/// );
+/// ```
+pub fn from_csv_reader<T, R>(reader: R) -> Result<Vec<T>, crate::Error<csv::Error>>
+where
+    T: serde::de::DeserializeOwned + crate::Validate,
+    R: std::io::Read,
+{
+    let CsvRecords { records, errors } = from_csv_reader_skip_invalid(reader)?;
+    match errors {
+        Some(errors) => Err(crate::Error::ValidationError(errors)),
+        None => Ok(records),
+    }
+}
+
+/// Deserialize and validate the records of the csv, continuing past the invalid records.
+///
+/// The valid records are returned with the errors of the invalid records,
+/// which are reported in the same way as [`from_csv_reader`].
+/// Only the I/O error stops the reading, which is returned as [`crate::Error::DeserializeError`].
+///
+/// ```rust
+/// use serde::Deserialize;
+/// use serde_valid::Validate;
+/// use serde_valid::csv::from_csv_reader_skip_invalid;
+///
+/// #[derive(Debug, Validate, Deserialize)]
+/// struct Customer {
+///     #[validate(min_length = 1)]
+///     name: String,
+///     #[validate(maximum = 150)]
+///     age: u32,
+/// }
+///
+/// let input = "name,age\nTaro,20\nJiro,200\nSaburo,x\nShiro,30\n";
+/// let customers = from_csv_reader_skip_invalid::<Customer, _>(input.as_bytes()).unwrap();
+///
+/// assert_eq!(customers.records.len(), 2);
+/// assert!(customers.errors.is_some());
+/// ```
+pub fn from_csv_reader_skip_invalid<T, R>(
+    reader: R,
+) -> Result<CsvRecords<T>, crate::Error<csv::Error>>
+where
+    T: serde::de::DeserializeOwned + crate::Validate,
+    R: std::io::Read,
+{
+    let mut reader = csv::Reader::from_reader(reader);
+    let headers = reader.byte_headers()?.clone();
+    let header_name = |index: u64| {
+        headers
+            .get(index as usize)
+            .map(|name| String::from_utf8_lossy(name).into_owned())
+    };
+
+    let mut records = vec![];
+    let mut items: ItemErrorsMap<crate::validation::Error> = IndexMap::new();
+    let mut record = csv::ByteRecord::new();
+
+    loop {
+        let line = reader.position().line() as usize;
+        match reader.read_byte_record(&mut record) {
+            Ok(false) => break,
+            Ok(true) => {
+                let line = record
+                    .position()
+                    .map_or(line, |position| position.line() as usize);
+                match record.deserialize::<MissingField<T>>(Some(&headers)) {
+                    Ok(MissingField(Ok(model))) => match model.validate() {
+                        Ok(()) => records.push(model),
+                        Err(errors) => {
+                            items.insert(line, errors);
+                        }
+                    },
+                    Ok(MissingField(Err(field))) => {
+                        items.insert(line, missing_field_errors(field));
+                    }
+                    Err(error) => {
+                        items.insert(line, record_errors(&error, header_name));
+                    }
+                }
+            }
+            Err(error) if error.is_io_error() => return Err(crate::Error::DeserializeError(error)),
+            Err(error) => {
+                let line = error
+                    .position()
+                    .map_or(line, |position| position.line() as usize);
+                items.insert(line, record_errors(&error, header_name));
+            }
+        }
+    }
+
+    let errors = match items.is_empty() {
+        true => None,
+        false => Some(Errors::Array(ArrayErrors::new(vec![], items))),
+    };
+    Ok(CsvRecords { records, errors })
+}
+
+/// Valid records of the csv, and the errors of the invalid records.
+///
+/// Created by [`from_csv_reader_skip_invalid`].
+#[derive(Debug)]
+pub struct CsvRecords<T> {
+    pub records: Vec<T>,
+    /// [`ArrayErrors`] keyed by the line number of the invalid records.
+    pub errors: Option<Errors>,
+}
+
+/// Convert the error of the record into the validation errors, keyed by the header name if known.
+fn record_errors(error: &csv::Error, header_name: impl Fn(u64) -> Option<String>) -> Errors {
+    let (reason, field) = match error.kind() {
+        csv::ErrorKind::Deserialize { err, .. } => {
+            (err.kind().to_string(), err.field().and_then(&header_name))
+        }
+        csv::ErrorKind::Utf8 { err, .. } => (err.to_string(), header_name(err.field() as u64)),
+        csv::ErrorKind::UnequalLengths {
+            expected_len, len, ..
+        } => (
+            format!(
+                "found record with {len} fields, but the previous record has {expected_len} fields"
+            ),
+            None,
+        ),
+        _ => (error.to_string(), None),
+    };
+
+    let error =
+        crate::validation::Error::Type(Format::Default.into_message(TypeError::new(reason)));
+    match field {
+        Some(field) => field_errors(field, error),
+        None => Errors::NewType(vec![error]),
+    }
+}
+
+fn missing_field_errors(field: &str) -> Errors {
+    field_errors(
+        field.to_string(),
+        crate::validation::Error::Required(Format::Default.into_message(RequiredError)),
+    )
+}
+
+fn field_errors(field: String, error: crate::validation::Error) -> Errors {
+    Errors::Object(ObjectErrors::new(
+        vec![],
+        IndexMap::from([(field.into(), Errors::NewType(vec![error]))]),
+    ))
+}
@@ -0,0 +1,322 @@
+use serde::de::{self, DeserializeSeed, Deserializer, EnumAccess, MapAccess, SeqAccess, Visitor};
+
+/// Record which tells the missing field apart from the other deserialization errors.
+///
+/// The error of `csv` has only the message of the missing field,
+/// so the missing field is caught by the map of the struct, and carried out as the value.
+#[derive(Debug)]
+pub(super) struct MissingField<T>(pub Result<T, &'static str>);
+
+impl<'de, T> serde::Deserialize<'de> for MissingField<T>
+where
+    T: serde::Deserialize<'de>,
+{
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        match T::deserialize(Proxy(deserializer)) {
+            Ok(value) => Ok(Self(Ok(value))),
+            Err(Error::MissingField(field)) => Ok(Self(Err(field))),
+            Err(Error::Inner(error)) => Err(error),
+            Err(Error::Custom(message)) => Err(de::Error::custom(message)),
+        }
+    }
+}
+
+/// Error of [`Proxy`], which keeps the error of the inner deserializer as it is.
+#[derive(Debug)]
+enum Error<E> {
+    MissingField(&'static str),
+    Inner(E),
+    Custom(String),
+}
+
+impl<E> std::fmt::Display for Error<E>
+where
+    E: std::fmt::Display,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::MissingField(field) => write!(f, "missing field `{field}`"),
+            Self::Inner(error) => error.fmt(f),
+            Self::Custom(message) => f.write_str(message),
+        }
+    }
+}
+
+impl<E> std::error::Error for Error<E> where E: std::error::Error {}
+
+impl<E> de::Error for Error<E>
+where
+    E: de::Error,
+{
+    fn custom<T: std::fmt::Display>(message: T) -> Self {
+        Self::Custom(message.to_string())
+    }
+
+    fn missing_field(field: &'static str) -> Self {
+        Self::MissingField(field)
+    }
+}
+
+/// Back into the error of the inner deserializer, where the missing field is carried out as the value.
+fn into_inner<T, E>(result: Result<T, Error<E>>) -> Result<Result<T, &'static str>, E>
+where
+    E: de::Error,
+{
+    match result {
+        Ok(value) => Ok(Ok(value)),
+        Err(Error::MissingField(field)) => Ok(Err(field)),
+        Err(Error::Inner(error)) => Err(error),
+        Err(Error::Custom(message)) => Err(E::custom(message)),
+    }
+}
+
+/// Out of the value of [`ProxyVisitor`], where the missing field is carried in.
+fn from_inner<T, E>(result: Result<Result<T, &'static str>, E>) -> Result<T, Error<E>> {
+    match result {
+        Ok(Ok(value)) => Ok(value),
+        Ok(Err(field)) => Err(Error::MissingField(field)),
+        Err(error) => Err(Error::Inner(error)),
+    }
+}
+
+/// Deserializer which gives the maps with [`Error`], so that the missing field is caught.
+struct Proxy<D>(D);
+
+macro_rules! forward_deserialize {
+    ($($method:ident($($arg:ident: $type:ty),*),)*) => {
+        $(
+            fn $method<V>(self, $($arg: $type,)* visitor: V) -> Result<V::Value, Self::Error>
+            where
+                V: Visitor<'de>,
+            {
+                from_inner(self.0.$method($($arg,)* ProxyVisitor(visitor)))
+            }
+        )*
+    };
+}
+
+impl<'de, D> Deserializer<'de> for Proxy<D>
+where
+    D: Deserializer<'de>,
+{
+    type Error = Error<D::Error>;
+
+    forward_deserialize! {
+        deserialize_any(),
+        deserialize_bool(),
+        deserialize_i8(),
+        deserialize_i16(),
+        deserialize_i32(),
+        deserialize_i64(),
+        deserialize_i128(),
+        deserialize_u8(),
+        deserialize_u16(),
+        deserialize_u32(),
+        deserialize_u64(),
+        deserialize_u128(),
+        deserialize_f32(),
+        deserialize_f64(),
+        deserialize_char(),
+        deserialize_str(),
+        deserialize_string(),
+        deserialize_bytes(),
+        deserialize_byte_buf(),
+        deserialize_option(),
+        deserialize_unit(),
+        deserialize_unit_struct(name: &'static str),
+        deserialize_newtype_struct(name: &'static str),
+        deserialize_seq(),
+        deserialize_tuple(len: usize),
+        deserialize_tuple_struct(name: &'static str, len: usize),
+        deserialize_map(),
+        deserialize_struct(name: &'static str, fields: &'static [&'static str]),
+        deserialize_enum(name: &'static str, variants: &'static [&'static str]),
+        deserialize_identifier(),
+        deserialize_ignored_any(),
+    }
+
+    fn is_human_readable(&self) -> bool {
+        self.0.is_human_readable()
+    }
+}
+
+/// Visitor which gives the map with [`Error`], and carries the missing field out as the value.
+struct ProxyVisitor<V>(V);
+
+macro_rules! forward_visit {
+    ($($method:ident($type:ty),)*) => {
+        $(
+            fn $method<E>(self, value: $type) -> Result<Self::Value, E>
+            where
+                E: de::Error,
+            {
+                self.0.$method(value).map(Ok)
+            }
+        )*
+    };
+}
+
+impl<'de, V> Visitor<'de> for ProxyVisitor<V>
+where
+    V: Visitor<'de>,
+{
+    type Value = Result<V::Value, &'static str>;
+
+    fn expecting(&self, formatter: &mut std::fmt::Formatter) -> std::fmt::Result {
+        self.0.expecting(formatter)
+    }
+
+    forward_visit! {
+        visit_bool(bool),
+        visit_i8(i8),
+        visit_i16(i16),
+        visit_i32(i32),
+        visit_i64(i64),
+        visit_i128(i128),
+        visit_u8(u8),
+        visit_u16(u16),
+        visit_u32(u32),
+        visit_u64(u64),
+        visit_u128(u128),
+        visit_f32(f32),
+        visit_f64(f64),
+        visit_char(char),
+        visit_str(&str),
+        visit_borrowed_str(&'de str),
+        visit_string(String),
+        visit_bytes(&[u8]),
+        visit_borrowed_bytes(&'de [u8]),
+        visit_byte_buf(Vec<u8>),
+    }
+
+    fn visit_none<E>(self) -> Result<Self::Value, E>
+    where
+        E: de::Error,
+    {
+        self.0.visit_none().map(Ok)
+    }
+
+    fn visit_some<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        into_inner(self.0.visit_some(Proxy(deserializer)))
+    }
+
+    fn visit_unit<E>(self) -> Result<Self::Value, E>
+    where
+        E: de::Error,
+    {
+        self.0.visit_unit().map(Ok)
+    }
+
+    fn visit_newtype_struct<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        into_inner(self.0.visit_newtype_struct(Proxy(deserializer)))
+    }
+
+    fn visit_seq<A>(self, seq: A) -> Result<Self::Value, A::Error>
+    where
+        A: SeqAccess<'de>,
+    {
+        self.0.visit_seq(seq).map(Ok)
+    }
+
+    fn visit_map<A>(self, map: A) -> Result<Self::Value, A::Error>
+    where
+        A: MapAccess<'de>,
+    {
+        into_inner(self.0.visit_map(ProxyMap(map)))
+    }
+
+    fn visit_enum<A>(self, data: A) -> Result<Self::Value, A::Error>
+    where
+        A: EnumAccess<'de>,
+    {
+        self.0.visit_enum(data).map(Ok)
+    }
+}
+
+/// Map whose error is [`Error`], which the struct uses for the missing field.
+struct ProxyMap<A>(A);
+
+impl<'de, A> MapAccess<'de> for ProxyMap<A>
+where
+    A: MapAccess<'de>,
+{
+    type Error = Error<A::Error>;
+
+    fn next_key_seed<K>(&mut self, seed: K) -> Result<Option<K::Value>, Self::Error>
+    where
+        K: DeserializeSeed<'de>,
+    {
+        self.0.next_key_seed(seed).map_err(Error::Inner)
+    }
+
+    fn next_value_seed<V>(&mut self, seed: V) -> Result<V::Value, Self::Error>
+    where
+        V: DeserializeSeed<'de>,
+    {
+        self.0.next_value_seed(seed).map_err(Error::Inner)
+    }
+
+    fn size_hint(&self) -> Option<usize> {
+        self.0.size_hint()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Debug, serde::Deserialize)]
+    struct TestStruct {
+        name: String,
+        age: u32,
+    }
+
+    #[test]
+    fn test_ok() {
+        let headers = csv::ByteRecord::from(vec!["name", "age"]);
+        let record = csv::ByteRecord::from(vec!["Taro", "20"]);
+
+        let MissingField(result) = record
+            .deserialize::<MissingField<TestStruct>>(Some(&headers))
+            .unwrap();
+        let value = result.unwrap();
+
+        assert_eq!((value.name.as_str(), value.age), ("Taro", 20));
+    }
+
+    #[test]
+    fn test_missing_field() {
+        let headers = csv::ByteRecord::from(vec!["name"]);
+        let record = csv::ByteRecord::from(vec!["Taro"]);
+
+        let MissingField(result) = record
+            .deserialize::<MissingField<TestStruct>>(Some(&headers))
+            .unwrap();
+
+        assert_eq!(result.unwrap_err(), "age");
+    }
+
+    #[test]
+    fn test_other_error() {
+        let headers = csv::ByteRecord::from(vec!["name", "age"]);
+        let record = csv::ByteRecord::from(vec!["Taro", "x"]);
+
+        let error = record
+            .deserialize::<MissingField<TestStruct>>(Some(&headers))
+            .unwrap_err();
+
+        assert!(matches!(
+            error.kind(),
+            csv::ErrorKind::Deserialize { err, .. } if err.field() == Some(1)
+        ));
+    }
+}
@@ -0,0 +1,9 @@
+mod de;
+mod error;
+mod from_env;
+
+pub use error::Error;
+pub use from_env::FromEnv;
+
+/// Separator of the list items, like `APP_HOSTS=a,b`.
+const LIST_SEPARATOR: char = ',';
@@ -0,0 +1,259 @@
+use serde::de::{DeserializeSeed, IntoDeserializer, MapAccess, SeqAccess, Visitor};
+use serde::forward_to_deserialize_any;
+
+use super::{Error, LIST_SEPARATOR};
+use crate::env_vars::{field_key, Node};
+
+/// Parse the value with [`std::str::FromStr`], and report the error with the variable name.
+macro_rules! deserialize_parse {
+    ($($method:ident => $visit:ident,)*) => {
+        $(
+            fn $method<V>(self, visitor: V) -> Result<V::Value, Error>
+            where
+                V: Visitor<'de>,
+            {
+                match self {
+                    Node::Value { variable, value } => match value.trim().parse() {
+                        Ok(parsed) => visitor
+                            .$visit(parsed)
+                            .map_err(|error| with_variable(error, variable)),
+                        Err(error) => Err(Error::new(
+                            variable,
+                            format!("invalid value {value:?}: {error}"),
+                        )),
+                    },
+                    node => node.deserialize_any(visitor),
+                }
+            }
+        )*
+    };
+}
+
+impl<'de> serde::Deserializer<'de> for Node {
+    type Error = Error;
+
+    fn deserialize_any<V>(self, visitor: V) -> Result<V::Value, Error>
+    where
+        V: Visitor<'de>,
+    {
+        match self {
+            Node::Value { variable, value } => visitor
+                .visit_string(value)
+                .map_err(|error| with_variable(error, variable)),
+            Node::Map { prefix, entries } => visitor
+                .visit_map(MapDeserializer {
+                    entries: entries.into_iter(),
+                    value: None,
+                })
+                .map_err(|error| error.with_prefix(&prefix)),
+        }
+    }
+
+    fn deserialize_option<V>(self, visitor: V) -> Result<V::Value, Error>
+    where
+        V: Visitor<'de>,
+    {
+        visitor.visit_some(self)
+    }
+
+    fn deserialize_newtype_struct<V>(
+        self,
+        _name: &'static str,
+        visitor: V,
+    ) -> Result<V::Value, Error>
+    where
+        V: Visitor<'de>,
+    {
+        visitor.visit_newtype_struct(self)
+    }
+
+    fn deserialize_seq<V>(self, visitor: V) -> Result<V::Value, Error>
+    where
+        V: Visitor<'de>,
+    {
+        match self {
+            Node::Value { variable, value } => {
+                let items = match value.trim() {
+                    "" => vec![],
+                    value => value
+                        .split(LIST_SEPARATOR)
+                        .map(|item| item.trim().to_string())
+                        .collect(),
+                };
+                visitor
+                    .visit_seq(SeqDeserializer {
+                        variable: variable.clone(),
+                        items: items.into_iter(),
+                    })
+                    .map_err(|error| with_variable(error, variable))
+            }
+            node => node.deserialize_any(visitor),
+        }
+    }
+
+    fn deserialize_tuple<V>(self, _len: usize, visitor: V) -> Result<V::Value, Error>
+    where
+        V: Visitor<'de>,
+    {
+        self.deserialize_seq(visitor)
+    }
+
+    fn deserialize_tuple_struct<V>(
+        self,
+        _name: &'static str,
+        _len: usize,
+        visitor: V,
+    ) -> Result<V::Value, Error>
+    where
+        V: Visitor<'de>,
+    {
+        self.deserialize_seq(visitor)
+    }
+
+    fn deserialize_struct<V>(
+        self,
+        _name: &'static str,
+        fields: &'static [&'static str],
+        visitor: V,
+    ) -> Result<V::Value, Error>
+    where
+        V: Visitor<'de>,
+    {
+        match self {
+            Node::Map { prefix, entries } => {
+                // the lowercased keys are given as the field names, like `maxWorkers`.
+                let entries = entries
+                    .into_iter()
+                    .map(
+                        |(key, node)| match fields.iter().find(|field| field_key(field) == key) {
+                            Some(field) => (field.to_string(), node),
+                            None => (key, node),
+                        },
+                    )
+                    .collect();
+                Node::Map { prefix, entries }.deserialize_any(visitor)
+            }
+            node => node.deserialize_any(visitor),
+        }
+    }
+
+    fn deserialize_enum<V>(
+        self,
+        _name: &'static str,
+        _variants: &'static [&'static str],
+        visitor: V,
+    ) -> Result<V::Value, Error>
+    where
+        V: Visitor<'de>,
+    {
+        match self {
+            Node::Value { variable, value } => visitor
+                .visit_enum(IntoDeserializer::<Error>::into_deserializer(value))
+                .map_err(|error| with_variable(error, variable)),
+            node => node.deserialize_any(visitor),
+        }
+    }
+
+    fn deserialize_unit<V>(self, visitor: V) -> Result<V::Value, Error>
+    where
+        V: Visitor<'de>,
+    {
+        visitor.visit_unit()
+    }
+
+    fn deserialize_ignored_any<V>(self, visitor: V) -> Result<V::Value, Error>
+    where
+        V: Visitor<'de>,
+    {
+        visitor.visit_unit()
+    }
+
+    deserialize_parse! {
+        deserialize_bool => visit_bool,
+        deserialize_i8 => visit_i8,
+        deserialize_i16 => visit_i16,
+        deserialize_i32 => visit_i32,
+        deserialize_i64 => visit_i64,
+        deserialize_i128 => visit_i128,
+        deserialize_u8 => visit_u8,
+        deserialize_u16 => visit_u16,
+        deserialize_u32 => visit_u32,
+        deserialize_u64 => visit_u64,
+        deserialize_u128 => visit_u128,
+        deserialize_f32 => visit_f32,
+        deserialize_f64 => visit_f64,
+        deserialize_char => visit_char,
+    }
+
+    forward_to_deserialize_any! {
+        str string bytes byte_buf unit_struct map identifier
+    }
+}
+
+fn with_variable(error: Error, variable: String) -> Error {
+    match error.variable() {
+        Some(_) => error,
+        None => Error::new(variable, error.message()),
+    }
+}
+
+struct MapDeserializer {
+    entries: std::collections::btree_map::IntoIter<String, Node>,
+    value: Option<Node>,
+}
+
+impl<'de> MapAccess<'de> for MapDeserializer {
+    type Error = Error;
+
+    fn next_key_seed<K>(&mut self, seed: K) -> Result<Option<K::Value>, Error>
+    where
+        K: DeserializeSeed<'de>,
+    {
+        match self.entries.next() {
+            Some((key, value)) => {
+                self.value = Some(value);
+                seed.deserialize(IntoDeserializer::<Error>::into_deserializer(key))
+                    .map(Some)
+            }
+            None => Ok(None),
+        }
+    }
+
+    fn next_value_seed<V>(&mut self, seed: V) -> Result<V::Value, Error>
+    where
+        V: DeserializeSeed<'de>,
+    {
+        match self.value.take() {
+            Some(value) => seed.deserialize(value),
+            None => Err(serde::de::Error::custom("value is missing")),
+        }
+    }
+}
+
+struct SeqDeserializer {
+    variable: String,
+    items: std::vec::IntoIter<String>,
+}
+
+impl<'de> SeqAccess<'de> for SeqDeserializer {
+    type Error = Error;
+
+    fn next_element_seed<T>(&mut self, seed: T) -> Result<Option<T::Value>, Error>
+    where
+        T: DeserializeSeed<'de>,
+    {
+        match self.items.next() {
+            Some(value) => seed
+                .deserialize(Node::Value {
+                    variable: self.variable.clone(),
+                    value,
+                })
+                .map(Some),
+            None => Ok(None),
+        }
+    }
+
+    fn size_hint(&self) -> Option<usize> {
+        Some(self.items.len())
+    }
+}
@@ -0,0 +1,76 @@
+/// Error of the deserialization from the environment variables.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Error {
+    variable: Option<String>,
+    message: String,
+    missing_field: Option<&'static str>,
+}
+
+impl Error {
+    pub(super) fn new(variable: impl Into<String>, message: impl std::fmt::Display) -> Self {
+        Self {
+            variable: Some(variable.into()),
+            message: message.to_string(),
+            missing_field: None,
+        }
+    }
+
+    /// Name of the environment variable which caused the error.
+    pub fn variable(&self) -> Option<&str> {
+        self.variable.as_deref()
+    }
+
+    pub fn message(&self) -> &str {
+        &self.message
+    }
+
+    /// Attach the variable name to the error raised inside the nested keys of `prefix`.
+    pub(super) fn with_prefix(mut self, prefix: &str) -> Self {
+        if self.variable.is_none() {
+            match self.missing_field.take() {
+                Some(field) => {
+                    self.variable = Some(format!("{prefix}{}", field.to_uppercase()));
+                    self.message = "missing environment variable".to_string();
+                }
+                None => {
+                    if let Some(variable) = prefix.strip_suffix(crate::env_vars::SEPARATOR) {
+                        self.variable = Some(variable.to_string());
+                    }
+                }
+            }
+        }
+        self
+    }
+}
+
+impl std::fmt::Display for Error {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match &self.variable {
+            Some(variable) => write!(f, "{variable}: {}", self.message),
+            None => f.write_str(&self.message),
+        }
+    }
+}
+
+impl std::error::Error for Error {}
+
+impl serde::de::Error for Error {
+    fn custom<T>(msg: T) -> Self
+    where
+        T: std::fmt::Display,
+    {
+        Self {
+            variable: None,
+            message: msg.to_string(),
+            missing_field: None,
+        }
+    }
+
+    fn missing_field(field: &'static str) -> Self {
+        Self {
+            variable: None,
+            message: format!("missing field `{field}`"),
+            missing_field: Some(field),
+        }
+    }
+}
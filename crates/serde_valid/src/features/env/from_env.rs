@@ -0,0 +1,160 @@
+use std::borrow::Cow;
+
+use indexmap::IndexMap;
+
+use crate::env_vars::Node;
+use crate::validation::{Error, Errors, ObjectErrors, PropertyErrorsMap};
+
+pub trait FromEnv
+where
+    Self: Sized,
+{
+    /// Load from the environment variables starting with `prefix`.
+    ///
+    /// The nested fields are separated by `__`, like `APP_DB__HOST` for `db.host`,
+    /// and the lists are separated by `,`, like `APP_HOSTS=a,b`.
+    /// The names are matched to the fields case-insensitively, like `APP_MAXWORKERS` for `maxWorkers`.
+    /// The validation errors are keyed by the name of the environment variables.
+    ///
+    /// ```rust
+    /// use serde::Deserialize;
+    /// use serde_valid::Validate;
+    /// use serde_valid::env::FromEnv;
+    ///
+    /// #[derive(Debug, Validate, Deserialize)]
+    /// struct Config {
+    ///     #[validate]
+    ///     db: Db,
+    /// }
+    ///
+    /// #[derive(Debug, Validate, Deserialize)]
+    /// struct Db {
+    ///     #[validate(min_length = 1)]
+    ///     host: String,
+    /// }
+    ///
+    /// let config = Config::from_env_prefixed("SERDE_VALID_DOC_");
+    ///
+    /// assert!(config.is_err());
+    /// ```
+    fn from_env_prefixed(prefix: &str) -> Result<Self, crate::Error<super::Error>> {
+        Self::from_vars_prefixed(
+            std::env::vars_os().filter_map(|(key, value)| {
+                Some((key.into_string().ok()?, value.into_string().ok()?))
+            }),
+            prefix,
+        )
+    }
+
+    /// Load from the given pairs of the variable names and values, in the same way as
+    /// [`FromEnv::from_env_prefixed`].
+    ///
+    /// ```rust
+    /// use serde::Deserialize;
+    /// use serde_json::json;
+    /// use serde_valid::Validate;
+    /// use serde_valid::env::FromEnv;
+    ///
+    /// #[derive(Debug, Validate, Deserialize)]
+    /// struct Config {
+    ///     #[validate(max_items = 2)]
+    ///     hosts: Vec<String>,
+    ///     #[validate]
+    ///     db: Db,
+    /// }
+    ///
+    /// #[derive(Debug, Validate, Deserialize)]
+    /// struct Db {
+    ///     #[validate(maximum = 100)]
+    ///     pool_size: u32,
+    /// }
+    ///
+    /// let err = Config::from_vars_prefixed(
+    ///     [("APP_HOSTS", "a,b,c"), ("APP_DB__POOL_SIZE", "10")],
+    ///     "APP_",
+    /// )
+    /// .unwrap_err();
+    ///
+    /// assert_eq!(
+    ///     serde_json::to_value(err.as_validation_errors().unwrap()).unwrap(),
+    ///     json!({
+    ///         "errors": [],
+    ///         "properties": {
+    ///             "APP_HOSTS": {
+    ///                 "errors": ["The length of the items must be `<= 2`."]
+    ///             }
+    ///         }
+    ///     })
+    /// );
+    /// ```
+    fn from_vars_prefixed<I, K, V>(
+        vars: I,
+        prefix: &str,
+    ) -> Result<Self, crate::Error<super::Error>>
+    where
+        I: IntoIterator<Item = (K, V)>,
+        K: Into<String>,
+        V: Into<String>;
+}
+
+impl<T> FromEnv for T
+where
+    T: serde::de::DeserializeOwned + crate::Validate,
+{
+    fn from_vars_prefixed<I, K, V>(
+        vars: I,
+        prefix: &str,
+    ) -> Result<Self, crate::Error<super::Error>>
+    where
+        I: IntoIterator<Item = (K, V)>,
+        K: Into<String>,
+        V: Into<String>,
+    {
+        let node = Node::from_vars(vars, prefix);
+        let model = T::deserialize(node.clone())?;
+        model
+            .validate()
+            .map_err(|errors| crate::Error::ValidationError(rekey_errors(errors, &node)))?;
+        Ok(model)
+    }
+}
+
+/// Key the errors by the name of the environment variables, like `APP_DB__HOST`.
+fn rekey_errors(errors: Errors, node: &Node) -> Errors {
+    match errors {
+        Errors::Object(object) => {
+            let mut properties = IndexMap::new();
+            collect_properties(object.properties, node, &mut properties);
+            Errors::Object(ObjectErrors::new(object.errors, properties))
+        }
+        errors => errors,
+    }
+}
+
+fn collect_properties(
+    fields: PropertyErrorsMap<Error>,
+    node: &Node,
+    properties: &mut PropertyErrorsMap<Error>,
+) {
+    for (field, errors) in fields {
+        let variable = node.field_variable(&field);
+        match errors {
+            Errors::Object(object) => {
+                if !object.errors.is_empty() {
+                    insert(properties, variable, Errors::NewType(object.errors));
+                }
+                collect_properties(object.properties, &node.field_node(&field), properties);
+            }
+            errors => insert(properties, variable, errors),
+        }
+    }
+}
+
+fn insert(properties: &mut PropertyErrorsMap<Error>, variable: String, errors: Errors) {
+    match properties.get_mut(variable.as_str()) {
+        Some(other) => other.merge(errors),
+        None => {
+            properties.insert(Cow::Owned(variable), errors);
+        }
+    }
+}
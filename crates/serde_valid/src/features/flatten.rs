@@ -4,4 +4,5 @@ mod into_flat;
 
 pub use flat_error::FlatError;
 pub use flat_errors::FlatErrors;
+pub(crate) use into_flat::escape_pointer_token;
 pub use into_flat::IntoFlat;
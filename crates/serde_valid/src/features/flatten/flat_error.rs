@@ -0,0 +1,25 @@
+/// Validation error with the [JSON Pointer](https://datatracker.ietf.org/doc/html/rfc6901)
+/// to the invalid value.
+#[derive(Debug, Clone, serde::Serialize)]
+pub struct FlatError<E = crate::validation::Error> {
+    pub pointer: String,
+    pub error: E,
+}
+
+impl<E> FlatError<E> {
+    pub fn new(pointer: impl Into<String>, error: E) -> Self {
+        Self {
+            pointer: pointer.into(),
+            error,
+        }
+    }
+}
+
+impl<E> std::fmt::Display for FlatError<E>
+where
+    E: std::fmt::Display,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}: {}", self.pointer, self.error)
+    }
+}
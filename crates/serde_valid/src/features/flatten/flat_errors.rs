@@ -0,0 +1,60 @@
+use super::FlatError;
+
+#[derive(Debug, Clone, Default, serde::Serialize)]
+#[serde(transparent)]
+pub struct FlatErrors<E = crate::validation::Error>(Vec<FlatError<E>>);
+
+impl<E> FlatErrors<E> {
+    pub fn new(errors: Vec<FlatError<E>>) -> Self {
+        Self(errors)
+    }
+
+    pub fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    pub fn iter(&self) -> std::slice::Iter<'_, FlatError<E>> {
+        self.0.iter()
+    }
+
+    pub fn into_vec(self) -> Vec<FlatError<E>> {
+        self.0
+    }
+}
+
+impl<E> IntoIterator for FlatErrors<E> {
+    type Item = FlatError<E>;
+    type IntoIter = std::vec::IntoIter<FlatError<E>>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.0.into_iter()
+    }
+}
+
+impl<'a, E> IntoIterator for &'a FlatErrors<E> {
+    type Item = &'a FlatError<E>;
+    type IntoIter = std::slice::Iter<'a, FlatError<E>>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.0.iter()
+    }
+}
+
+impl<E> std::fmt::Display for FlatErrors<E>
+where
+    E: std::fmt::Display,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for (index, error) in self.0.iter().enumerate() {
+            if index != 0 {
+                writeln!(f)?;
+            }
+            write!(f, "{error}")?;
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,122 @@
+use crate::validation::{ArrayErrors, Error, Errors, ObjectErrors, VecErrors};
+
+use super::{FlatError, FlatErrors};
+
+/// Flatten the errors tree into the list of errors with [JSON Pointer](https://datatracker.ietf.org/doc/html/rfc6901).
+///
+/// # Examples
+/// ```rust
+/// use serde_valid::flatten::IntoFlat;
+/// use serde_valid::Validate;
+///
+/// #[derive(Validate)]
+/// struct Data {
+///     #[validate(maximum = 5)]
+///     val: Vec<i32>,
+/// }
+///
+/// let errors = Data { val: vec![1, 6] }.validate().unwrap_err().into_flat();
+///
+/// assert_eq!(errors.to_string(), "/val/1: The number must be `<= 5`.");
+/// ```
+pub trait IntoFlat
+where
+    Self: Sized,
+{
+    fn into_flat(self) -> FlatErrors {
+        let mut errors = vec![];
+        self.into_flat_at(&mut errors, "");
+        FlatErrors::new(errors)
+    }
+
+    fn into_flat_at(self, errors: &mut Vec<FlatError>, pointer: &str);
+}
+
+impl IntoFlat for Errors {
+    fn into_flat_at(self, errors: &mut Vec<FlatError>, pointer: &str) {
+        match self {
+            Errors::Array(array_errors) => array_errors.into_flat_at(errors, pointer),
+            Errors::Object(object_errors) => object_errors.into_flat_at(errors, pointer),
+            Errors::NewType(vec_errors) => vec_errors.into_flat_at(errors, pointer),
+        }
+    }
+}
+
+impl IntoFlat for ArrayErrors {
+    fn into_flat_at(self, errors: &mut Vec<FlatError>, pointer: &str) {
+        self.errors.into_flat_at(errors, pointer);
+        for (index, item_errors) in self.items {
+            item_errors.into_flat_at(errors, &format!("{pointer}/{index}"));
+        }
+    }
+}
+
+impl IntoFlat for ObjectErrors {
+    fn into_flat_at(self, errors: &mut Vec<FlatError>, pointer: &str) {
+        self.errors.into_flat_at(errors, pointer);
+        for (property, property_errors) in self.properties {
+            property_errors.into_flat_at(
+                errors,
+                &format!("{pointer}/{}", escape_pointer_token(&property)),
+            );
+        }
+    }
+}
+
+impl IntoFlat for VecErrors {
+    fn into_flat_at(self, errors: &mut Vec<FlatError>, pointer: &str) {
+        for error in self {
+            match error {
+                Error::Items(array_errors) => array_errors.into_flat_at(errors, pointer),
+                Error::Properties(object_errors) => object_errors.into_flat_at(errors, pointer),
+                _ => errors.push(FlatError::new(pointer, error)),
+            }
+        }
+    }
+}
+
+impl IntoFlat for &Errors {
+    fn into_flat_at(self, errors: &mut Vec<FlatError>, pointer: &str) {
+        self.clone().into_flat_at(errors, pointer)
+    }
+}
+
+/// Escape the reference token of the JSON Pointer.
+pub(crate) fn escape_pointer_token(token: &str) -> String {
+    token.replace('~', "~0").replace('/', "~1")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use indexmap::indexmap;
+
+    #[test]
+    fn test_escape_pointer_token() {
+        assert_eq!(escape_pointer_token("a/b~c"), "a~1b~0c");
+    }
+
+    #[test]
+    fn test_into_flat() {
+        let errors = Errors::Object(ObjectErrors::new(
+            vec![Error::Custom("root".to_string())],
+            indexmap! {
+                "a/b".into() => Errors::Array(ArrayErrors::new(
+                    vec![],
+                    indexmap! {
+                        2 => Errors::NewType(vec![Error::Custom("item".to_string())]),
+                    },
+                )),
+            },
+        ));
+
+        assert_eq!(
+            errors
+                .into_flat()
+                .iter()
+                .map(|error| (error.pointer.as_str(), error.error.to_string()))
+                .collect::<Vec<_>>(),
+            vec![("", "root".to_string()), ("/a~1b/2", "item".to_string())]
+        );
+    }
+}
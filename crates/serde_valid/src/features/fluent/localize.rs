@@ -107,6 +107,10 @@ impl Localize for crate::validation::Error {
             Self::ExclusiveMinimum(message) => message.localize(bundle),
             Self::ExclusiveMaximum(message) => message.localize(bundle),
             Self::MultipleOf(message) => message.localize(bundle),
+            Self::Past(message) => message.localize(bundle),
+            Self::Future(message) => message.localize(bundle),
+            Self::Within(message) => message.localize(bundle),
+            Self::OlderThan(message) => message.localize(bundle),
             Self::MinLength(message) => message.localize(bundle),
             Self::MaxLength(message) => message.localize(bundle),
             Self::Pattern(message) => message.localize(bundle),
@@ -116,6 +120,8 @@ impl Localize for crate::validation::Error {
             Self::MinProperties(message) => message.localize(bundle),
             Self::MaxProperties(message) => message.localize(bundle),
             Self::Enum(message) => message.localize(bundle),
+            Self::Type(message) => message.localize(bundle),
+            Self::Required(message) => message.localize(bundle),
             Self::Custom(message) => LocalizedError::String(message.to_string()),
             Self::Items(message) => LocalizedError::Items(message.localize(bundle)),
             Self::Properties(message) => LocalizedError::Properties(message.localize(bundle)),
@@ -251,7 +257,7 @@ mod test {
                 args: vec![("name", FluentValue::from("John"))],
             })
             .into_message(crate::MaximumError {
-                maximum: serde_valid_literal::Number::I32(10),
+                maximum: serde_valid_literal::Number::I32(10).into(),
             }),
         );
 
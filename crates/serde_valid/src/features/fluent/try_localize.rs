@@ -175,6 +175,10 @@ impl TryLocalize for crate::validation::Error {
             Self::ExclusiveMinimum(message) => message.try_localize(bundle),
             Self::ExclusiveMaximum(message) => message.try_localize(bundle),
             Self::MultipleOf(message) => message.try_localize(bundle),
+            Self::Past(message) => message.try_localize(bundle),
+            Self::Future(message) => message.try_localize(bundle),
+            Self::Within(message) => message.try_localize(bundle),
+            Self::OlderThan(message) => message.try_localize(bundle),
             Self::MinLength(message) => message.try_localize(bundle),
             Self::MaxLength(message) => message.try_localize(bundle),
             Self::Pattern(message) => message.try_localize(bundle),
@@ -184,6 +188,8 @@ impl TryLocalize for crate::validation::Error {
             Self::MinProperties(message) => message.try_localize(bundle),
             Self::MaxProperties(message) => message.try_localize(bundle),
             Self::Enum(message) => message.try_localize(bundle),
+            Self::Type(message) => message.try_localize(bundle),
+            Self::Required(message) => message.try_localize(bundle),
             Self::Custom(message) => Ok(LocalizedError::String(message.to_string())),
             Self::Items(message) => Ok(LocalizedError::Items(message.try_localize(bundle)?)),
             Self::Properties(message) => {
@@ -314,7 +320,7 @@ mod test {
                 args: vec![("name", FluentValue::from("John"))],
             })
             .into_message(crate::MaximumError {
-                maximum: serde_valid_literal::Number::I32(10),
+                maximum: serde_valid_literal::Number::I32(10).into(),
             }),
         );
 
@@ -0,0 +1,13 @@
+mod from_json5_reader;
+mod from_json5_slice;
+mod from_json5_str;
+mod from_json5_value;
+mod to_json5_string;
+
+pub use serde_json::Value;
+
+pub use from_json5_reader::FromJson5Reader;
+pub use from_json5_slice::FromJson5Slice;
+pub use from_json5_str::FromJson5Str;
+pub use from_json5_value::FromJson5Value;
+pub use to_json5_string::ToJson5String;
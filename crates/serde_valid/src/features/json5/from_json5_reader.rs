@@ -0,0 +1,48 @@
+pub trait FromJson5Reader
+where
+    Self: Sized,
+{
+    /// Convert from json5 reader.
+    ///
+    /// ```should_panic
+    /// use std::fs::File;
+    /// use serde::Deserialize;
+    /// use serde_valid::Validate;
+    /// use serde_valid::json5::FromJson5Reader;
+    ///
+    /// #[derive(Debug, Validate, Deserialize)]
+    /// struct TestStruct {
+    ///     #[validate(maximum = 2000)]
+    ///     val: i32,
+    /// }
+    ///
+    /// let s = TestStruct::from_json5_reader(File::open("foo.txt").unwrap());
+    ///
+    /// assert!(s.is_ok())
+    /// ```
+    fn from_json5_reader<R>(reader: R) -> Result<Self, crate::Error<json5::Error>>
+    where
+        R: std::io::Read;
+}
+
+impl<T> FromJson5Reader for T
+where
+    T: serde::de::DeserializeOwned + crate::Validate,
+{
+    fn from_json5_reader<R>(reader: R) -> Result<Self, crate::Error<json5::Error>>
+    where
+        R: std::io::Read,
+    {
+        use serde::de::Error;
+
+        let mut buffer = String::new();
+        let mut reader = reader;
+        reader
+            .read_to_string(&mut buffer)
+            .map_err(json5::Error::custom)?;
+
+        let model: T = json5::from_str(&buffer)?;
+        model.validate().map_err(crate::Error::ValidationError)?;
+        Ok(model)
+    }
+}
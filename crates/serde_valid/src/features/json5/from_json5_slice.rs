@@ -0,0 +1,36 @@
+pub trait FromJson5Slice<'de>
+where
+    Self: Sized,
+{
+    /// Convert from json5 slice.
+    ///
+    /// ```rust
+    /// use serde::Deserialize;
+    /// use serde_valid::Validate;
+    /// use serde_valid::json5::FromJson5Slice;
+    ///
+    /// #[derive(Debug, Validate, Deserialize)]
+    /// struct TestStruct {
+    ///     #[validate(min_length = 1)]
+    ///     val: String,
+    /// }
+    ///
+    /// let s = TestStruct::from_json5_slice(b"{ val: 'abcde', }");
+    ///
+    /// assert!(s.is_ok())
+    /// ```
+    fn from_json5_slice(slice: &'de [u8]) -> Result<Self, crate::Error<json5::Error>>;
+}
+
+impl<'de, T> FromJson5Slice<'de> for T
+where
+    T: serde::de::Deserialize<'de> + crate::Validate,
+{
+    fn from_json5_slice(slice: &'de [u8]) -> Result<Self, crate::Error<json5::Error>> {
+        use serde::de::Error;
+
+        let model: T = json5::from_str(std::str::from_utf8(slice).map_err(json5::Error::custom)?)?;
+        model.validate().map_err(crate::Error::ValidationError)?;
+        Ok(model)
+    }
+}
@@ -0,0 +1,34 @@
+pub trait FromJson5Str<'de>
+where
+    Self: Sized,
+{
+    /// Convert from json5 str.
+    ///
+    /// ```rust
+    /// use serde::Deserialize;
+    /// use serde_valid::Validate;
+    /// use serde_valid::json5::FromJson5Str;
+    ///
+    /// #[derive(Debug, Validate, Deserialize)]
+    /// struct TestStruct {
+    ///     #[validate(min_length = 1)]
+    ///     val: String,
+    /// }
+    ///
+    /// let s = TestStruct::from_json5_str("{ val: 'abcde', /* comment */ }");
+    ///
+    /// assert!(s.is_ok())
+    /// ```
+    fn from_json5_str(str: &'de str) -> Result<Self, crate::Error<json5::Error>>;
+}
+
+impl<'de, T> FromJson5Str<'de> for T
+where
+    T: serde::de::Deserialize<'de> + crate::Validate,
+{
+    fn from_json5_str(str: &'de str) -> Result<Self, crate::Error<json5::Error>> {
+        let model: T = json5::from_str(str)?;
+        model.validate().map_err(crate::Error::ValidationError)?;
+        Ok(model)
+    }
+}
@@ -0,0 +1,38 @@
+pub trait FromJson5Value
+where
+    Self: Sized,
+{
+    /// Convert from [`serde_json::Value`](serde_json::Value),
+    /// which shares the data model with json5.
+    ///
+    /// ```rust
+    /// use serde::Deserialize;
+    /// use serde_valid::Validate;
+    /// use serde_valid::json5::{FromJson5Value, Value};
+    ///
+    /// #[derive(Debug, Validate, Deserialize)]
+    /// struct TestStruct {
+    ///     #[validate(maximum = 2000)]
+    ///     val: i32,
+    /// }
+    ///
+    /// let value: Value = json5::from_str("{ val: 1234 }").unwrap();
+    /// let s = TestStruct::from_json5_value(value);
+    ///
+    /// assert!(s.is_ok())
+    /// ```
+    fn from_json5_value(value: serde_json::Value) -> Result<Self, crate::Error<json5::Error>>;
+}
+
+impl<T> FromJson5Value for T
+where
+    T: serde::de::DeserializeOwned + crate::Validate,
+{
+    fn from_json5_value(value: serde_json::Value) -> Result<Self, crate::Error<json5::Error>> {
+        use serde::de::Error;
+
+        let model: T = serde::Deserialize::deserialize(value).map_err(json5::Error::custom)?;
+        model.validate().map_err(crate::Error::ValidationError)?;
+        Ok(model)
+    }
+}
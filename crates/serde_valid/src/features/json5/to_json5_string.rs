@@ -0,0 +1,58 @@
+pub trait ToJson5String {
+    /// Convert to json5 string.
+    ///
+    /// ```rust
+    /// use serde::Serialize;
+    /// use serde_valid::json5::ToJson5String;
+    /// use serde_valid::Validate;
+    ///
+    /// #[derive(Debug, Validate, Serialize)]
+    /// struct TestStruct {
+    ///     #[validate(maximum = 100)]
+    ///     val: i32,
+    /// }
+    /// let s = TestStruct { val: 10 };
+    ///
+    /// assert!(s.to_json5_string().is_ok());
+    /// ```
+    fn to_json5_string(&self) -> Result<String, json5::Error>;
+
+    /// Convert to json5 string after validation.
+    ///
+    /// ```rust
+    /// use serde::Serialize;
+    /// use serde_valid::json5::ToJson5String;
+    /// use serde_valid::Validate;
+    ///
+    /// #[derive(Debug, Validate, Serialize)]
+    /// struct TestStruct {
+    ///     #[validate(maximum = 100)]
+    ///     val: i32,
+    /// }
+    /// let s = TestStruct { val: 1000 };
+    ///
+    /// assert!(s.to_json5_string_validated().unwrap_err().is_validation_errors());
+    /// ```
+    fn to_json5_string_validated(&self) -> Result<String, crate::Error<json5::Error>>
+    where
+        Self: crate::Validate,
+    {
+        self.validate().map_err(crate::Error::ValidationError)?;
+        Ok(self.to_json5_string()?)
+    }
+}
+
+impl<T> ToJson5String for T
+where
+    T: serde::Serialize + crate::Validate,
+{
+    fn to_json5_string(&self) -> Result<String, json5::Error> {
+        json5::to_string(self)
+    }
+}
+
+impl ToJson5String for serde_json::Value {
+    fn to_json5_string(&self) -> Result<String, json5::Error> {
+        json5::to_string(self)
+    }
+}
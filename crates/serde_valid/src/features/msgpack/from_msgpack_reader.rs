@@ -0,0 +1,40 @@
+pub trait FromMsgpackReader
+where
+    Self: Sized,
+{
+    /// Convert from msgpack reader.
+    ///
+    /// ```should_panic
+    /// use std::fs::File;
+    /// use serde::Deserialize;
+    /// use serde_valid::Validate;
+    /// use serde_valid::msgpack::FromMsgpackReader;
+    ///
+    /// #[derive(Debug, Validate, Deserialize)]
+    /// struct TestStruct {
+    ///     #[validate(maximum = 2000)]
+    ///     val: i32,
+    /// }
+    ///
+    /// let s = TestStruct::from_msgpack_reader(File::open("foo.msgpack").unwrap());
+    ///
+    /// assert!(s.is_ok())
+    /// ```
+    fn from_msgpack_reader<R>(reader: R) -> Result<Self, crate::Error<rmp_serde::decode::Error>>
+    where
+        R: std::io::Read;
+}
+
+impl<T> FromMsgpackReader for T
+where
+    T: serde::de::DeserializeOwned + crate::Validate,
+{
+    fn from_msgpack_reader<R>(reader: R) -> Result<Self, crate::Error<rmp_serde::decode::Error>>
+    where
+        R: std::io::Read,
+    {
+        let model: T = rmp_serde::from_read(reader)?;
+        model.validate().map_err(crate::Error::ValidationError)?;
+        Ok(model)
+    }
+}
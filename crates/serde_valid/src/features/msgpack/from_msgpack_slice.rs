@@ -0,0 +1,40 @@
+pub trait FromMsgpackSlice<'de>
+where
+    Self: Sized,
+{
+    /// Convert from msgpack slice.
+    ///
+    /// Both the named (map) and the compact (array) encodings of the structs are accepted.
+    ///
+    /// ```rust
+    /// use serde::{Deserialize, Serialize};
+    /// use serde_valid::Validate;
+    /// use serde_valid::msgpack::{FromMsgpackSlice, ToMsgpackVec};
+    ///
+    /// #[derive(Debug, Validate, Deserialize, Serialize)]
+    /// struct TestStruct<'a> {
+    ///     #[validate(min_length = 1)]
+    ///     val: &'a str,
+    /// }
+    ///
+    /// let bytes = TestStruct { val: "abcde" }.to_msgpack_vec_named().unwrap();
+    /// let s = TestStruct::from_msgpack_slice(&bytes);
+    ///
+    /// assert!(s.is_ok())
+    /// ```
+    fn from_msgpack_slice(slice: &'de [u8])
+        -> Result<Self, crate::Error<rmp_serde::decode::Error>>;
+}
+
+impl<'de, T> FromMsgpackSlice<'de> for T
+where
+    T: serde::de::Deserialize<'de> + crate::Validate,
+{
+    fn from_msgpack_slice(
+        slice: &'de [u8],
+    ) -> Result<Self, crate::Error<rmp_serde::decode::Error>> {
+        let model: T = rmp_serde::from_slice(slice)?;
+        model.validate().map_err(crate::Error::ValidationError)?;
+        Ok(model)
+    }
+}
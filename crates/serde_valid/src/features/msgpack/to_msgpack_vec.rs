@@ -0,0 +1,100 @@
+pub trait ToMsgpackVec {
+    /// Convert to msgpack bytes, encoding the structs as arrays.
+    ///
+    /// ```rust
+    /// use serde::Serialize;
+    /// use serde_valid::msgpack::ToMsgpackVec;
+    /// use serde_valid::Validate;
+    ///
+    /// #[derive(Debug, Validate, Serialize)]
+    /// struct TestStruct {
+    ///     #[validate(maximum = 100)]
+    ///     val: i32,
+    /// }
+    /// let s = TestStruct { val: 10 };
+    ///
+    /// assert_eq!(s.to_msgpack_vec().unwrap(), b"\x91\x0a");
+    /// ```
+    fn to_msgpack_vec(&self) -> Result<Vec<u8>, rmp_serde::encode::Error>;
+
+    /// Convert to msgpack bytes, encoding the structs as maps with the field names.
+    ///
+    /// ```rust
+    /// use serde::Serialize;
+    /// use serde_valid::msgpack::ToMsgpackVec;
+    /// use serde_valid::Validate;
+    ///
+    /// #[derive(Debug, Validate, Serialize)]
+    /// struct TestStruct {
+    ///     #[validate(maximum = 100)]
+    ///     val: i32,
+    /// }
+    /// let s = TestStruct { val: 10 };
+    ///
+    /// assert_eq!(s.to_msgpack_vec_named().unwrap(), b"\x81\xa3val\x0a");
+    /// ```
+    fn to_msgpack_vec_named(&self) -> Result<Vec<u8>, rmp_serde::encode::Error>;
+
+    /// Convert to msgpack bytes after validation.
+    ///
+    /// ```rust
+    /// use serde::Serialize;
+    /// use serde_valid::msgpack::ToMsgpackVec;
+    /// use serde_valid::Validate;
+    ///
+    /// #[derive(Debug, Validate, Serialize)]
+    /// struct TestStruct {
+    ///     #[validate(maximum = 100)]
+    ///     val: i32,
+    /// }
+    /// let s = TestStruct { val: 1000 };
+    ///
+    /// assert!(s.to_msgpack_vec_validated().unwrap_err().is_validation_errors());
+    /// ```
+    fn to_msgpack_vec_validated(&self) -> Result<Vec<u8>, crate::Error<rmp_serde::encode::Error>>
+    where
+        Self: crate::Validate,
+    {
+        self.validate().map_err(crate::Error::ValidationError)?;
+        Ok(self.to_msgpack_vec()?)
+    }
+
+    /// Convert to named msgpack bytes after validation.
+    ///
+    /// ```rust
+    /// use serde::Serialize;
+    /// use serde_valid::msgpack::ToMsgpackVec;
+    /// use serde_valid::Validate;
+    ///
+    /// #[derive(Debug, Validate, Serialize)]
+    /// struct TestStruct {
+    ///     #[validate(maximum = 100)]
+    ///     val: i32,
+    /// }
+    /// let s = TestStruct { val: 1000 };
+    ///
+    /// assert!(s.to_msgpack_vec_named_validated().unwrap_err().is_validation_errors());
+    /// ```
+    fn to_msgpack_vec_named_validated(
+        &self,
+    ) -> Result<Vec<u8>, crate::Error<rmp_serde::encode::Error>>
+    where
+        Self: crate::Validate,
+    {
+        self.validate().map_err(crate::Error::ValidationError)?;
+        Ok(self.to_msgpack_vec_named()?)
+    }
+}
+
+impl<T> ToMsgpackVec for T
+where
+    T: serde::Serialize + crate::Validate,
+{
+    fn to_msgpack_vec(&self) -> Result<Vec<u8>, rmp_serde::encode::Error> {
+        rmp_serde::to_vec(self)
+    }
+
+    fn to_msgpack_vec_named(&self) -> Result<Vec<u8>, rmp_serde::encode::Error> {
+        rmp_serde::to_vec_named(self)
+    }
+}
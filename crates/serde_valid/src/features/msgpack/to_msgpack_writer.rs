@@ -0,0 +1,126 @@
+pub trait ToMsgpackWriter {
+    /// Convert to msgpack writer, encoding the structs as arrays.
+    ///
+    /// ```rust
+    /// use serde::Serialize;
+    /// use serde_valid::msgpack::ToMsgpackWriter;
+    /// use serde_valid::Validate;
+    ///
+    /// #[derive(Debug, Validate, Serialize)]
+    /// struct TestStruct {
+    ///     #[validate(maximum = 100)]
+    ///     val: i32,
+    /// }
+    /// let s = TestStruct { val: 10 };
+    /// let mut buffer = Vec::new();
+    ///
+    /// assert!(s.to_msgpack_writer(&mut buffer).is_ok());
+    /// ```
+    fn to_msgpack_writer<W>(&self, writer: W) -> Result<(), rmp_serde::encode::Error>
+    where
+        W: std::io::Write;
+
+    /// Convert to msgpack writer, encoding the structs as maps with the field names.
+    ///
+    /// ```rust
+    /// use serde::Serialize;
+    /// use serde_valid::msgpack::ToMsgpackWriter;
+    /// use serde_valid::Validate;
+    ///
+    /// #[derive(Debug, Validate, Serialize)]
+    /// struct TestStruct {
+    ///     #[validate(maximum = 100)]
+    ///     val: i32,
+    /// }
+    /// let s = TestStruct { val: 10 };
+    /// let mut buffer = Vec::new();
+    ///
+    /// assert!(s.to_msgpack_writer_named(&mut buffer).is_ok());
+    /// ```
+    fn to_msgpack_writer_named<W>(&self, writer: W) -> Result<(), rmp_serde::encode::Error>
+    where
+        W: std::io::Write;
+
+    /// Convert to msgpack writer after validation.
+    ///
+    /// Nothing is written when the validation fails.
+    ///
+    /// ```rust
+    /// use serde::Serialize;
+    /// use serde_valid::msgpack::ToMsgpackWriter;
+    /// use serde_valid::Validate;
+    ///
+    /// #[derive(Debug, Validate, Serialize)]
+    /// struct TestStruct {
+    ///     #[validate(maximum = 100)]
+    ///     val: i32,
+    /// }
+    /// let s = TestStruct { val: 1000 };
+    /// let mut buffer = Vec::new();
+    ///
+    /// assert!(s.to_msgpack_writer_validated(&mut buffer).unwrap_err().is_validation_errors());
+    /// assert!(buffer.is_empty());
+    /// ```
+    fn to_msgpack_writer_validated<W>(
+        &self,
+        writer: W,
+    ) -> Result<(), crate::Error<rmp_serde::encode::Error>>
+    where
+        Self: crate::Validate,
+        W: std::io::Write,
+    {
+        self.validate().map_err(crate::Error::ValidationError)?;
+        Ok(self.to_msgpack_writer(writer)?)
+    }
+
+    /// Convert to named msgpack writer after validation.
+    ///
+    /// Nothing is written when the validation fails.
+    ///
+    /// ```rust
+    /// use serde::Serialize;
+    /// use serde_valid::msgpack::ToMsgpackWriter;
+    /// use serde_valid::Validate;
+    ///
+    /// #[derive(Debug, Validate, Serialize)]
+    /// struct TestStruct {
+    ///     #[validate(maximum = 100)]
+    ///     val: i32,
+    /// }
+    /// let s = TestStruct { val: 1000 };
+    /// let mut buffer = Vec::new();
+    ///
+    /// assert!(s.to_msgpack_writer_named_validated(&mut buffer).unwrap_err().is_validation_errors());
+    /// assert!(buffer.is_empty());
+    /// ```
+    fn to_msgpack_writer_named_validated<W>(
+        &self,
+        writer: W,
+    ) -> Result<(), crate::Error<rmp_serde::encode::Error>>
+    where
+        Self: crate::Validate,
+        W: std::io::Write,
+    {
+        self.validate().map_err(crate::Error::ValidationError)?;
+        Ok(self.to_msgpack_writer_named(writer)?)
+    }
+}
+
+impl<T> ToMsgpackWriter for T
+where
+    T: serde::Serialize + crate::Validate,
+{
+    fn to_msgpack_writer<W>(&self, mut writer: W) -> Result<(), rmp_serde::encode::Error>
+    where
+        W: std::io::Write,
+    {
+        rmp_serde::encode::write(&mut writer, self)
+    }
+
+    fn to_msgpack_writer_named<W>(&self, mut writer: W) -> Result<(), rmp_serde::encode::Error>
+    where
+        W: std::io::Write,
+    {
+        rmp_serde::encode::write_named(&mut writer, self)
+    }
+}
@@ -0,0 +1,5 @@
+mod details;
+mod error;
+
+pub use details::{ProblemDetails, CONTENT_TYPE};
+pub use error::ProblemError;
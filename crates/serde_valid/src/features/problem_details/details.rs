@@ -0,0 +1,161 @@
+use super::ProblemError;
+
+/// Media type of the problem details JSON object.
+pub const CONTENT_TYPE: &str = "application/problem+json";
+
+/// Problem details for HTTP APIs ([RFC 9457](https://datatracker.ietf.org/doc/html/rfc9457)).
+///
+/// [`crate::Error::DeserializeError`] becomes `400 Bad Request`,
+/// and validation errors become `422 Unprocessable Content`
+/// with the `errors` extension member.
+///
+/// # Examples
+/// ```rust
+/// use serde::Deserialize;
+/// use serde_json::json;
+/// use serde_valid::json::FromJsonValue;
+/// use serde_valid::problem_details::ProblemDetails;
+/// use serde_valid::Validate;
+///
+/// #[derive(Debug, Deserialize, Validate)]
+/// struct Data {
+///     #[validate(maximum = 100)]
+///     val: i32,
+/// }
+///
+/// let err = Data::from_json_value(json!({ "val": 123 })).unwrap_err();
+///
+/// assert_eq!(
+///     serde_json::to_value(ProblemDetails::from(&err)).unwrap(),
+///     json!({
+///         "type": "about:blank",
+///         "title": "Unprocessable Content",
+///         "status": 422,
+///         "detail": "The request has 1 validation error.",
+///         "errors": [
+///             {
+///                 "pointer": "/val",
+///                 "message": "The number must be `<= 100`.",
+///                 "code": "maximum"
+///             }
+///         ]
+///     })
+/// );
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct ProblemDetails {
+    #[serde(rename = "type")]
+    pub r#type: String,
+    pub title: String,
+    pub status: u16,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub detail: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub instance: Option<String>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub errors: Vec<ProblemError>,
+}
+
+impl ProblemDetails {
+    pub const BAD_REQUEST: u16 = 400;
+    pub const UNPROCESSABLE_CONTENT: u16 = 422;
+
+    pub fn new(status: u16, title: impl Into<String>) -> Self {
+        Self {
+            r#type: "about:blank".to_string(),
+            title: title.into(),
+            status,
+            detail: None,
+            instance: None,
+            errors: vec![],
+        }
+    }
+
+    pub fn from_deserialize_error<E>(error: &E) -> Self
+    where
+        E: std::error::Error,
+    {
+        Self::new(Self::BAD_REQUEST, "Bad Request").with_detail(error.to_string())
+    }
+
+    pub fn from_validation_errors(errors: &crate::validation::Errors) -> Self {
+        let errors = ProblemError::collect(errors);
+        let detail = match errors.len() {
+            1 => "The request has 1 validation error.".to_string(),
+            count => format!("The request has {count} validation errors."),
+        };
+
+        Self {
+            errors,
+            ..Self::new(Self::UNPROCESSABLE_CONTENT, "Unprocessable Content").with_detail(detail)
+        }
+    }
+
+    pub fn with_type(mut self, r#type: impl Into<String>) -> Self {
+        self.r#type = r#type.into();
+        self
+    }
+
+    pub fn with_title(mut self, title: impl Into<String>) -> Self {
+        self.title = title.into();
+        self
+    }
+
+    pub fn with_detail(mut self, detail: impl Into<String>) -> Self {
+        self.detail = Some(detail.into());
+        self
+    }
+
+    pub fn with_instance(mut self, instance: impl Into<String>) -> Self {
+        self.instance = Some(instance.into());
+        self
+    }
+
+    pub fn content_type(&self) -> &'static str {
+        CONTENT_TYPE
+    }
+}
+
+impl<E> From<&crate::Error<E>> for ProblemDetails
+where
+    E: 'static + std::error::Error,
+{
+    fn from(error: &crate::Error<E>) -> Self {
+        match error {
+            crate::Error::DeserializeError(error) => Self::from_deserialize_error(error),
+            crate::Error::ValidationError(errors) => Self::from_validation_errors(errors),
+        }
+    }
+}
+
+impl<E> From<crate::Error<E>> for ProblemDetails
+where
+    E: 'static + std::error::Error,
+{
+    fn from(error: crate::Error<E>) -> Self {
+        Self::from(&error)
+    }
+}
+
+impl From<&crate::validation::Errors> for ProblemDetails {
+    fn from(errors: &crate::validation::Errors) -> Self {
+        Self::from_validation_errors(errors)
+    }
+}
+
+impl From<crate::validation::Errors> for ProblemDetails {
+    fn from(errors: crate::validation::Errors) -> Self {
+        Self::from_validation_errors(&errors)
+    }
+}
+
+impl std::fmt::Display for ProblemDetails {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match serde_json::to_string(&self) {
+            Ok(json_string) => {
+                write!(f, "{}", json_string)
+            }
+            Err(_) => Err(std::fmt::Error),
+        }
+    }
+}
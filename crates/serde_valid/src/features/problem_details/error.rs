@@ -0,0 +1,35 @@
+use crate::flatten::{FlatError, IntoFlat};
+use crate::validation::Errors;
+
+/// Entry of the `errors` extension member of [`ProblemDetails`](super::ProblemDetails).
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct ProblemError {
+    /// [JSON Pointer](https://datatracker.ietf.org/doc/html/rfc6901) to the invalid value.
+    pub pointer: String,
+    pub message: String,
+    pub code: String,
+}
+
+impl ProblemError {
+    pub fn new(
+        pointer: impl Into<String>,
+        message: impl Into<String>,
+        code: impl Into<String>,
+    ) -> Self {
+        Self {
+            pointer: pointer.into(),
+            message: message.into(),
+            code: code.into(),
+        }
+    }
+
+    pub(crate) fn collect(errors: &Errors) -> Vec<Self> {
+        errors
+            .into_flat()
+            .into_iter()
+            .map(|FlatError { pointer, error }| {
+                ProblemError::new(pointer, error.to_string(), error.code())
+            })
+            .collect()
+    }
+}
@@ -0,0 +1,13 @@
+mod from_ron_reader;
+mod from_ron_slice;
+mod from_ron_str;
+mod to_ron_string;
+mod to_ron_writer;
+
+pub use ron::ser::PrettyConfig;
+
+pub use from_ron_reader::FromRonReader;
+pub use from_ron_slice::FromRonSlice;
+pub use from_ron_str::FromRonStr;
+pub use to_ron_string::ToRonString;
+pub use to_ron_writer::ToRonWriter;
@@ -0,0 +1,40 @@
+pub trait FromRonReader
+where
+    Self: Sized,
+{
+    /// Convert from ron reader.
+    ///
+    /// ```should_panic
+    /// use std::fs::File;
+    /// use serde::Deserialize;
+    /// use serde_valid::Validate;
+    /// use serde_valid::ron::FromRonReader;
+    ///
+    /// #[derive(Debug, Validate, Deserialize)]
+    /// struct TestStruct {
+    ///     #[validate(maximum = 2000)]
+    ///     val: i32,
+    /// }
+    ///
+    /// let s = TestStruct::from_ron_reader(File::open("foo.txt").unwrap());
+    ///
+    /// assert!(s.is_ok())
+    /// ```
+    fn from_ron_reader<R>(reader: R) -> Result<Self, crate::Error<ron::error::SpannedError>>
+    where
+        R: std::io::Read;
+}
+
+impl<T> FromRonReader for T
+where
+    T: serde::de::DeserializeOwned + crate::Validate,
+{
+    fn from_ron_reader<R>(reader: R) -> Result<Self, crate::Error<ron::error::SpannedError>>
+    where
+        R: std::io::Read,
+    {
+        let model: T = ron::de::from_reader(reader)?;
+        model.validate().map_err(crate::Error::ValidationError)?;
+        Ok(model)
+    }
+}
@@ -0,0 +1,34 @@
+pub trait FromRonSlice<'de>
+where
+    Self: Sized,
+{
+    /// Convert from ron slice.
+    ///
+    /// ```rust
+    /// use serde::Deserialize;
+    /// use serde_valid::Validate;
+    /// use serde_valid::ron::FromRonSlice;
+    ///
+    /// #[derive(Debug, Validate, Deserialize)]
+    /// struct TestStruct<'a> {
+    ///     #[validate(min_length = 1)]
+    ///     val: &'a str,
+    /// }
+    ///
+    /// let s = TestStruct::from_ron_slice(br#"(val: "abcde")"#);
+    ///
+    /// assert!(s.is_ok())
+    /// ```
+    fn from_ron_slice(slice: &'de [u8]) -> Result<Self, crate::Error<ron::error::SpannedError>>;
+}
+
+impl<'de, T> FromRonSlice<'de> for T
+where
+    T: serde::de::Deserialize<'de> + crate::Validate,
+{
+    fn from_ron_slice(slice: &'de [u8]) -> Result<Self, crate::Error<ron::error::SpannedError>> {
+        let model: T = ron::de::from_bytes(slice)?;
+        model.validate().map_err(crate::Error::ValidationError)?;
+        Ok(model)
+    }
+}
@@ -0,0 +1,34 @@
+pub trait FromRonStr<'de>
+where
+    Self: Sized,
+{
+    /// Convert from ron str.
+    ///
+    /// ```rust
+    /// use serde::Deserialize;
+    /// use serde_valid::Validate;
+    /// use serde_valid::ron::FromRonStr;
+    ///
+    /// #[derive(Debug, Validate, Deserialize)]
+    /// struct TestStruct<'a> {
+    ///     #[validate(min_length = 1)]
+    ///     val: &'a str,
+    /// }
+    ///
+    /// let s = TestStruct::from_ron_str(r#"(val: "abcde")"#);
+    ///
+    /// assert!(s.is_ok())
+    /// ```
+    fn from_ron_str(str: &'de str) -> Result<Self, crate::Error<ron::error::SpannedError>>;
+}
+
+impl<'de, T> FromRonStr<'de> for T
+where
+    T: serde::de::Deserialize<'de> + crate::Validate,
+{
+    fn from_ron_str(str: &'de str) -> Result<Self, crate::Error<ron::error::SpannedError>> {
+        let model: T = ron::from_str(str)?;
+        model.validate().map_err(crate::Error::ValidationError)?;
+        Ok(model)
+    }
+}
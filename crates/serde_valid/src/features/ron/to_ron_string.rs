@@ -0,0 +1,108 @@
+pub trait ToRonString {
+    /// Convert to ron string.
+    ///
+    /// ```rust
+    /// use serde::Serialize;
+    /// use serde_valid::ron::ToRonString;
+    /// use serde_valid::Validate;
+    ///
+    /// #[derive(Debug, Validate, Serialize)]
+    /// struct TestStruct {
+    ///     #[validate(maximum = 100)]
+    ///     val: i32,
+    /// }
+    /// let s = TestStruct { val: 10 };
+    ///
+    /// assert_eq!(s.to_ron_string().unwrap(), "(val:10)");
+    /// ```
+    fn to_ron_string(&self) -> Result<String, ron::Error>;
+
+    /// Convert to ron pretty string.
+    ///
+    /// ```rust
+    /// use serde::Serialize;
+    /// use serde_valid::ron::ToRonString;
+    /// use serde_valid::Validate;
+    ///
+    /// #[derive(Debug, Validate, Serialize)]
+    /// struct TestStruct {
+    ///     #[validate(maximum = 100)]
+    ///     val: i32,
+    /// }
+    /// let s = TestStruct { val: 10 };
+    ///
+    /// assert!(s.to_ron_string_pretty().is_ok());
+    /// ```
+    fn to_ron_string_pretty(&self) -> Result<String, ron::Error>;
+
+    /// Convert to ron string after validation.
+    ///
+    /// ```rust
+    /// use serde::Serialize;
+    /// use serde_valid::ron::ToRonString;
+    /// use serde_valid::Validate;
+    ///
+    /// #[derive(Debug, Validate, Serialize)]
+    /// struct TestStruct {
+    ///     #[validate(maximum = 100)]
+    ///     val: i32,
+    /// }
+    /// let s = TestStruct { val: 1000 };
+    ///
+    /// assert!(s.to_ron_string_validated().unwrap_err().is_validation_errors());
+    /// ```
+    fn to_ron_string_validated(&self) -> Result<String, crate::Error<ron::Error>>
+    where
+        Self: crate::Validate,
+    {
+        self.validate().map_err(crate::Error::ValidationError)?;
+        Ok(self.to_ron_string()?)
+    }
+
+    /// Convert to ron pretty string after validation.
+    ///
+    /// ```rust
+    /// use serde::Serialize;
+    /// use serde_valid::ron::ToRonString;
+    /// use serde_valid::Validate;
+    ///
+    /// #[derive(Debug, Validate, Serialize)]
+    /// struct TestStruct {
+    ///     #[validate(maximum = 100)]
+    ///     val: i32,
+    /// }
+    /// let s = TestStruct { val: 1000 };
+    ///
+    /// assert!(s.to_ron_string_pretty_validated().unwrap_err().is_validation_errors());
+    /// ```
+    fn to_ron_string_pretty_validated(&self) -> Result<String, crate::Error<ron::Error>>
+    where
+        Self: crate::Validate,
+    {
+        self.validate().map_err(crate::Error::ValidationError)?;
+        Ok(self.to_ron_string_pretty()?)
+    }
+}
+
+impl<T> ToRonString for T
+where
+    T: serde::Serialize + crate::Validate,
+{
+    fn to_ron_string(&self) -> Result<String, ron::Error> {
+        ron::to_string(self)
+    }
+
+    fn to_ron_string_pretty(&self) -> Result<String, ron::Error> {
+        ron::ser::to_string_pretty(self, ron::ser::PrettyConfig::default())
+    }
+}
+
+impl ToRonString for ron::Value {
+    fn to_ron_string(&self) -> Result<String, ron::Error> {
+        ron::to_string(self)
+    }
+
+    fn to_ron_string_pretty(&self) -> Result<String, ron::Error> {
+        ron::ser::to_string_pretty(self, ron::ser::PrettyConfig::default())
+    }
+}
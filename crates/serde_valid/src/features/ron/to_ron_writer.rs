@@ -0,0 +1,136 @@
+pub trait ToRonWriter {
+    /// Convert to ron writer.
+    ///
+    /// ```rust
+    /// use serde::Serialize;
+    /// use serde_valid::ron::ToRonWriter;
+    /// use serde_valid::Validate;
+    ///
+    /// #[derive(Debug, Validate, Serialize)]
+    /// struct TestStruct {
+    ///     #[validate(maximum = 100)]
+    ///     val: i32,
+    /// }
+    /// let s = TestStruct { val: 10 };
+    /// let mut buffer = Vec::new();
+    ///
+    /// assert!(s.to_ron_writer(&mut buffer).is_ok());
+    /// ```
+    fn to_ron_writer<W>(&self, writer: W) -> Result<(), ron::Error>
+    where
+        W: std::io::Write;
+
+    /// Convert to pretty ron writer.
+    ///
+    /// ```rust
+    /// use serde::Serialize;
+    /// use serde_valid::ron::ToRonWriter;
+    /// use serde_valid::Validate;
+    ///
+    /// #[derive(Debug, Validate, Serialize)]
+    /// struct TestStruct {
+    ///     #[validate(maximum = 100)]
+    ///     val: i32,
+    /// }
+    /// let s = TestStruct { val: 10 };
+    /// let mut buffer = Vec::new();
+    ///
+    /// assert!(s.to_ron_writer_pretty(&mut buffer).is_ok());
+    /// ```
+    fn to_ron_writer_pretty<W>(&self, writer: W) -> Result<(), ron::Error>
+    where
+        W: std::io::Write;
+
+    /// Convert to ron writer after validation.
+    ///
+    /// Nothing is written when the validation fails.
+    ///
+    /// ```rust
+    /// use serde::Serialize;
+    /// use serde_valid::ron::ToRonWriter;
+    /// use serde_valid::Validate;
+    ///
+    /// #[derive(Debug, Validate, Serialize)]
+    /// struct TestStruct {
+    ///     #[validate(maximum = 100)]
+    ///     val: i32,
+    /// }
+    /// let s = TestStruct { val: 1000 };
+    /// let mut buffer = Vec::new();
+    ///
+    /// assert!(s.to_ron_writer_validated(&mut buffer).unwrap_err().is_validation_errors());
+    /// assert!(buffer.is_empty());
+    /// ```
+    fn to_ron_writer_validated<W>(&self, writer: W) -> Result<(), crate::Error<ron::Error>>
+    where
+        Self: crate::Validate,
+        W: std::io::Write,
+    {
+        self.validate().map_err(crate::Error::ValidationError)?;
+        Ok(self.to_ron_writer(writer)?)
+    }
+
+    /// Convert to pretty ron writer after validation.
+    ///
+    /// Nothing is written when the validation fails.
+    ///
+    /// ```rust
+    /// use serde::Serialize;
+    /// use serde_valid::ron::ToRonWriter;
+    /// use serde_valid::Validate;
+    ///
+    /// #[derive(Debug, Validate, Serialize)]
+    /// struct TestStruct {
+    ///     #[validate(maximum = 100)]
+    ///     val: i32,
+    /// }
+    /// let s = TestStruct { val: 1000 };
+    /// let mut buffer = Vec::new();
+    ///
+    /// assert!(s.to_ron_writer_pretty_validated(&mut buffer).unwrap_err().is_validation_errors());
+    /// assert!(buffer.is_empty());
+    /// ```
+    fn to_ron_writer_pretty_validated<W>(&self, writer: W) -> Result<(), crate::Error<ron::Error>>
+    where
+        Self: crate::Validate,
+        W: std::io::Write,
+    {
+        self.validate().map_err(crate::Error::ValidationError)?;
+        Ok(self.to_ron_writer_pretty(writer)?)
+    }
+}
+
+impl<T> ToRonWriter for T
+where
+    T: serde::Serialize + crate::Validate,
+{
+    fn to_ron_writer<W>(&self, writer: W) -> Result<(), ron::Error>
+    where
+        W: std::io::Write,
+    {
+        ron::ser::to_writer(writer, self)
+    }
+
+    fn to_ron_writer_pretty<W>(&self, writer: W) -> Result<(), ron::Error>
+    where
+        W: std::io::Write,
+    {
+        ron::ser::to_writer_pretty(writer, self, ron::ser::PrettyConfig::default())
+    }
+}
+
+impl ToRonWriter for ron::Value {
+    fn to_ron_writer<W>(&self, writer: W) -> Result<(), ron::Error>
+    where
+        W: std::io::Write,
+    {
+        ron::ser::to_writer(writer, self)
+    }
+
+    fn to_ron_writer_pretty<W>(&self, writer: W) -> Result<(), ron::Error>
+    where
+        W: std::io::Write,
+    {
+        ron::ser::to_writer_pretty(writer, self, ron::ser::PrettyConfig::default())
+    }
+}
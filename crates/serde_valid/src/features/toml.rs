@@ -2,6 +2,7 @@ mod from_toml_reader;
 mod from_toml_slice;
 mod from_toml_str;
 mod from_toml_value;
+mod span_map;
 mod to_toml_string;
 mod to_toml_value;
 mod to_toml_writer;
@@ -12,6 +13,7 @@ pub use from_toml_reader::FromTomlReader;
 pub use from_toml_slice::FromTomlSlice;
 pub use from_toml_str::FromTomlStr;
 pub use from_toml_value::FromTomlValue;
+pub use span_map::span_map_from_toml_str;
 pub use to_toml_string::ToTomlString;
 pub use to_toml_value::ToTomlValue;
 pub use to_toml_writer::ToTomlWriter;
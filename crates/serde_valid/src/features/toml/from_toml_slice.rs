@@ -20,6 +20,28 @@ where
     /// s.unwrap();
     /// ```
     fn from_toml_slice(slice: &'de [u8]) -> Result<Self, crate::Error<serde_toml::de::Error>>;
+
+    /// Convert from toml slice, and attach the source locations to the validation errors.
+    ///
+    /// ```rust
+    /// use serde::Deserialize;
+    /// use serde_valid::Validate;
+    /// use serde_valid::toml::FromTomlSlice;
+    ///
+    /// #[derive(Debug, Validate, Deserialize)]
+    /// struct TestStruct {
+    ///     #[validate(maximum = 100)]
+    ///     val: i32,
+    /// }
+    ///
+    /// let err = TestStruct::from_toml_slice_with_location(b"val = 123").unwrap_err();
+    /// let located_error = err.as_validation_errors().unwrap().iter().next().unwrap();
+    ///
+    /// assert_eq!(located_error.location.unwrap().to_string(), "1:7");
+    /// ```
+    fn from_toml_slice_with_location(
+        slice: &'de [u8],
+    ) -> Result<Self, crate::location::Error<serde_toml::de::Error>>;
 }
 
 impl<'de, T> FromTomlSlice<'de> for T
@@ -35,4 +57,21 @@ where
         model.validate().map_err(crate::Error::ValidationError)?;
         Ok(model)
     }
+
+    fn from_toml_slice_with_location(
+        slice: &'de [u8],
+    ) -> Result<Self, crate::location::Error<serde_toml::de::Error>> {
+        let source = std::str::from_utf8(slice).map_err(|error| {
+            crate::location::Error::DeserializeError(serde::de::Error::custom(error))
+        })?;
+        let model = T::deserialize(serde_toml::Deserializer::new(source))?;
+        model.validate().map_err(|errors| {
+            crate::location::Error::ValidationError(Box::new(crate::location::LocatedErrors::new(
+                source,
+                &super::span_map_from_toml_str(source),
+                errors,
+            )))
+        })?;
+        Ok(model)
+    }
 }
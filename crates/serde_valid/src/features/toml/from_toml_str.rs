@@ -20,6 +20,38 @@ where
     /// assert!(s.is_ok())
     /// ```
     fn from_toml_str(str: &'de str) -> Result<Self, crate::Error<serde_toml::de::Error>>;
+
+    /// Convert from toml str, and attach the source locations to the validation errors.
+    ///
+    /// ```rust
+    /// use serde::Deserialize;
+    /// use serde_valid::Validate;
+    /// use serde_valid::toml::FromTomlStr;
+    ///
+    /// #[derive(Debug, Validate, Deserialize)]
+    /// struct TestStruct {
+    ///     #[validate(maximum = 100)]
+    ///     val: i32,
+    /// }
+    ///
+    /// let err = TestStruct::from_toml_str_with_location("val = 123\n")
+    ///     .unwrap_err()
+    ///     .with_origin("config.toml");
+    ///
+    /// assert_eq!(
+    ///     err.to_string(),
+    ///     concat!(
+    ///         "error at /val: The number must be `<= 100`.\n",
+    ///         " --> config.toml:1:7\n",
+    ///         "  |\n",
+    ///         "1 | val = 123\n",
+    ///         "  |       ^^^\n",
+    ///     )
+    /// );
+    /// ```
+    fn from_toml_str_with_location(
+        str: &'de str,
+    ) -> Result<Self, crate::location::Error<serde_toml::de::Error>>;
 }
 
 impl<'de, T> FromTomlStr<'de> for T
@@ -31,4 +63,18 @@ where
         model.validate().map_err(crate::Error::ValidationError)?;
         Ok(model)
     }
+
+    fn from_toml_str_with_location(
+        str: &'de str,
+    ) -> Result<Self, crate::location::Error<serde_toml::de::Error>> {
+        let model = T::deserialize(serde_toml::Deserializer::new(str))?;
+        model.validate().map_err(|errors| {
+            crate::location::Error::ValidationError(Box::new(crate::location::LocatedErrors::new(
+                str,
+                &super::span_map_from_toml_str(str),
+                errors,
+            )))
+        })?;
+        Ok(model)
+    }
 }
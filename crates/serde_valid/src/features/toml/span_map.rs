@@ -0,0 +1,167 @@
+use indexmap::IndexMap;
+use serde::de::{Deserialize, Deserializer, IgnoredAny, MapAccess, SeqAccess, Visitor};
+use serde_toml::Spanned;
+
+use crate::flatten::escape_pointer_token;
+use crate::location::{Span, SpanMap};
+
+/// Record the spans of all values in the toml str.
+///
+/// ```rust
+/// use serde_valid::toml::span_map_from_toml_str;
+/// use serde_valid::location::Span;
+///
+/// let span_map = span_map_from_toml_str("[server]\nport = 80\n");
+///
+/// assert_eq!(span_map.get("/server/port"), Some(Span::new(16, 18)));
+/// ```
+///
+/// Invalid toml records the root span only.
+pub fn span_map_from_toml_str(source: &str) -> SpanMap {
+    let mut span_map = SpanMap::new();
+    span_map.insert("", Span::new(0, source.len()));
+
+    if let Ok(value) = SpannedValue::deserialize(serde_toml::Deserializer::new(source)) {
+        value.record(String::new(), &mut span_map);
+    }
+    span_map
+}
+
+enum SpannedValue {
+    Table(IndexMap<String, Spanned<SpannedValue>>),
+    Array(Vec<Spanned<SpannedValue>>),
+    Other,
+}
+
+impl SpannedValue {
+    fn record(self, pointer: String, span_map: &mut SpanMap) {
+        match self {
+            Self::Table(table) => {
+                for (key, value) in table {
+                    record_spanned(
+                        format!("{pointer}/{}", escape_pointer_token(&key)),
+                        value,
+                        span_map,
+                    );
+                }
+            }
+            Self::Array(array) => {
+                for (index, value) in array.into_iter().enumerate() {
+                    record_spanned(format!("{pointer}/{index}"), value, span_map);
+                }
+            }
+            Self::Other => {}
+        }
+    }
+}
+
+fn record_spanned(pointer: String, value: Spanned<SpannedValue>, span_map: &mut SpanMap) {
+    let span = value.span();
+    span_map.insert(pointer.clone(), Span::new(span.start, span.end));
+    value.into_inner().record(pointer, span_map);
+}
+
+impl<'de> Deserialize<'de> for SpannedValue {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_any(SpannedValueVisitor)
+    }
+}
+
+struct SpannedValueVisitor;
+
+impl<'de> Visitor<'de> for SpannedValueVisitor {
+    type Value = SpannedValue;
+
+    fn expecting(&self, formatter: &mut std::fmt::Formatter) -> std::fmt::Result {
+        formatter.write_str("any toml value")
+    }
+
+    fn visit_bool<E>(self, _: bool) -> Result<Self::Value, E> {
+        Ok(SpannedValue::Other)
+    }
+
+    fn visit_i64<E>(self, _: i64) -> Result<Self::Value, E> {
+        Ok(SpannedValue::Other)
+    }
+
+    fn visit_u64<E>(self, _: u64) -> Result<Self::Value, E> {
+        Ok(SpannedValue::Other)
+    }
+
+    fn visit_f64<E>(self, _: f64) -> Result<Self::Value, E> {
+        Ok(SpannedValue::Other)
+    }
+
+    fn visit_str<E>(self, _: &str) -> Result<Self::Value, E> {
+        Ok(SpannedValue::Other)
+    }
+
+    fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+    where
+        A: SeqAccess<'de>,
+    {
+        let mut array = Vec::new();
+        while let Some(value) = seq.next_element()? {
+            array.push(value);
+        }
+        Ok(SpannedValue::Array(array))
+    }
+
+    fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+    where
+        A: MapAccess<'de>,
+    {
+        let mut table = IndexMap::new();
+        while let Some(key) = map.next_key::<String>()? {
+            if key.starts_with("$__toml_private") {
+                // datetime is deserialized as the private table.
+                map.next_value::<IgnoredAny>()?;
+                return Ok(SpannedValue::Other);
+            }
+            table.insert(key, map.next_value()?);
+        }
+        Ok(SpannedValue::Table(table))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_span_map_from_toml_str() {
+        let source = r#"name = "ab"
+date = 1979-05-27
+
+[[items]]
+count = 1
+
+[[items]]
+count = 100
+tags = ["a", "b/c"]
+"#;
+        let span_map = span_map_from_toml_str(source);
+
+        let text = |pointer: &str| {
+            let span = span_map.get(pointer).unwrap();
+            &source[span.start..span.end]
+        };
+
+        assert_eq!(text(""), source);
+        assert_eq!(text("/name"), r#""ab""#);
+        assert_eq!(text("/date"), "1979-05-27");
+        assert_eq!(text("/items/1/count"), "100");
+        assert_eq!(text("/items/1/tags/1"), r#""b/c""#);
+    }
+
+    #[test]
+    fn test_span_map_from_invalid_toml_str() {
+        let span_map = span_map_from_toml_str("name = ");
+
+        assert_eq!(span_map.len(), 1);
+        assert_eq!(span_map.get("/name"), Some(Span::new(0, 7)));
+    }
+}
@@ -34,6 +34,56 @@ pub trait ToTomlString {
     /// assert!(s.to_toml_string_pretty().is_ok());
     /// ```
     fn to_toml_string_pretty(&self) -> Result<String, serde_toml::ser::Error>;
+
+    /// Convert to toml string after validation.
+    ///
+    /// ```rust
+    /// use serde::Serialize;
+    /// use serde_valid::toml::ToTomlString;
+    /// use serde_valid::Validate;
+    ///
+    /// #[derive(Debug, Validate, Serialize)]
+    /// struct TestStruct {
+    ///     #[validate(maximum = 100)]
+    ///     val: i32,
+    /// }
+    /// let s = TestStruct { val: 1000 };
+    ///
+    /// assert!(s.to_toml_string_validated().unwrap_err().is_validation_errors());
+    /// ```
+    fn to_toml_string_validated(&self) -> Result<String, crate::Error<serde_toml::ser::Error>>
+    where
+        Self: crate::Validate,
+    {
+        self.validate().map_err(crate::Error::ValidationError)?;
+        Ok(self.to_toml_string()?)
+    }
+
+    /// Convert to toml pretty string after validation.
+    ///
+    /// ```rust
+    /// use serde::Serialize;
+    /// use serde_valid::toml::ToTomlString;
+    /// use serde_valid::Validate;
+    ///
+    /// #[derive(Debug, Validate, Serialize)]
+    /// struct TestStruct {
+    ///     #[validate(maximum = 100)]
+    ///     val: i32,
+    /// }
+    /// let s = TestStruct { val: 1000 };
+    ///
+    /// assert!(s.to_toml_string_pretty_validated().unwrap_err().is_validation_errors());
+    /// ```
+    fn to_toml_string_pretty_validated(
+        &self,
+    ) -> Result<String, crate::Error<serde_toml::ser::Error>>
+    where
+        Self: crate::Validate,
+    {
+        self.validate().map_err(crate::Error::ValidationError)?;
+        Ok(self.to_toml_string_pretty()?)
+    }
 }
 
 impl<T> ToTomlString for T
@@ -16,6 +16,32 @@ pub trait ToTomlValue {
     /// assert!(s.to_toml_value().is_ok());
     /// ```
     fn to_toml_value(&self) -> Result<serde_toml::Value, serde_toml::ser::Error>;
+
+    /// Convert to toml value after validation.
+    ///
+    /// ```rust
+    /// use serde::Serialize;
+    /// use serde_valid::toml::ToTomlValue;
+    /// use serde_valid::Validate;
+    ///
+    /// #[derive(Debug, Validate, Serialize)]
+    /// struct TestStruct {
+    ///     #[validate(maximum = 100)]
+    ///     val: i32,
+    /// }
+    /// let s = TestStruct { val: 1000 };
+    ///
+    /// assert!(s.to_toml_value_validated().unwrap_err().is_validation_errors());
+    /// ```
+    fn to_toml_value_validated(
+        &self,
+    ) -> Result<serde_toml::Value, crate::Error<serde_toml::ser::Error>>
+    where
+        Self: crate::Validate,
+    {
+        self.validate().map_err(crate::Error::ValidationError)?;
+        Ok(self.to_toml_value()?)
+    }
 }
 
 impl<T> ToTomlValue for T
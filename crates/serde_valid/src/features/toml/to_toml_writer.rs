@@ -44,6 +44,70 @@ pub trait ToTomlWriter {
     fn to_toml_writer_pretty<W>(&self, writer: W) -> Result<(), serde_toml::ser::Error>
     where
         W: std::io::Write;
+
+    /// Convert to toml writer after validation.
+    ///
+    /// Nothing is written when the validation fails.
+    ///
+    /// ```rust
+    /// use serde::Serialize;
+    /// use serde_valid::toml::ToTomlWriter;
+    /// use serde_valid::Validate;
+    ///
+    /// #[derive(Debug, Validate, Serialize)]
+    /// struct TestStruct {
+    ///     #[validate(maximum = 100)]
+    ///     val: i32,
+    /// }
+    /// let s = TestStruct { val: 1000 };
+    /// let mut buffer = Vec::new();
+    ///
+    /// assert!(s.to_toml_writer_validated(&mut buffer).unwrap_err().is_validation_errors());
+    /// assert!(buffer.is_empty());
+    /// ```
+    fn to_toml_writer_validated<W>(
+        &self,
+        writer: W,
+    ) -> Result<(), crate::Error<serde_toml::ser::Error>>
+    where
+        Self: crate::Validate,
+        W: std::io::Write,
+    {
+        self.validate().map_err(crate::Error::ValidationError)?;
+        Ok(self.to_toml_writer(writer)?)
+    }
+
+    /// Convert to pretty toml writer after validation.
+    ///
+    /// Nothing is written when the validation fails.
+    ///
+    /// ```rust
+    /// use serde::Serialize;
+    /// use serde_valid::toml::ToTomlWriter;
+    /// use serde_valid::Validate;
+    ///
+    /// #[derive(Debug, Validate, Serialize)]
+    /// struct TestStruct {
+    ///     #[validate(maximum = 100)]
+    ///     val: i32,
+    /// }
+    /// let s = TestStruct { val: 1000 };
+    /// let mut buffer = Vec::new();
+    ///
+    /// assert!(s.to_toml_writer_pretty_validated(&mut buffer).unwrap_err().is_validation_errors());
+    /// assert!(buffer.is_empty());
+    /// ```
+    fn to_toml_writer_pretty_validated<W>(
+        &self,
+        writer: W,
+    ) -> Result<(), crate::Error<serde_toml::ser::Error>>
+    where
+        Self: crate::Validate,
+        W: std::io::Write,
+    {
+        self.validate().map_err(crate::Error::ValidationError)?;
+        Ok(self.to_toml_writer_pretty(writer)?)
+    }
 }
 
 impl<T> ToTomlWriter for T
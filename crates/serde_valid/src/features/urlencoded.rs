@@ -0,0 +1,103 @@
+mod from_form_slice;
+mod from_query_str;
+mod seq_or_scalar;
+mod to_query_string;
+
+use std::{borrow::Cow, collections::HashMap};
+
+pub use from_form_slice::FromFormSlice;
+pub use from_query_str::FromQueryStr;
+pub use to_query_string::ToQueryString;
+
+/// Default maximum depth of the nested values, like `a[b][c]=1`, which is the one of `serde_qs`.
+pub const DEFAULT_MAX_DEPTH: usize = 5;
+
+/// Default strict mode of `serde_qs`, which is off to accept the percent-encoded brackets
+/// `a%5Bb%5D=1` which browsers send for the form fields.
+pub const DEFAULT_STRICT: bool = false;
+
+fn default_config() -> serde_qs::Config {
+    serde_qs::Config::new(DEFAULT_MAX_DEPTH, DEFAULT_STRICT)
+}
+
+/// Deserialize the urlencoded input with the config of `serde_qs`.
+///
+/// A lone value `a=1` is accepted as a sequence of one element where the target type is a sequence.
+fn from_bytes<T>(input: &[u8], config: &serde_qs::Config) -> Result<T, serde_qs::Error>
+where
+    T: serde::de::DeserializeOwned,
+{
+    let input = repeated_keys_as_sequence(input);
+    let deserializer = serde_qs::Deserializer::with_config(config, &input)?;
+    T::deserialize(seq_or_scalar::SeqOrScalar(deserializer))
+}
+
+/// Rewrite the repeated keys `a=1&a=2` into the sequence form `a[]=1&a[]=2`.
+///
+/// The keys are compared after the percent-decoding, so that `a=1&%61=2` is repeated too.
+/// The keys with brackets are left as they are, because they are already nested.
+fn repeated_keys_as_sequence(input: &[u8]) -> Cow<'_, [u8]> {
+    let pairs = input
+        .split(|byte| *byte == b'&')
+        .filter(|pair| !pair.is_empty())
+        .map(|pair| match pair.iter().position(|byte| *byte == b'=') {
+            Some(position) => pair.split_at(position),
+            None => (pair, &[][..]),
+        })
+        .collect::<Vec<_>>();
+
+    let keys = pairs
+        .iter()
+        .map(|(key, _)| decode_key(key))
+        .collect::<Vec<_>>();
+    let mut counts = HashMap::<&str, usize>::with_capacity(pairs.len());
+    for key in &keys {
+        *counts.entry(key).or_default() += 1;
+    }
+    let is_repeated = |key: &str| counts[key] > 1 && !key.contains('[');
+    if !keys.iter().any(|key| is_repeated(key)) {
+        return Cow::Borrowed(input);
+    }
+
+    let mut output = Vec::with_capacity(input.len() + pairs.len() * 2);
+    for (index, ((key, value), decoded_key)) in pairs.iter().zip(&keys).enumerate() {
+        if index > 0 {
+            output.push(b'&');
+        }
+        output.extend_from_slice(key);
+        if is_repeated(decoded_key) {
+            output.extend_from_slice(b"[]");
+        }
+        output.extend_from_slice(value);
+    }
+    Cow::Owned(output)
+}
+
+/// Percent-decode the key, where `+` is a space.
+fn decode_key(key: &[u8]) -> String {
+    form_urlencoded::parse(key)
+        .next()
+        .map(|(key, _)| key.into_owned())
+        .unwrap_or_default()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_repeated_keys_as_sequence() {
+        assert_eq!(
+            repeated_keys_as_sequence(b"a=1&b=2&a=3&c[0]=4&c[1]=5"),
+            &b"a[]=1&b=2&a[]=3&c[0]=4&c[1]=5"[..]
+        );
+        assert_eq!(
+            repeated_keys_as_sequence(b"a=1&%61=2&a+b=3&a%20b=4"),
+            &b"a[]=1&%61[]=2&a+b[]=3&a%20b[]=4"[..]
+        );
+        assert!(matches!(
+            repeated_keys_as_sequence(b"a=1&b[x]=2&b%5By%5D=3&b%5by%5d=4&c%5B%5D=5&c[]=6"),
+            Cow::Borrowed(_)
+        ));
+    }
+}
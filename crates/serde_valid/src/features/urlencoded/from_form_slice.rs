@@ -0,0 +1,70 @@
+pub trait FromFormSlice
+where
+    Self: Sized,
+{
+    /// Convert from `application/x-www-form-urlencoded` body.
+    ///
+    /// The nested values and the arrays are written in the same way as [`FromQueryStr`](super::FromQueryStr),
+    /// and the percent-encoded brackets `a%5Bb%5D=1` are accepted.
+    ///
+    /// ```rust
+    /// use serde::Deserialize;
+    /// use serde_valid::Validate;
+    /// use serde_valid::urlencoded::FromFormSlice;
+    ///
+    /// #[derive(Debug, Validate, Deserialize)]
+    /// struct TestStruct {
+    ///     #[validate(min_length = 1)]
+    ///     name: String,
+    ///     #[validate(maximum = 100)]
+    ///     age: u32,
+    /// }
+    ///
+    /// let err = TestStruct::from_form_slice(b"name=Taro+Yamada&age=200").unwrap_err();
+    ///
+    /// assert!(err.is_validation_errors());
+    /// ```
+    fn from_form_slice(slice: &[u8]) -> Result<Self, crate::Error<serde_qs::Error>>;
+
+    /// Convert from `application/x-www-form-urlencoded` body with the config of `serde_qs`,
+    /// which is [`DEFAULT_MAX_DEPTH`](super::DEFAULT_MAX_DEPTH) and
+    /// [`DEFAULT_STRICT`](super::DEFAULT_STRICT) in [`FromFormSlice::from_form_slice`].
+    ///
+    /// ```rust
+    /// use serde::Deserialize;
+    /// use serde_valid::Validate;
+    /// use serde_valid::urlencoded::FromFormSlice;
+    ///
+    /// #[derive(Debug, Validate, Deserialize)]
+    /// struct TestStruct {
+    ///     #[validate(min_length = 1)]
+    ///     name: String,
+    /// }
+    ///
+    /// let s = TestStruct::from_form_slice_with_config(b"name=Taro", &serde_qs::Config::new(2, true));
+    ///
+    /// assert!(s.is_ok());
+    /// ```
+    fn from_form_slice_with_config(
+        slice: &[u8],
+        config: &serde_qs::Config,
+    ) -> Result<Self, crate::Error<serde_qs::Error>>;
+}
+
+impl<T> FromFormSlice for T
+where
+    T: serde::de::DeserializeOwned + crate::Validate,
+{
+    fn from_form_slice(slice: &[u8]) -> Result<Self, crate::Error<serde_qs::Error>> {
+        Self::from_form_slice_with_config(slice, &super::default_config())
+    }
+
+    fn from_form_slice_with_config(
+        slice: &[u8],
+        config: &serde_qs::Config,
+    ) -> Result<Self, crate::Error<serde_qs::Error>> {
+        let model: T = super::from_bytes(slice, config)?;
+        model.validate().map_err(crate::Error::ValidationError)?;
+        Ok(model)
+    }
+}
@@ -0,0 +1,84 @@
+pub trait FromQueryStr
+where
+    Self: Sized,
+{
+    /// Convert from URL query string.
+    ///
+    /// The leading `?` is ignored. The nested values are written as `a[b]=1`,
+    /// and the arrays as `a[0]=1&a[1]=2`, `a[]=1&a[]=2` or the repeated keys `a=1&a=2`.
+    ///
+    /// ```rust
+    /// use serde::Deserialize;
+    /// use serde_valid::Validate;
+    /// use serde_valid::urlencoded::FromQueryStr;
+    ///
+    /// #[derive(Debug, Validate, Deserialize)]
+    /// struct TestStruct {
+    ///     #[validate(max_items = 3)]
+    ///     tags: Vec<String>,
+    ///     #[validate]
+    ///     page: Page,
+    /// }
+    ///
+    /// #[derive(Debug, Validate, Deserialize)]
+    /// struct Page {
+    ///     #[validate(maximum = 100)]
+    ///     size: u32,
+    /// }
+    ///
+    /// let s = TestStruct::from_query_str("?tags=a&tags=b&page[size]=10").unwrap();
+    ///
+    /// assert_eq!(s.tags, vec!["a", "b"]);
+    /// assert_eq!(s.page.size, 10);
+    /// ```
+    fn from_query_str(str: &str) -> Result<Self, crate::Error<serde_qs::Error>>;
+
+    /// Convert from URL query string with the config of `serde_qs`,
+    /// which is [`DEFAULT_MAX_DEPTH`](super::DEFAULT_MAX_DEPTH) and
+    /// [`DEFAULT_STRICT`](super::DEFAULT_STRICT) in [`FromQueryStr::from_query_str`].
+    ///
+    /// ```rust
+    /// use serde::Deserialize;
+    /// use serde_valid::Validate;
+    /// use serde_valid::urlencoded::FromQueryStr;
+    ///
+    /// #[derive(Debug, Validate, Deserialize)]
+    /// struct TestStruct {
+    ///     #[validate]
+    ///     page: Page,
+    /// }
+    ///
+    /// #[derive(Debug, Validate, Deserialize)]
+    /// struct Page {
+    ///     #[validate(maximum = 100)]
+    ///     size: u32,
+    /// }
+    ///
+    /// let s = TestStruct::from_query_str_with_config("page[size]=10", &serde_qs::Config::new(0, true));
+    ///
+    /// assert!(s.is_err());
+    /// ```
+    fn from_query_str_with_config(
+        str: &str,
+        config: &serde_qs::Config,
+    ) -> Result<Self, crate::Error<serde_qs::Error>>;
+}
+
+impl<T> FromQueryStr for T
+where
+    T: serde::de::DeserializeOwned + crate::Validate,
+{
+    fn from_query_str(str: &str) -> Result<Self, crate::Error<serde_qs::Error>> {
+        Self::from_query_str_with_config(str, &super::default_config())
+    }
+
+    fn from_query_str_with_config(
+        str: &str,
+        config: &serde_qs::Config,
+    ) -> Result<Self, crate::Error<serde_qs::Error>> {
+        let str = str.strip_prefix('?').unwrap_or(str);
+        let model: T = super::from_bytes(str.as_bytes(), config)?;
+        model.validate().map_err(crate::Error::ValidationError)?;
+        Ok(model)
+    }
+}
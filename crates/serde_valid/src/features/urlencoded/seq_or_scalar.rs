@@ -0,0 +1,341 @@
+use serde::de::{
+    self, DeserializeSeed, Deserializer, EnumAccess, MapAccess, SeqAccess, VariantAccess, Visitor,
+};
+
+/// Deserializer which accepts a lone value `a=1` as a sequence of one element,
+/// where the target type asks for a sequence.
+///
+/// Every nested deserializer is wrapped too, so that the nested sequences are accepted.
+/// The lone value is given as a sequence by `serde_qs`
+/// when it is deserialized as a newtype struct.
+pub(super) struct SeqOrScalar<T>(pub T);
+
+const SEQ_OR_SCALAR: &str = "SeqOrScalar";
+
+macro_rules! forward_deserialize {
+    ($($method:ident($($arg:ident: $type:ty),*),)*) => {
+        $(
+            fn $method<V>(self, $($arg: $type,)* visitor: V) -> Result<V::Value, Self::Error>
+            where
+                V: Visitor<'de>,
+            {
+                self.0.$method($($arg,)* SeqOrScalar(visitor))
+            }
+        )*
+    };
+}
+
+impl<'de, D> Deserializer<'de> for SeqOrScalar<D>
+where
+    D: Deserializer<'de>,
+{
+    type Error = D::Error;
+
+    fn deserialize_seq<V>(self, visitor: V) -> Result<V::Value, Self::Error>
+    where
+        V: Visitor<'de>,
+    {
+        self.0
+            .deserialize_newtype_struct(SEQ_OR_SCALAR, SeqOrScalar(visitor))
+    }
+
+    forward_deserialize! {
+        deserialize_any(),
+        deserialize_bool(),
+        deserialize_i8(),
+        deserialize_i16(),
+        deserialize_i32(),
+        deserialize_i64(),
+        deserialize_i128(),
+        deserialize_u8(),
+        deserialize_u16(),
+        deserialize_u32(),
+        deserialize_u64(),
+        deserialize_u128(),
+        deserialize_f32(),
+        deserialize_f64(),
+        deserialize_char(),
+        deserialize_str(),
+        deserialize_string(),
+        deserialize_bytes(),
+        deserialize_byte_buf(),
+        deserialize_option(),
+        deserialize_unit(),
+        deserialize_unit_struct(name: &'static str),
+        deserialize_newtype_struct(name: &'static str),
+        deserialize_tuple(len: usize),
+        deserialize_tuple_struct(name: &'static str, len: usize),
+        deserialize_map(),
+        deserialize_struct(name: &'static str, fields: &'static [&'static str]),
+        deserialize_enum(name: &'static str, variants: &'static [&'static str]),
+        deserialize_identifier(),
+        deserialize_ignored_any(),
+    }
+
+    fn is_human_readable(&self) -> bool {
+        self.0.is_human_readable()
+    }
+}
+
+macro_rules! forward_visit {
+    ($($method:ident($type:ty),)*) => {
+        $(
+            fn $method<E>(self, value: $type) -> Result<Self::Value, E>
+            where
+                E: de::Error,
+            {
+                self.0.$method(value)
+            }
+        )*
+    };
+}
+
+impl<'de, V> Visitor<'de> for SeqOrScalar<V>
+where
+    V: Visitor<'de>,
+{
+    type Value = V::Value;
+
+    fn expecting(&self, formatter: &mut std::fmt::Formatter) -> std::fmt::Result {
+        self.0.expecting(formatter)
+    }
+
+    forward_visit! {
+        visit_bool(bool),
+        visit_i8(i8),
+        visit_i16(i16),
+        visit_i32(i32),
+        visit_i64(i64),
+        visit_i128(i128),
+        visit_u8(u8),
+        visit_u16(u16),
+        visit_u32(u32),
+        visit_u64(u64),
+        visit_u128(u128),
+        visit_f32(f32),
+        visit_f64(f64),
+        visit_char(char),
+        visit_str(&str),
+        visit_borrowed_str(&'de str),
+        visit_string(String),
+        visit_bytes(&[u8]),
+        visit_borrowed_bytes(&'de [u8]),
+        visit_byte_buf(Vec<u8>),
+    }
+
+    fn visit_none<E>(self) -> Result<Self::Value, E>
+    where
+        E: de::Error,
+    {
+        self.0.visit_none()
+    }
+
+    fn visit_some<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        self.0.visit_some(SeqOrScalar(deserializer))
+    }
+
+    fn visit_unit<E>(self) -> Result<Self::Value, E>
+    where
+        E: de::Error,
+    {
+        self.0.visit_unit()
+    }
+
+    fn visit_newtype_struct<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        self.0.visit_newtype_struct(SeqOrScalar(deserializer))
+    }
+
+    fn visit_seq<A>(self, seq: A) -> Result<Self::Value, A::Error>
+    where
+        A: SeqAccess<'de>,
+    {
+        self.0.visit_seq(SeqOrScalar(seq))
+    }
+
+    fn visit_map<A>(self, map: A) -> Result<Self::Value, A::Error>
+    where
+        A: MapAccess<'de>,
+    {
+        self.0.visit_map(SeqOrScalar(map))
+    }
+
+    fn visit_enum<A>(self, data: A) -> Result<Self::Value, A::Error>
+    where
+        A: EnumAccess<'de>,
+    {
+        self.0.visit_enum(SeqOrScalar(data))
+    }
+}
+
+impl<'de, S> DeserializeSeed<'de> for SeqOrScalar<S>
+where
+    S: DeserializeSeed<'de>,
+{
+    type Value = S::Value;
+
+    fn deserialize<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        self.0.deserialize(SeqOrScalar(deserializer))
+    }
+}
+
+impl<'de, A> SeqAccess<'de> for SeqOrScalar<A>
+where
+    A: SeqAccess<'de>,
+{
+    type Error = A::Error;
+
+    fn next_element_seed<T>(&mut self, seed: T) -> Result<Option<T::Value>, Self::Error>
+    where
+        T: DeserializeSeed<'de>,
+    {
+        self.0.next_element_seed(SeqOrScalar(seed))
+    }
+
+    fn size_hint(&self) -> Option<usize> {
+        self.0.size_hint()
+    }
+}
+
+impl<'de, A> MapAccess<'de> for SeqOrScalar<A>
+where
+    A: MapAccess<'de>,
+{
+    type Error = A::Error;
+
+    fn next_key_seed<K>(&mut self, seed: K) -> Result<Option<K::Value>, Self::Error>
+    where
+        K: DeserializeSeed<'de>,
+    {
+        self.0.next_key_seed(SeqOrScalar(seed))
+    }
+
+    fn next_value_seed<V>(&mut self, seed: V) -> Result<V::Value, Self::Error>
+    where
+        V: DeserializeSeed<'de>,
+    {
+        self.0.next_value_seed(SeqOrScalar(seed))
+    }
+
+    fn size_hint(&self) -> Option<usize> {
+        self.0.size_hint()
+    }
+}
+
+impl<'de, A> EnumAccess<'de> for SeqOrScalar<A>
+where
+    A: EnumAccess<'de>,
+{
+    type Error = A::Error;
+    type Variant = SeqOrScalar<A::Variant>;
+
+    fn variant_seed<V>(self, seed: V) -> Result<(V::Value, Self::Variant), Self::Error>
+    where
+        V: DeserializeSeed<'de>,
+    {
+        self.0
+            .variant_seed(SeqOrScalar(seed))
+            .map(|(value, variant)| (value, SeqOrScalar(variant)))
+    }
+}
+
+impl<'de, A> VariantAccess<'de> for SeqOrScalar<A>
+where
+    A: VariantAccess<'de>,
+{
+    type Error = A::Error;
+
+    fn unit_variant(self) -> Result<(), Self::Error> {
+        self.0.unit_variant()
+    }
+
+    fn newtype_variant_seed<T>(self, seed: T) -> Result<T::Value, Self::Error>
+    where
+        T: DeserializeSeed<'de>,
+    {
+        self.0.newtype_variant_seed(SeqOrScalar(seed))
+    }
+
+    fn tuple_variant<V>(self, len: usize, visitor: V) -> Result<V::Value, Self::Error>
+    where
+        V: Visitor<'de>,
+    {
+        self.0.tuple_variant(len, SeqOrScalar(visitor))
+    }
+
+    fn struct_variant<V>(
+        self,
+        fields: &'static [&'static str],
+        visitor: V,
+    ) -> Result<V::Value, Self::Error>
+    where
+        V: Visitor<'de>,
+    {
+        self.0.struct_variant(fields, SeqOrScalar(visitor))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Value which asks for a newtype struct, and accepts only a sequence.
+    #[derive(Debug, PartialEq)]
+    struct SeqOnly(Vec<String>);
+
+    impl<'de> serde::Deserialize<'de> for SeqOnly {
+        fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+        where
+            D: Deserializer<'de>,
+        {
+            struct SeqOnlyVisitor;
+
+            impl<'de> Visitor<'de> for SeqOnlyVisitor {
+                type Value = SeqOnly;
+
+                fn expecting(&self, formatter: &mut std::fmt::Formatter) -> std::fmt::Result {
+                    formatter.write_str("a sequence")
+                }
+
+                fn visit_seq<A>(self, seq: A) -> Result<Self::Value, A::Error>
+                where
+                    A: SeqAccess<'de>,
+                {
+                    serde::Deserialize::deserialize(de::value::SeqAccessDeserializer::new(seq))
+                        .map(SeqOnly)
+                }
+            }
+
+            deserializer.deserialize_newtype_struct(SEQ_OR_SCALAR, SeqOnlyVisitor)
+        }
+    }
+
+    #[derive(Debug, PartialEq, serde::Deserialize)]
+    struct TestStruct {
+        lone: SeqOnly,
+        many: SeqOnly,
+    }
+
+    #[test]
+    fn test_serde_qs_gives_newtype_struct_as_sequence() {
+        // `SeqOrScalar` depends on this behaviour of `serde_qs`,
+        // so that the lone value is accepted as a sequence.
+        assert_eq!(
+            crate::features::urlencoded::default_config()
+                .deserialize_str::<TestStruct>("lone=a&many[]=b&many[]=c")
+                .unwrap(),
+            TestStruct {
+                lone: SeqOnly(vec!["a".to_string()]),
+                many: SeqOnly(vec!["b".to_string(), "c".to_string()]),
+            }
+        );
+    }
+}
@@ -0,0 +1,53 @@
+pub trait ToQueryString {
+    /// Convert to URL query string.
+    ///
+    /// ```rust
+    /// use serde::Serialize;
+    /// use serde_valid::urlencoded::ToQueryString;
+    /// use serde_valid::Validate;
+    ///
+    /// #[derive(Debug, Validate, Serialize)]
+    /// struct TestStruct {
+    ///     #[validate(maximum = 100)]
+    ///     val: i32,
+    ///     tags: Vec<String>,
+    /// }
+    /// let s = TestStruct { val: 10, tags: vec!["a".to_string(), "b c".to_string()] };
+    ///
+    /// assert_eq!(s.to_query_string().unwrap(), "val=10&tags[0]=a&tags[1]=b+c");
+    /// ```
+    fn to_query_string(&self) -> Result<String, serde_qs::Error>;
+
+    /// Convert to URL query string after validation.
+    ///
+    /// ```rust
+    /// use serde::Serialize;
+    /// use serde_valid::urlencoded::ToQueryString;
+    /// use serde_valid::Validate;
+    ///
+    /// #[derive(Debug, Validate, Serialize)]
+    /// struct TestStruct {
+    ///     #[validate(maximum = 100)]
+    ///     val: i32,
+    /// }
+    /// let s = TestStruct { val: 1000 };
+    ///
+    /// assert!(s.to_query_string_validated().unwrap_err().is_validation_errors());
+    /// ```
+    fn to_query_string_validated(&self) -> Result<String, crate::Error<serde_qs::Error>>
+    where
+        Self: crate::Validate,
+    {
+        self.validate().map_err(crate::Error::ValidationError)?;
+        Ok(self.to_query_string()?)
+    }
+}
+
+impl<T> ToQueryString for T
+where
+    T: serde::Serialize + crate::Validate,
+{
+    fn to_query_string(&self) -> Result<String, serde_qs::Error> {
+        serde_qs::to_string(self)
+    }
+}
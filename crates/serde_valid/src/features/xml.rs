@@ -0,0 +1,7 @@
+mod from_xml_reader;
+mod from_xml_str;
+mod to_xml_string;
+
+pub use from_xml_reader::FromXmlReader;
+pub use from_xml_str::FromXmlStr;
+pub use to_xml_string::ToXmlString;
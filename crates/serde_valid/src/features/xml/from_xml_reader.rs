@@ -0,0 +1,40 @@
+pub trait FromXmlReader
+where
+    Self: Sized,
+{
+    /// Convert from xml reader.
+    ///
+    /// ```should_panic
+    /// use std::fs::File;
+    /// use serde::Deserialize;
+    /// use serde_valid::Validate;
+    /// use serde_valid::xml::FromXmlReader;
+    ///
+    /// #[derive(Debug, Validate, Deserialize)]
+    /// struct TestStruct {
+    ///     #[validate(maximum = 2000)]
+    ///     val: i32,
+    /// }
+    ///
+    /// let s = TestStruct::from_xml_reader(File::open("foo.xml").unwrap());
+    ///
+    /// assert!(s.is_ok())
+    /// ```
+    fn from_xml_reader<R>(reader: R) -> Result<Self, crate::Error<quick_xml::DeError>>
+    where
+        R: std::io::Read;
+}
+
+impl<T> FromXmlReader for T
+where
+    T: serde::de::DeserializeOwned + crate::Validate,
+{
+    fn from_xml_reader<R>(reader: R) -> Result<Self, crate::Error<quick_xml::DeError>>
+    where
+        R: std::io::Read,
+    {
+        let model: T = quick_xml::de::from_reader(std::io::BufReader::new(reader))?;
+        model.validate().map_err(crate::Error::ValidationError)?;
+        Ok(model)
+    }
+}
@@ -0,0 +1,51 @@
+pub trait FromXmlStr<'de>
+where
+    Self: Sized,
+{
+    /// Convert from xml str.
+    ///
+    /// The errors are keyed by the serde names of the fields,
+    /// such as `@attr` for the attributes and `$text` for the text content.
+    ///
+    /// ```rust
+    /// use serde::Deserialize;
+    /// use serde_json::json;
+    /// use serde_valid::Validate;
+    /// use serde_valid::xml::FromXmlStr;
+    ///
+    /// #[derive(Debug, Validate, Deserialize)]
+    /// struct Item {
+    ///     #[serde(rename = "@id")]
+    ///     #[validate(minimum = 1)]
+    ///     id: u32,
+    ///     #[validate(min_length = 1)]
+    ///     name: String,
+    /// }
+    ///
+    /// let err = Item::from_xml_str(r#"<item id="0"><name>abc</name></item>"#).unwrap_err();
+    ///
+    /// assert_eq!(
+    ///     serde_json::to_value(err.as_validation_errors().unwrap()).unwrap(),
+    ///     json!({
+    ///         "errors": [],
+    ///         "properties": {
+    ///             "@id": {
+    ///                 "errors": ["The number must be `>= 1`."]
+    ///             }
+    ///         }
+    ///     })
+    /// );
+    /// ```
+    fn from_xml_str(str: &'de str) -> Result<Self, crate::Error<quick_xml::DeError>>;
+}
+
+impl<'de, T> FromXmlStr<'de> for T
+where
+    T: serde::de::Deserialize<'de> + crate::Validate,
+{
+    fn from_xml_str(str: &'de str) -> Result<Self, crate::Error<quick_xml::DeError>> {
+        let model: T = quick_xml::de::from_str(str)?;
+        model.validate().map_err(crate::Error::ValidationError)?;
+        Ok(model)
+    }
+}
@@ -0,0 +1,79 @@
+pub trait ToXmlString {
+    /// Convert to xml string, using the type name as the root element.
+    ///
+    /// ```rust
+    /// use serde::Serialize;
+    /// use serde_valid::xml::ToXmlString;
+    /// use serde_valid::Validate;
+    ///
+    /// #[derive(Debug, Validate, Serialize)]
+    /// struct TestStruct {
+    ///     #[serde(rename = "@id")]
+    ///     id: u32,
+    ///     #[validate(maximum = 100)]
+    ///     val: i32,
+    /// }
+    /// let s = TestStruct { id: 1, val: 10 };
+    ///
+    /// assert_eq!(
+    ///     s.to_xml_string().unwrap(),
+    ///     r#"<TestStruct id="1"><val>10</val></TestStruct>"#
+    /// );
+    /// ```
+    fn to_xml_string(&self) -> Result<String, quick_xml::SeError>;
+
+    /// Convert to xml string with the given root element.
+    ///
+    /// ```rust
+    /// use serde::Serialize;
+    /// use serde_valid::xml::ToXmlString;
+    /// use serde_valid::Validate;
+    ///
+    /// #[derive(Debug, Validate, Serialize)]
+    /// struct TestStruct {
+    ///     #[validate(maximum = 100)]
+    ///     val: i32,
+    /// }
+    /// let s = TestStruct { val: 10 };
+    ///
+    /// assert_eq!(s.to_xml_string_with_root("item").unwrap(), "<item><val>10</val></item>");
+    /// ```
+    fn to_xml_string_with_root(&self, root_tag: &str) -> Result<String, quick_xml::SeError>;
+
+    /// Convert to xml string after validation.
+    ///
+    /// ```rust
+    /// use serde::Serialize;
+    /// use serde_valid::xml::ToXmlString;
+    /// use serde_valid::Validate;
+    ///
+    /// #[derive(Debug, Validate, Serialize)]
+    /// struct TestStruct {
+    ///     #[validate(maximum = 100)]
+    ///     val: i32,
+    /// }
+    /// let s = TestStruct { val: 1000 };
+    ///
+    /// assert!(s.to_xml_string_validated().unwrap_err().is_validation_errors());
+    /// ```
+    fn to_xml_string_validated(&self) -> Result<String, crate::Error<quick_xml::SeError>>
+    where
+        Self: crate::Validate,
+    {
+        self.validate().map_err(crate::Error::ValidationError)?;
+        Ok(self.to_xml_string()?)
+    }
+}
+
+impl<T> ToXmlString for T
+where
+    T: serde::Serialize + crate::Validate,
+{
+    fn to_xml_string(&self) -> Result<String, quick_xml::SeError> {
+        quick_xml::se::to_string(self)
+    }
+
+    fn to_xml_string_with_root(&self, root_tag: &str) -> Result<String, quick_xml::SeError> {
+        quick_xml::se::to_string_with_root(root_tag, self)
+    }
+}
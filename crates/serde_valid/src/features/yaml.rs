@@ -2,6 +2,7 @@ mod from_yaml_reader;
 mod from_yaml_slice;
 mod from_yaml_str;
 mod from_yaml_value;
+mod span_map;
 mod to_yaml_string;
 mod to_yaml_value;
 mod to_yaml_writer;
@@ -12,6 +13,7 @@ pub use from_yaml_reader::FromYamlReader;
 pub use from_yaml_slice::FromYamlSlice;
 pub use from_yaml_str::FromYamlStr;
 pub use from_yaml_value::FromYamlValue;
+pub use span_map::span_map_from_yaml_str;
 pub use to_yaml_string::ToYamlString;
 pub use to_yaml_value::ToYamlValue;
 pub use to_yaml_writer::ToYamlWriter;
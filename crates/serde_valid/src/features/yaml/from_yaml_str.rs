@@ -20,6 +20,38 @@ where
     /// assert!(s.is_ok())
     /// ```
     fn from_yaml_str(str: &'de str) -> Result<Self, crate::Error<serde_yaml::Error>>;
+
+    /// Convert from yaml str, and attach the source locations to the validation errors.
+    ///
+    /// ```rust
+    /// use serde::Deserialize;
+    /// use serde_valid::Validate;
+    /// use serde_valid::yaml::FromYamlStr;
+    ///
+    /// #[derive(Debug, Validate, Deserialize)]
+    /// struct TestStruct {
+    ///     #[validate(maximum = 100)]
+    ///     val: i32,
+    /// }
+    ///
+    /// let err = TestStruct::from_yaml_str_with_location("val: 123\n")
+    ///     .unwrap_err()
+    ///     .with_origin("config.yaml");
+    ///
+    /// assert_eq!(
+    ///     err.to_string(),
+    ///     concat!(
+    ///         "error at /val: The number must be `<= 100`.\n",
+    ///         " --> config.yaml:1:6\n",
+    ///         "  |\n",
+    ///         "1 | val: 123\n",
+    ///         "  |      ^^^\n",
+    ///     )
+    /// );
+    /// ```
+    fn from_yaml_str_with_location(
+        str: &'de str,
+    ) -> Result<Self, crate::location::Error<serde_yaml::Error>>;
 }
 
 impl<'de, T> FromYamlStr<'de> for T
@@ -31,4 +63,18 @@ where
         model.validate().map_err(crate::Error::ValidationError)?;
         Ok(model)
     }
+
+    fn from_yaml_str_with_location(
+        str: &'de str,
+    ) -> Result<Self, crate::location::Error<serde_yaml::Error>> {
+        let model: T = serde_yaml::from_str(str)?;
+        model.validate().map_err(|errors| {
+            crate::location::Error::ValidationError(Box::new(crate::location::LocatedErrors::new(
+                str,
+                &super::span_map_from_yaml_str(str),
+                errors,
+            )))
+        })?;
+        Ok(model)
+    }
 }
@@ -0,0 +1,280 @@
+use yaml_rust2::parser::{Event, MarkedEventReceiver, Parser};
+use yaml_rust2::scanner::{Marker, TScalarStyle};
+
+use crate::flatten::escape_pointer_token;
+use crate::location::{Span, SpanMap};
+
+/// Record the spans of all values in the yaml str.
+///
+/// ```rust
+/// use serde_valid::yaml::span_map_from_yaml_str;
+/// use serde_valid::location::Span;
+///
+/// let span_map = span_map_from_yaml_str("server:\n  port: 80\n");
+///
+/// assert_eq!(span_map.get("/server/port"), Some(Span::new(16, 18)));
+/// ```
+///
+/// Invalid yaml is recorded as far as it can be read.
+pub fn span_map_from_yaml_str(source: &str) -> SpanMap {
+    let mut recorder = Recorder {
+        source,
+        char_offsets: source
+            .char_indices()
+            .map(|(offset, _)| offset)
+            .chain(std::iter::once(source.len()))
+            .collect(),
+        frames: vec![],
+        pending_block_scalar: None,
+        span_map: SpanMap::new(),
+    };
+    // the events before the syntax error are already recorded.
+    let _ = Parser::new_from_str(source).load(&mut recorder, false);
+    recorder.span_map
+}
+
+struct Recorder<'a> {
+    source: &'a str,
+    char_offsets: Vec<usize>,
+    frames: Vec<Frame>,
+    pending_block_scalar: Option<(Option<String>, usize)>,
+    span_map: SpanMap,
+}
+
+struct Frame {
+    kind: FrameKind,
+    /// `None` if the collection is a complex mapping key.
+    pointer: Option<String>,
+    start: usize,
+    flow: bool,
+}
+
+enum FrameKind {
+    Mapping { key: Option<Option<String>> },
+    Sequence { index: usize },
+}
+
+impl Recorder<'_> {
+    fn offset(&self, mark: Marker) -> usize {
+        self.char_offsets
+            .get(mark.index())
+            .copied()
+            .unwrap_or(self.source.len())
+    }
+
+    fn in_flow(&self) -> bool {
+        self.frames.last().is_some_and(|frame| frame.flow)
+    }
+
+    /// Return the pointer of the next node, or `None` when the node is a mapping key.
+    fn next_node_pointer(&mut self, start: usize) -> NextNode {
+        let Some(frame) = self.frames.last_mut() else {
+            return NextNode::Value(Some(String::new()));
+        };
+        if frame.start > start {
+            // the block mapping starts from its first key.
+            frame.start = start;
+        }
+        let parent = frame.pointer.as_deref();
+        match &mut frame.kind {
+            FrameKind::Mapping { key } => match key.take() {
+                None => NextNode::Key,
+                Some(key) => NextNode::Value(
+                    parent
+                        .zip(key)
+                        .map(|(parent, key)| format!("{parent}/{}", escape_pointer_token(&key))),
+                ),
+            },
+            FrameKind::Sequence { index } => {
+                *index += 1;
+                NextNode::Value(parent.map(|parent| format!("{parent}/{}", *index - 1)))
+            }
+        }
+    }
+
+    fn set_key(&mut self, key: Option<String>) {
+        if let Some(Frame {
+            kind: FrameKind::Mapping { key: frame_key },
+            ..
+        }) = self.frames.last_mut()
+        {
+            *frame_key = Some(key);
+        }
+    }
+
+    fn insert(&mut self, pointer: Option<String>, start: usize, end: usize) {
+        if let Some(pointer) = pointer {
+            let end = start.max(trim_end(self.source, end));
+            self.span_map.insert(pointer, Span::new(start, end));
+        }
+    }
+
+    fn scalar_end(&self, start: usize, style: TScalarStyle) -> usize {
+        let rest = &self.source[start..];
+        let end = match style {
+            TScalarStyle::SingleQuoted => quoted_end(rest, '\'', None),
+            TScalarStyle::DoubleQuoted => quoted_end(rest, '"', Some('\\')),
+            _ => plain_end(rest, self.in_flow()),
+        };
+        start + end
+    }
+}
+
+enum NextNode {
+    Key,
+    Value(Option<String>),
+}
+
+impl MarkedEventReceiver for Recorder<'_> {
+    fn on_event(&mut self, event: Event, mark: Marker) {
+        let start = self.offset(mark);
+
+        if let Some((pointer, scalar_start)) = self.pending_block_scalar.take() {
+            self.insert(pointer, scalar_start, start);
+        }
+
+        match event {
+            Event::Scalar(value, style, ..) => match self.next_node_pointer(start) {
+                NextNode::Key => self.set_key(Some(value)),
+                NextNode::Value(pointer) => match style {
+                    TScalarStyle::Literal | TScalarStyle::Folded => {
+                        self.pending_block_scalar = Some((pointer, start));
+                    }
+                    _ => {
+                        let end = self.scalar_end(start, style);
+                        self.insert(pointer, start, end);
+                    }
+                },
+            },
+            Event::Alias(..) => match self.next_node_pointer(start) {
+                NextNode::Key => self.set_key(None),
+                NextNode::Value(pointer) => {
+                    let end = self.scalar_end(start, TScalarStyle::Plain);
+                    self.insert(pointer, start, end);
+                }
+            },
+            Event::MappingStart(..) | Event::SequenceStart(..) => {
+                let pointer = match self.next_node_pointer(start) {
+                    NextNode::Key => {
+                        self.set_key(None);
+                        None
+                    }
+                    NextNode::Value(pointer) => pointer,
+                };
+                let kind = match event {
+                    Event::MappingStart(..) => FrameKind::Mapping { key: None },
+                    _ => FrameKind::Sequence { index: 0 },
+                };
+                let flow = matches!(self.source[start..].chars().next(), Some('{' | '['));
+                self.frames.push(Frame {
+                    kind,
+                    pointer,
+                    start,
+                    flow,
+                });
+            }
+            Event::MappingEnd | Event::SequenceEnd => {
+                if let Some(frame) = self.frames.pop() {
+                    let end = match frame.flow {
+                        true => self.source[start..]
+                            .chars()
+                            .next()
+                            .map_or(start, |c| start + c.len_utf8()),
+                        false => start,
+                    };
+                    self.insert(frame.pointer, frame.start, end);
+                }
+            }
+            _ => {}
+        }
+    }
+}
+
+fn quoted_end(rest: &str, quote: char, escape: Option<char>) -> usize {
+    let mut chars = rest.char_indices().skip(1);
+    while let Some((index, c)) = chars.next() {
+        if Some(c) == escape {
+            chars.next();
+        } else if c == quote {
+            if quote == '\'' && rest[index + 1..].starts_with('\'') {
+                chars.next();
+            } else {
+                return index + 1;
+            }
+        }
+    }
+    rest.len()
+}
+
+fn plain_end(rest: &str, flow: bool) -> usize {
+    let mut previous = ' ';
+    for (index, c) in rest.char_indices() {
+        let next = rest[index + c.len_utf8()..].chars().next();
+        let end = match c {
+            '\n' | '\r' => true,
+            '#' => previous.is_whitespace(),
+            ':' => next.is_none_or(|next| {
+                next.is_whitespace() || (flow && matches!(next, ',' | ']' | '}'))
+            }),
+            ',' | '[' | ']' | '{' | '}' => flow,
+            _ => false,
+        };
+        if end && index > 0 {
+            return index;
+        }
+        previous = c;
+    }
+    rest.len()
+}
+
+fn trim_end(source: &str, end: usize) -> usize {
+    source[..end].trim_end().len()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_span_map_from_yaml_str() {
+        let source = r#"# comment
+name: "a\"b" # comment
+あ: 'it''s'
+items:
+  - count: 1
+  - {count: 100, url: http://a}
+tags: [a, b/c]
+text: |
+  line
+"#;
+        let span_map = span_map_from_yaml_str(source);
+
+        let text = |pointer: &str| {
+            let span = span_map.get(pointer).unwrap();
+            &source[span.start..span.end]
+        };
+
+        assert_eq!(text(""), &source[10..source.len() - 1]);
+        assert_eq!(text("/name"), r#""a\"b""#);
+        assert_eq!(text("/あ"), "'it''s'");
+        assert_eq!(
+            text("/items"),
+            "- count: 1\n  - {count: 100, url: http://a}"
+        );
+        assert_eq!(text("/items/0"), "count: 1");
+        assert_eq!(text("/items/0/count"), "1");
+        assert_eq!(text("/items/1"), "{count: 100, url: http://a}");
+        assert_eq!(text("/items/1/count"), "100");
+        assert_eq!(text("/items/1/url"), "http://a");
+        assert_eq!(text("/tags/1"), "b/c");
+        assert_eq!(text("/text"), "line");
+    }
+
+    #[test]
+    fn test_span_map_from_invalid_yaml_str() {
+        let span_map = span_map_from_yaml_str("a: 1\nb: [\n");
+
+        assert_eq!(span_map.get("/a"), Some(Span::new(3, 4)));
+        assert_eq!(span_map.get("/b"), None);
+    }
+}
@@ -16,6 +16,30 @@ pub trait ToYamlString {
     /// assert!(s.to_yaml_string().is_ok());
     /// ```
     fn to_yaml_string(&self) -> Result<String, serde_yaml::Error>;
+
+    /// Convert to yaml string after validation.
+    ///
+    /// ```rust
+    /// use serde::Serialize;
+    /// use serde_valid::yaml::ToYamlString;
+    /// use serde_valid::Validate;
+    ///
+    /// #[derive(Debug, Validate, Serialize)]
+    /// struct TestStruct {
+    ///     #[validate(maximum = 100)]
+    ///     val: i32,
+    /// }
+    /// let s = TestStruct { val: 1000 };
+    ///
+    /// assert!(s.to_yaml_string_validated().unwrap_err().is_validation_errors());
+    /// ```
+    fn to_yaml_string_validated(&self) -> Result<String, crate::Error<serde_yaml::Error>>
+    where
+        Self: crate::Validate,
+    {
+        self.validate().map_err(crate::Error::ValidationError)?;
+        Ok(self.to_yaml_string()?)
+    }
 }
 
 impl<T> ToYamlString for T
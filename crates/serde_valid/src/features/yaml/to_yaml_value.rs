@@ -16,6 +16,30 @@ pub trait ToYamlValue {
     /// assert!(s.to_yaml_value().is_ok());
     /// ```
     fn to_yaml_value(&self) -> Result<serde_yaml::Value, serde_yaml::Error>;
+
+    /// Convert to yaml value after validation.
+    ///
+    /// ```rust
+    /// use serde::Serialize;
+    /// use serde_valid::yaml::ToYamlValue;
+    /// use serde_valid::Validate;
+    ///
+    /// #[derive(Debug, Validate, Serialize)]
+    /// struct TestStruct {
+    ///     #[validate(maximum = 100)]
+    ///     val: i32,
+    /// }
+    /// let s = TestStruct { val: 1000 };
+    ///
+    /// assert!(s.to_yaml_value_validated().unwrap_err().is_validation_errors());
+    /// ```
+    fn to_yaml_value_validated(&self) -> Result<serde_yaml::Value, crate::Error<serde_yaml::Error>>
+    where
+        Self: crate::Validate,
+    {
+        self.validate().map_err(crate::Error::ValidationError)?;
+        Ok(self.to_yaml_value()?)
+    }
 }
 
 impl<T> ToYamlValue for T
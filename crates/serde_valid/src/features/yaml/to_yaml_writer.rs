@@ -19,6 +19,35 @@ pub trait ToYamlWriter {
     fn to_yaml_writer<W>(&self, writer: W) -> Result<(), serde_yaml::Error>
     where
         W: std::io::Write;
+
+    /// Convert to yaml writer after validation.
+    ///
+    /// Nothing is written when the validation fails.
+    ///
+    /// ```rust
+    /// use serde::Serialize;
+    /// use serde_valid::yaml::ToYamlWriter;
+    /// use serde_valid::Validate;
+    ///
+    /// #[derive(Debug, Validate, Serialize)]
+    /// struct TestStruct {
+    ///     #[validate(maximum = 100)]
+    ///     val: i32,
+    /// }
+    /// let s = TestStruct { val: 1000 };
+    /// let mut buffer = Vec::new();
+    ///
+    /// assert!(s.to_yaml_writer_validated(&mut buffer).unwrap_err().is_validation_errors());
+    /// assert!(buffer.is_empty());
+    /// ```
+    fn to_yaml_writer_validated<W>(&self, writer: W) -> Result<(), crate::Error<serde_yaml::Error>>
+    where
+        Self: crate::Validate,
+        W: std::io::Write,
+    {
+        self.validate().map_err(crate::Error::ValidationError)?;
+        Ok(self.to_yaml_writer(writer)?)
+    }
 }
 
 impl<T> ToYamlWriter for T
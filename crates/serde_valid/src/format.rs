@@ -0,0 +1,175 @@
+//! Format-agnostic deserialization and serialization with validation.
+//!
+//! Each data format is a type implementing [`DataFormat`], and [`from_str`], [`from_reader`]
+//! and [`to_string`] work with any of them.
+//! The format can also be picked at runtime by the file extension with [`DynFormat`].
+
+mod dyn_format;
+mod formats;
+
+pub use dyn_format::{DynFormat, DynFormatError};
+pub use formats::Json;
+#[cfg(feature = "json5")]
+pub use formats::Json5;
+#[cfg(feature = "ron")]
+pub use formats::Ron;
+#[cfg(feature = "toml")]
+pub use formats::Toml;
+#[cfg(feature = "xml")]
+pub use formats::Xml;
+#[cfg(feature = "yaml")]
+pub use formats::Yaml;
+
+/// Data format which can deserialize and serialize serde types.
+///
+/// Third-party formats plug into [`from_str`], [`from_reader`] and [`to_string`]
+/// by implementing this trait.
+///
+/// ```rust
+/// use serde::Deserialize;
+/// use serde_valid::Validate;
+/// use serde_valid::format::DataFormat;
+///
+/// struct PrettyJson;
+///
+/// impl DataFormat for PrettyJson {
+///     type DeserializeError = serde_json::Error;
+///     type SerializeError = serde_json::Error;
+///
+///     fn deserialize_str<T>(str: &str) -> Result<T, Self::DeserializeError>
+///     where
+///         T: serde::de::DeserializeOwned,
+///     {
+///         serde_json::from_str(str)
+///     }
+///
+///     fn deserialize_reader<T, R>(reader: R) -> Result<T, Self::DeserializeError>
+///     where
+///         T: serde::de::DeserializeOwned,
+///         R: std::io::Read,
+///     {
+///         serde_json::from_reader(reader)
+///     }
+///
+///     fn serialize_string<T>(value: &T) -> Result<String, Self::SerializeError>
+///     where
+///         T: ?Sized + serde::Serialize,
+///     {
+///         serde_json::to_string_pretty(value)
+///     }
+/// }
+///
+/// #[derive(Debug, Validate, Deserialize)]
+/// struct TestStruct {
+///     #[validate(maximum = 100)]
+///     val: i32,
+/// }
+///
+/// let s = serde_valid::from_str::<PrettyJson, TestStruct>(r#"{ "val": 10 }"#);
+///
+/// assert!(s.is_ok());
+/// ```
+pub trait DataFormat {
+    type DeserializeError: 'static + std::error::Error;
+    type SerializeError: 'static + std::error::Error;
+
+    /// Deserialize from the str, without validation.
+    fn deserialize_str<T>(str: &str) -> Result<T, Self::DeserializeError>
+    where
+        T: serde::de::DeserializeOwned;
+
+    /// Deserialize from the reader, without validation.
+    fn deserialize_reader<T, R>(reader: R) -> Result<T, Self::DeserializeError>
+    where
+        T: serde::de::DeserializeOwned,
+        R: std::io::Read;
+
+    /// Serialize to the string, without validation.
+    fn serialize_string<T>(value: &T) -> Result<String, Self::SerializeError>
+    where
+        T: ?Sized + serde::Serialize;
+}
+
+/// Deserialize from the str in the format `F`, and validate.
+///
+/// ```rust
+/// use serde::Deserialize;
+/// use serde_valid::Validate;
+/// use serde_valid::format::Json;
+///
+/// #[derive(Debug, Validate, Deserialize)]
+/// struct TestStruct {
+///     #[validate(maximum = 100)]
+///     val: i32,
+/// }
+///
+/// let err = serde_valid::from_str::<Json, TestStruct>(r#"{ "val": 123 }"#).unwrap_err();
+///
+/// assert!(err.is_validation_errors());
+/// ```
+pub fn from_str<F, T>(str: &str) -> Result<T, crate::Error<F::DeserializeError>>
+where
+    F: DataFormat,
+    T: serde::de::DeserializeOwned + crate::Validate,
+{
+    let model: T = F::deserialize_str(str)?;
+    model.validate().map_err(crate::Error::ValidationError)?;
+    Ok(model)
+}
+
+/// Deserialize from the reader in the format `F`, and validate.
+///
+/// ```should_panic
+/// use std::fs::File;
+/// use serde::Deserialize;
+/// use serde_valid::Validate;
+/// use serde_valid::format::Json;
+///
+/// #[derive(Debug, Validate, Deserialize)]
+/// struct TestStruct {
+///     #[validate(maximum = 100)]
+///     val: i32,
+/// }
+///
+/// let s = serde_valid::from_reader::<Json, TestStruct, _>(File::open("foo.json").unwrap());
+///
+/// assert!(s.is_ok());
+/// ```
+pub fn from_reader<F, T, R>(reader: R) -> Result<T, crate::Error<F::DeserializeError>>
+where
+    F: DataFormat,
+    T: serde::de::DeserializeOwned + crate::Validate,
+    R: std::io::Read,
+{
+    let model: T = F::deserialize_reader(reader)?;
+    model.validate().map_err(crate::Error::ValidationError)?;
+    Ok(model)
+}
+
+/// Validate, and serialize to the string in the format `F`.
+///
+/// ```rust
+/// use serde::Serialize;
+/// use serde_valid::Validate;
+/// use serde_valid::format::Json;
+///
+/// #[derive(Debug, Validate, Serialize)]
+/// struct TestStruct {
+///     #[validate(maximum = 100)]
+///     val: i32,
+/// }
+///
+/// assert_eq!(
+///     serde_valid::to_string::<Json, _>(&TestStruct { val: 10 }).unwrap(),
+///     r#"{"val":10}"#
+/// );
+/// assert!(serde_valid::to_string::<Json, _>(&TestStruct { val: 1000 }).is_err());
+/// ```
+pub fn to_string<F, T>(value: &T) -> Result<String, crate::Error<F::SerializeError>>
+where
+    F: DataFormat,
+    T: ?Sized + serde::Serialize + crate::Validate,
+{
+    value.validate().map_err(crate::Error::ValidationError)?;
+    Ok(F::serialize_string(value)?)
+}
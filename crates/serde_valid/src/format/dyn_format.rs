@@ -0,0 +1,141 @@
+/// Data format picked at runtime, such as by the file extension.
+///
+/// ```rust
+/// use serde::Deserialize;
+/// use serde_valid::Validate;
+/// use serde_valid::format::DynFormat;
+///
+/// #[derive(Debug, Validate, Deserialize)]
+/// struct Config {
+///     #[validate(maximum = 100)]
+///     workers: u32,
+/// }
+///
+/// let format = DynFormat::from_path("config.json").unwrap();
+/// let err = format.from_str::<Config>(r#"{ "workers": 200 }"#).unwrap_err();
+///
+/// assert!(err.is_validation_errors());
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum DynFormat {
+    Json,
+    #[cfg(feature = "json5")]
+    Json5,
+    #[cfg(feature = "ron")]
+    Ron,
+    #[cfg(feature = "toml")]
+    Toml,
+    #[cfg(feature = "xml")]
+    Xml,
+    #[cfg(feature = "yaml")]
+    Yaml,
+}
+
+/// Apply the generic function to the [`DataFormat`](super::DataFormat) of the variant.
+macro_rules! dispatch {
+    ($format:expr, $function:ident::<_ $(, $param:ty)*>($($arg:expr),*)) => {
+        match $format {
+            DynFormat::Json => $function::<super::Json $(, $param)*>($($arg),*).map_err(into_dyn_error),
+            #[cfg(feature = "json5")]
+            DynFormat::Json5 => $function::<super::Json5 $(, $param)*>($($arg),*).map_err(into_dyn_error),
+            #[cfg(feature = "ron")]
+            DynFormat::Ron => $function::<super::Ron $(, $param)*>($($arg),*).map_err(into_dyn_error),
+            #[cfg(feature = "toml")]
+            DynFormat::Toml => $function::<super::Toml $(, $param)*>($($arg),*).map_err(into_dyn_error),
+            #[cfg(feature = "xml")]
+            DynFormat::Xml => $function::<super::Xml $(, $param)*>($($arg),*).map_err(into_dyn_error),
+            #[cfg(feature = "yaml")]
+            DynFormat::Yaml => $function::<super::Yaml $(, $param)*>($($arg),*).map_err(into_dyn_error),
+        }
+    };
+}
+
+impl DynFormat {
+    /// Pick the format by the file extension, ignoring the case.
+    ///
+    /// Returns `None` if the extension is unknown, or its feature is disabled.
+    pub fn from_extension(extension: &str) -> Option<Self> {
+        match extension.to_ascii_lowercase().as_str() {
+            "json" => Some(Self::Json),
+            #[cfg(feature = "json5")]
+            "json5" => Some(Self::Json5),
+            #[cfg(feature = "ron")]
+            "ron" => Some(Self::Ron),
+            #[cfg(feature = "toml")]
+            "toml" => Some(Self::Toml),
+            #[cfg(feature = "xml")]
+            "xml" => Some(Self::Xml),
+            #[cfg(feature = "yaml")]
+            "yaml" | "yml" => Some(Self::Yaml),
+            _ => None,
+        }
+    }
+
+    /// Pick the format by the extension of the file path.
+    pub fn from_path(path: impl AsRef<std::path::Path>) -> Option<Self> {
+        Self::from_extension(path.as_ref().extension()?.to_str()?)
+    }
+
+    /// Deserialize from the str, and validate.
+    pub fn from_str<T>(&self, str: &str) -> Result<T, crate::Error<DynFormatError>>
+    where
+        T: serde::de::DeserializeOwned + crate::Validate,
+    {
+        use super::from_str;
+
+        dispatch!(self, from_str::<_, T>(str))
+    }
+
+    /// Deserialize from the reader, and validate.
+    pub fn from_reader<T, R>(&self, reader: R) -> Result<T, crate::Error<DynFormatError>>
+    where
+        T: serde::de::DeserializeOwned + crate::Validate,
+        R: std::io::Read,
+    {
+        use super::from_reader;
+
+        dispatch!(self, from_reader::<_, T, R>(reader))
+    }
+
+    /// Validate, and serialize to the string.
+    pub fn to_string<T>(&self, value: &T) -> Result<String, crate::Error<DynFormatError>>
+    where
+        T: ?Sized + serde::Serialize + crate::Validate,
+    {
+        use super::to_string;
+
+        dispatch!(self, to_string::<_, T>(value))
+    }
+}
+
+/// Error of the format picked at runtime.
+#[derive(Debug, thiserror::Error)]
+#[error(transparent)]
+pub struct DynFormatError(Box<dyn std::error::Error + Send + Sync>);
+
+impl DynFormatError {
+    pub fn new<E>(error: E) -> Self
+    where
+        E: std::error::Error + Send + Sync + 'static,
+    {
+        Self(Box::new(error))
+    }
+
+    /// Return the error of the format, to be downcasted.
+    pub fn into_inner(self) -> Box<dyn std::error::Error + Send + Sync> {
+        self.0
+    }
+}
+
+fn into_dyn_error<E>(error: crate::Error<E>) -> crate::Error<DynFormatError>
+where
+    E: std::error::Error + Send + Sync + 'static,
+{
+    match error {
+        crate::Error::DeserializeError(error) => {
+            crate::Error::DeserializeError(DynFormatError::new(error))
+        }
+        crate::Error::ValidationError(errors) => crate::Error::ValidationError(errors),
+    }
+}
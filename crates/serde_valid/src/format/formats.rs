@@ -0,0 +1,209 @@
+use super::DataFormat;
+
+/// Read the whole reader into the string, for the formats which have no reader API.
+#[cfg(any(feature = "json5", feature = "toml"))]
+fn read_to_string<R, E>(mut reader: R) -> Result<String, E>
+where
+    R: std::io::Read,
+    E: serde::de::Error,
+{
+    let mut buffer = String::new();
+    reader.read_to_string(&mut buffer).map_err(E::custom)?;
+    Ok(buffer)
+}
+
+/// [JSON](https://www.json.org/) format.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Json;
+
+impl DataFormat for Json {
+    type DeserializeError = serde_json::Error;
+    type SerializeError = serde_json::Error;
+
+    fn deserialize_str<T>(str: &str) -> Result<T, Self::DeserializeError>
+    where
+        T: serde::de::DeserializeOwned,
+    {
+        serde_json::from_str(str)
+    }
+
+    fn deserialize_reader<T, R>(reader: R) -> Result<T, Self::DeserializeError>
+    where
+        T: serde::de::DeserializeOwned,
+        R: std::io::Read,
+    {
+        serde_json::from_reader(reader)
+    }
+
+    fn serialize_string<T>(value: &T) -> Result<String, Self::SerializeError>
+    where
+        T: ?Sized + serde::Serialize,
+    {
+        serde_json::to_string(value)
+    }
+}
+
+/// [TOML](https://toml.io/) format.
+#[cfg(feature = "toml")]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Toml;
+
+#[cfg(feature = "toml")]
+impl DataFormat for Toml {
+    type DeserializeError = serde_toml::de::Error;
+    type SerializeError = serde_toml::ser::Error;
+
+    fn deserialize_str<T>(str: &str) -> Result<T, Self::DeserializeError>
+    where
+        T: serde::de::DeserializeOwned,
+    {
+        serde_toml::from_str(str)
+    }
+
+    fn deserialize_reader<T, R>(reader: R) -> Result<T, Self::DeserializeError>
+    where
+        T: serde::de::DeserializeOwned,
+        R: std::io::Read,
+    {
+        serde_toml::from_str(&read_to_string::<_, Self::DeserializeError>(reader)?)
+    }
+
+    fn serialize_string<T>(value: &T) -> Result<String, Self::SerializeError>
+    where
+        T: ?Sized + serde::Serialize,
+    {
+        serde_toml::to_string(value)
+    }
+}
+
+/// [YAML](https://yaml.org/) format.
+#[cfg(feature = "yaml")]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Yaml;
+
+#[cfg(feature = "yaml")]
+impl DataFormat for Yaml {
+    type DeserializeError = serde_yaml::Error;
+    type SerializeError = serde_yaml::Error;
+
+    fn deserialize_str<T>(str: &str) -> Result<T, Self::DeserializeError>
+    where
+        T: serde::de::DeserializeOwned,
+    {
+        serde_yaml::from_str(str)
+    }
+
+    fn deserialize_reader<T, R>(reader: R) -> Result<T, Self::DeserializeError>
+    where
+        T: serde::de::DeserializeOwned,
+        R: std::io::Read,
+    {
+        serde_yaml::from_reader(reader)
+    }
+
+    fn serialize_string<T>(value: &T) -> Result<String, Self::SerializeError>
+    where
+        T: ?Sized + serde::Serialize,
+    {
+        serde_yaml::to_string(value)
+    }
+}
+
+/// [JSON5](https://json5.org/) format.
+#[cfg(feature = "json5")]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Json5;
+
+#[cfg(feature = "json5")]
+impl DataFormat for Json5 {
+    type DeserializeError = json5::Error;
+    type SerializeError = json5::Error;
+
+    fn deserialize_str<T>(str: &str) -> Result<T, Self::DeserializeError>
+    where
+        T: serde::de::DeserializeOwned,
+    {
+        json5::from_str(str)
+    }
+
+    fn deserialize_reader<T, R>(reader: R) -> Result<T, Self::DeserializeError>
+    where
+        T: serde::de::DeserializeOwned,
+        R: std::io::Read,
+    {
+        json5::from_str(&read_to_string::<_, Self::DeserializeError>(reader)?)
+    }
+
+    fn serialize_string<T>(value: &T) -> Result<String, Self::SerializeError>
+    where
+        T: ?Sized + serde::Serialize,
+    {
+        json5::to_string(&value)
+    }
+}
+
+/// [RON](https://github.com/ron-rs/ron) format.
+#[cfg(feature = "ron")]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Ron;
+
+#[cfg(feature = "ron")]
+impl DataFormat for Ron {
+    type DeserializeError = ron::error::SpannedError;
+    type SerializeError = ron::Error;
+
+    fn deserialize_str<T>(str: &str) -> Result<T, Self::DeserializeError>
+    where
+        T: serde::de::DeserializeOwned,
+    {
+        ron::from_str(str)
+    }
+
+    fn deserialize_reader<T, R>(reader: R) -> Result<T, Self::DeserializeError>
+    where
+        T: serde::de::DeserializeOwned,
+        R: std::io::Read,
+    {
+        ron::de::from_reader(reader)
+    }
+
+    fn serialize_string<T>(value: &T) -> Result<String, Self::SerializeError>
+    where
+        T: ?Sized + serde::Serialize,
+    {
+        ron::to_string(value)
+    }
+}
+
+/// [XML](https://www.w3.org/XML/) format.
+#[cfg(feature = "xml")]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Xml;
+
+#[cfg(feature = "xml")]
+impl DataFormat for Xml {
+    type DeserializeError = quick_xml::DeError;
+    type SerializeError = quick_xml::SeError;
+
+    fn deserialize_str<T>(str: &str) -> Result<T, Self::DeserializeError>
+    where
+        T: serde::de::DeserializeOwned,
+    {
+        quick_xml::de::from_str(str)
+    }
+
+    fn deserialize_reader<T, R>(reader: R) -> Result<T, Self::DeserializeError>
+    where
+        T: serde::de::DeserializeOwned,
+        R: std::io::Read,
+    {
+        quick_xml::de::from_reader(std::io::BufReader::new(reader))
+    }
+
+    fn serialize_string<T>(value: &T) -> Result<String, Self::SerializeError>
+    where
+        T: ?Sized + serde::Serialize,
+    {
+        quick_xml::se::to_string(value)
+    }
+}
@@ -1,17 +1,24 @@
+mod from_json_array_reader;
+mod from_json_lines_reader;
 mod from_json_reader;
 mod from_json_slice;
 mod from_json_str;
 mod from_json_value;
+pub(crate) mod lenient;
+mod span_map;
 mod to_json_string;
 mod to_json_value;
 mod to_json_writer;
 
 pub use serde_json::{json, Map, Value};
 
+pub use from_json_array_reader::{from_json_array_reader, validate_json_array_reader};
+pub use from_json_lines_reader::{from_json_lines_reader, JsonLines, LineError};
 pub use from_json_reader::FromJsonReader;
 pub use from_json_slice::FromJsonSlice;
 pub use from_json_str::FromJsonStr;
 pub use from_json_value::FromJsonValue;
+pub use span_map::span_map_from_json_str;
 pub use to_json_string::ToJsonString;
 pub use to_json_value::ToJsonValue;
 pub use to_json_writer::ToJsonWriter;
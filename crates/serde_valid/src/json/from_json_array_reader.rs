@@ -0,0 +1,133 @@
+use indexmap::IndexMap;
+use serde::de::{DeserializeOwned, Deserializer, SeqAccess, Visitor};
+
+use crate::validation::{ArrayErrors, Errors, ItemErrorsMap};
+
+/// Validate the top-level json array element by element, without collecting it into a `Vec`.
+///
+/// The errors are keyed by the index of the element, like [`ArrayErrors::items`].
+///
+/// ```rust
+/// use serde::Deserialize;
+/// use serde_json::json;
+/// use serde_valid::Validate;
+/// use serde_valid::json::validate_json_array_reader;
+///
+/// #[derive(Debug, Validate, Deserialize)]
+/// struct TestStruct {
+///     #[validate(maximum = 100)]
+///     val: i32,
+/// }
+///
+/// let input = r#"[{"val": 1}, {"val": 200}, {"val": 3}]"#;
+/// let err = validate_json_array_reader::<TestStruct, _>(input.as_bytes()).unwrap_err();
+///
+/// assert_eq!(
+///     err.as_validation_errors().unwrap().to_string(),
+///     json!({
+///         "errors": [],
+///         "items": {
+///             "1": {
+///                 "errors": [],
+///                 "properties": {
+///                     "val": {
+///                         "errors": ["The number must be `<= 100`."]
+///                     }
+///                 }
+///             }
+///         }
+///     })
+///     .to_string()
+/// );
+/// ```
+pub fn validate_json_array_reader<T, R>(reader: R) -> Result<(), crate::Error<serde_json::Error>>
+where
+    T: DeserializeOwned + crate::Validate,
+    R: std::io::Read,
+{
+    from_json_array_reader(reader, |_: T| {})
+}
+
+/// Validate the top-level json array element by element, and pass the valid elements to the callback.
+///
+/// Only one element is kept in memory at a time, besides the errors of the invalid elements.
+/// The deserialization error stops the reading, because the rest of the stream cannot be located.
+///
+/// ```rust
+/// use serde::Deserialize;
+/// use serde_valid::Validate;
+/// use serde_valid::json::from_json_array_reader;
+///
+/// #[derive(Debug, Validate, Deserialize)]
+/// struct TestStruct {
+///     #[validate(maximum = 100)]
+///     val: i32,
+/// }
+///
+/// let input = r#"[{"val": 1}, {"val": 200}, {"val": 3}]"#;
+/// let mut sum = 0;
+/// let result = from_json_array_reader(input.as_bytes(), |item: TestStruct| sum += item.val);
+///
+/// assert!(result.is_err());
+/// assert_eq!(sum, 4);
+/// ```
+pub fn from_json_array_reader<T, R, F>(
+    reader: R,
+    on_valid: F,
+) -> Result<(), crate::Error<serde_json::Error>>
+where
+    T: DeserializeOwned + crate::Validate,
+    R: std::io::Read,
+    F: FnMut(T),
+{
+    let mut deserializer = serde_json::Deserializer::from_reader(std::io::BufReader::new(reader));
+    let items = (&mut deserializer).deserialize_seq(ArrayVisitor {
+        on_valid,
+        _marker: std::marker::PhantomData,
+    })?;
+    deserializer.end()?;
+
+    if items.is_empty() {
+        Ok(())
+    } else {
+        Err(crate::Error::ValidationError(Errors::Array(
+            ArrayErrors::new(vec![], items),
+        )))
+    }
+}
+
+struct ArrayVisitor<T, F> {
+    on_valid: F,
+    _marker: std::marker::PhantomData<fn() -> T>,
+}
+
+impl<'de, T, F> Visitor<'de> for ArrayVisitor<T, F>
+where
+    T: DeserializeOwned + crate::Validate,
+    F: FnMut(T),
+{
+    type Value = ItemErrorsMap<crate::validation::Error>;
+
+    fn expecting(&self, formatter: &mut std::fmt::Formatter) -> std::fmt::Result {
+        formatter.write_str("a sequence")
+    }
+
+    fn visit_seq<A>(mut self, mut seq: A) -> Result<Self::Value, A::Error>
+    where
+        A: SeqAccess<'de>,
+    {
+        let mut items = IndexMap::new();
+        let mut index = 0;
+
+        while let Some(item) = seq.next_element::<T>()? {
+            match item.validate() {
+                Ok(()) => (self.on_valid)(item),
+                Err(errors) => {
+                    items.insert(index, errors);
+                }
+            }
+            index += 1;
+        }
+        Ok(items)
+    }
+}
@@ -0,0 +1,186 @@
+use std::io::BufRead;
+
+use indexmap::IndexMap;
+
+use crate::validation::error::Format;
+use crate::validation::{ArrayErrors, Errors};
+use crate::TypeError;
+
+/// Read [JSON Lines](https://jsonlines.org/) (NDJSON), validating each line.
+///
+/// The lines are read one by one, so the memory usage does not depend on the size of the input.
+/// Blank lines are skipped.
+///
+/// ```rust
+/// use serde::Deserialize;
+/// use serde_valid::Validate;
+/// use serde_valid::json::from_json_lines_reader;
+///
+/// #[derive(Debug, Validate, Deserialize)]
+/// struct TestStruct {
+///     #[validate(maximum = 100)]
+///     val: i32,
+/// }
+///
+/// let input = "{\"val\": 1}\n{\"val\": 200}\n{\"val\": 3}\n";
+/// let results = from_json_lines_reader::<TestStruct, _>(input.as_bytes()).collect::<Vec<_>>();
+///
+/// assert!(results[0].is_ok());
+/// assert_eq!(results[1].as_ref().unwrap_err().line, 2);
+/// assert!(results[2].is_ok());
+/// ```
+pub fn from_json_lines_reader<T, R>(reader: R) -> JsonLines<T, R>
+where
+    T: serde::de::DeserializeOwned + crate::Validate,
+    R: std::io::Read,
+{
+    JsonLines {
+        reader: std::io::BufReader::new(reader),
+        buffer: Vec::new(),
+        line: 0,
+        finished: false,
+        _marker: std::marker::PhantomData,
+    }
+}
+
+/// Iterator over the validated values of JSON Lines.
+///
+/// Created by [`from_json_lines_reader`].
+#[derive(Debug)]
+pub struct JsonLines<T, R> {
+    reader: std::io::BufReader<R>,
+    buffer: Vec<u8>,
+    line: usize,
+    finished: bool,
+    _marker: std::marker::PhantomData<fn() -> T>,
+}
+
+impl<T, R> JsonLines<T, R>
+where
+    T: serde::de::DeserializeOwned + crate::Validate,
+    R: std::io::Read,
+{
+    /// Read all the lines, and aggregate the errors into [`ArrayErrors`] keyed by the 1-based line number.
+    ///
+    /// The deserialization errors of the lines are reported as [`TypeError`].
+    /// The I/O error stops the reading, and is returned as [`crate::Error::DeserializeError`].
+    ///
+    /// ```rust
+    /// use serde::Deserialize;
+    /// use serde_json::json;
+    /// use serde_valid::Validate;
+    /// use serde_valid::json::from_json_lines_reader;
+    ///
+    /// #[derive(Debug, Validate, Deserialize)]
+    /// struct TestStruct {
+    ///     #[validate(maximum = 100)]
+    ///     val: i32,
+    /// }
+    ///
+    /// let input = "{\"val\": 1}\n{\"val\": 200}\n\n{\"val\": 3}\n";
+    /// let err = from_json_lines_reader::<TestStruct, _>(input.as_bytes())
+    ///     .summarize()
+    ///     .unwrap_err();
+    ///
+    /// assert_eq!(
+    ///     err.as_validation_errors().unwrap().to_string(),
+    ///     json!({
+    ///         "errors": [],
+    ///         "items": {
+    ///             "2": {
+    ///                 "errors": [],
+    ///                 "properties": {
+    ///                     "val": {
+    ///                         "errors": ["The number must be `<= 100`."]
+    ///                     }
+    ///                 }
+    ///             }
+    ///         }
+    ///     })
+    ///     .to_string()
+    /// );
+    /// ```
+    pub fn summarize(self) -> Result<(), crate::Error<serde_json::Error>> {
+        let mut items = IndexMap::new();
+
+        for result in self {
+            let LineError { line, error } = match result {
+                Ok(_) => continue,
+                Err(error) => error,
+            };
+            let errors = match error {
+                crate::Error::ValidationError(errors) => errors,
+                crate::Error::DeserializeError(error) if error.is_io() => {
+                    return Err(crate::Error::DeserializeError(error))
+                }
+                crate::Error::DeserializeError(error) => {
+                    Errors::NewType(vec![crate::validation::Error::Type(
+                        Format::Default.into_message(TypeError::new(error.to_string())),
+                    )])
+                }
+            };
+            items.insert(line, errors);
+        }
+
+        if items.is_empty() {
+            Ok(())
+        } else {
+            Err(crate::Error::ValidationError(Errors::Array(
+                ArrayErrors::new(vec![], items),
+            )))
+        }
+    }
+}
+
+impl<T, R> Iterator for JsonLines<T, R>
+where
+    T: serde::de::DeserializeOwned + crate::Validate,
+    R: std::io::Read,
+{
+    type Item = Result<T, LineError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while !self.finished {
+            self.buffer.clear();
+            self.line += 1;
+
+            match self.reader.read_until(b'\n', &mut self.buffer) {
+                Ok(0) => self.finished = true,
+                Ok(_) if self.buffer.iter().all(u8::is_ascii_whitespace) => continue,
+                Ok(_) => {
+                    // the invalid utf-8 is the error of this line only.
+                    let result = serde_json::from_slice::<T>(&self.buffer)
+                        .map_err(crate::Error::DeserializeError)
+                        .and_then(|model| {
+                            model.validate().map_err(crate::Error::ValidationError)?;
+                            Ok(model)
+                        });
+                    return Some(result.map_err(|error| LineError::new(self.line, error)));
+                }
+                Err(error) => {
+                    self.finished = true;
+                    return Some(Err(LineError::new(
+                        self.line,
+                        crate::Error::DeserializeError(serde_json::Error::io(error)),
+                    )));
+                }
+            }
+        }
+        None
+    }
+}
+
+/// Error of the line in JSON Lines.
+#[derive(Debug, thiserror::Error)]
+#[error("line {line}: {error}")]
+pub struct LineError {
+    /// 1-based line number.
+    pub line: usize,
+    pub error: crate::Error<serde_json::Error>,
+}
+
+impl LineError {
+    pub fn new(line: usize, error: crate::Error<serde_json::Error>) -> Self {
+        Self { line, error }
+    }
+}
@@ -20,6 +20,28 @@ where
     /// assert!(s.is_ok())
     /// ```
     fn from_json_slice(slice: &'de [u8]) -> Result<Self, crate::Error<serde_json::Error>>;
+
+    /// Convert from json slice, and attach the source locations to the validation errors.
+    ///
+    /// ```rust
+    /// use serde::Deserialize;
+    /// use serde_valid::Validate;
+    /// use serde_valid::json::FromJsonSlice;
+    ///
+    /// #[derive(Debug, Validate, Deserialize)]
+    /// struct TestStruct {
+    ///     #[validate(maximum = 100)]
+    ///     val: i32,
+    /// }
+    ///
+    /// let err = TestStruct::from_json_slice_with_location(br#"{ "val": 123 }"#).unwrap_err();
+    /// let located_error = err.as_validation_errors().unwrap().iter().next().unwrap();
+    ///
+    /// assert_eq!(located_error.location.unwrap().to_string(), "1:10");
+    /// ```
+    fn from_json_slice_with_location(
+        slice: &'de [u8],
+    ) -> Result<Self, crate::location::Error<serde_json::Error>>;
 }
 
 impl<'de, T> FromJsonSlice<'de> for T
@@ -31,4 +53,19 @@ where
         model.validate().map_err(crate::Error::ValidationError)?;
         Ok(model)
     }
+
+    fn from_json_slice_with_location(
+        slice: &'de [u8],
+    ) -> Result<Self, crate::location::Error<serde_json::Error>> {
+        let model: T = serde_json::from_slice(slice)?;
+        model.validate().map_err(|errors| {
+            let source = String::from_utf8_lossy(slice);
+            crate::location::Error::ValidationError(Box::new(crate::location::LocatedErrors::new(
+                source.as_ref(),
+                &super::span_map_from_json_str(&source),
+                errors,
+            )))
+        })?;
+        Ok(model)
+    }
 }
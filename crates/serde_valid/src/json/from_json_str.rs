@@ -20,6 +20,86 @@ where
     /// assert!(s.is_ok())
     /// ```
     fn from_json_str(str: &'de str) -> Result<Self, crate::Error<serde_json::Error>>;
+
+    /// Convert from json str, and attach the source locations to the validation errors.
+    ///
+    /// ```rust
+    /// use serde::Deserialize;
+    /// use serde_valid::Validate;
+    /// use serde_valid::json::FromJsonStr;
+    ///
+    /// #[derive(Debug, Validate, Deserialize)]
+    /// struct TestStruct {
+    ///     #[validate(maximum = 100)]
+    ///     val: i32,
+    /// }
+    ///
+    /// let err = TestStruct::from_json_str_with_location("{\n  \"val\": 123\n}")
+    ///     .unwrap_err()
+    ///     .with_origin("config.json");
+    ///
+    /// assert_eq!(
+    ///     err.to_string(),
+    ///     concat!(
+    ///         "error at /val: The number must be `<= 100`.\n",
+    ///         " --> config.json:2:10\n",
+    ///         "  |\n",
+    ///         "2 |   \"val\": 123\n",
+    ///         "  |          ^^^\n",
+    ///     )
+    /// );
+    /// ```
+    fn from_json_str_with_location(
+        str: &'de str,
+    ) -> Result<Self, crate::location::Error<serde_json::Error>>;
+
+    /// Convert from json str, collecting the deserialization errors
+    /// (type mismatches and missing fields) into the validation errors.
+    ///
+    /// See [`FromJsonValue::from_json_value_lenient`](super::FromJsonValue::from_json_value_lenient).
+    /// The syntax errors of the json are still returned as [`crate::Error::DeserializeError`].
+    ///
+    /// ```rust
+    /// use serde::Deserialize;
+    /// use serde_valid::Validate;
+    /// use serde_valid::json::{json, FromJsonStr};
+    ///
+    /// #[derive(Debug, Validate, Deserialize)]
+    /// struct TestStruct {
+    ///     #[validate(maximum = 100)]
+    ///     score: i32,
+    ///     items: Vec<i32>,
+    /// }
+    ///
+    /// let err = TestStruct::from_json_str_lenient(r#"{ "score": 200, "items": [1, "2"] }"#)
+    ///     .unwrap_err();
+    ///
+    /// assert_eq!(
+    ///     err.as_validation_errors().unwrap().to_string(),
+    ///     json!({
+    ///         "errors": [],
+    ///         "properties": {
+    ///             "items": {
+    ///                 "errors": [],
+    ///                 "items": {
+    ///                     "1": {
+    ///                         "errors": [
+    ///                             "The value cannot be deserialized: invalid type: string \"2\", expected i32."
+    ///                         ]
+    ///                     }
+    ///                 }
+    ///             },
+    ///             "score": {
+    ///                 "errors": ["The number must be `<= 100`."]
+    ///             }
+    ///         }
+    ///     })
+    ///     .to_string()
+    /// );
+    /// ```
+    fn from_json_str_lenient(str: &'de str) -> Result<Self, crate::Error<serde_json::Error>>
+    where
+        Self: super::FromJsonValue;
 }
 
 impl<'de, T> FromJsonStr<'de> for T
@@ -31,4 +111,25 @@ where
         model.validate().map_err(crate::Error::ValidationError)?;
         Ok(model)
     }
+
+    fn from_json_str_with_location(
+        str: &'de str,
+    ) -> Result<Self, crate::location::Error<serde_json::Error>> {
+        let model: Self = serde_json::from_str(str)?;
+        model.validate().map_err(|errors| {
+            crate::location::Error::ValidationError(Box::new(crate::location::LocatedErrors::new(
+                str,
+                &super::span_map_from_json_str(str),
+                errors,
+            )))
+        })?;
+        Ok(model)
+    }
+
+    fn from_json_str_lenient(str: &'de str) -> Result<Self, crate::Error<serde_json::Error>>
+    where
+        Self: super::FromJsonValue,
+    {
+        Self::from_json_value_lenient(serde_json::from_str(str)?)
+    }
 }
@@ -20,6 +20,57 @@ where
     /// assert!(s.is_ok())
     /// ```
     fn from_json_value(value: serde_json::Value) -> Result<Self, crate::Error<serde_json::Error>>;
+
+    /// Convert from [`serde_json::Value`](serde_json::Value), collecting the deserialization errors
+    /// (type mismatches and missing fields) into the validation errors.
+    ///
+    /// The rest of the document is still deserialized and validated,
+    /// so that all the problems are reported at once.
+    /// Each repair deserializes the whole document again, so the number of the repairs
+    /// is bounded by the size of the document. When the repairs run out,
+    /// the errors so far are returned with the error at the root
+    /// `"The deserialization errors are truncated, because the document has too many of them."`.
+    ///
+    /// ```rust
+    /// use serde::Deserialize;
+    /// use serde_valid::Validate;
+    /// use serde_valid::json::{json, FromJsonValue};
+    ///
+    /// #[derive(Debug, Validate, Deserialize)]
+    /// struct TestStruct {
+    ///     age: i32,
+    ///     #[validate(min_length = 1)]
+    ///     name: String,
+    ///     email: String,
+    /// }
+    ///
+    /// let err = TestStruct::from_json_value_lenient(json!({ "age": "ten", "name": "" }))
+    ///     .unwrap_err();
+    ///
+    /// assert_eq!(
+    ///     err.as_validation_errors().unwrap().to_string(),
+    ///     json!({
+    ///         "errors": [],
+    ///         "properties": {
+    ///             "age": {
+    ///                 "errors": [
+    ///                     "The value cannot be deserialized: invalid type: string \"ten\", expected i32."
+    ///                 ]
+    ///             },
+    ///             "email": {
+    ///                 "errors": ["The value is required."]
+    ///             },
+    ///             "name": {
+    ///                 "errors": ["The length of the value must be `>= 1`."]
+    ///             }
+    ///         }
+    ///     })
+    ///     .to_string()
+    /// );
+    /// ```
+    fn from_json_value_lenient(
+        value: serde_json::Value,
+    ) -> Result<Self, crate::Error<serde_json::Error>>;
 }
 
 impl<T> FromJsonValue for T
@@ -31,4 +82,10 @@ where
         model.validate().map_err(crate::Error::ValidationError)?;
         Ok(model)
     }
+
+    fn from_json_value_lenient(
+        value: serde_json::Value,
+    ) -> Result<Self, crate::Error<serde_json::Error>> {
+        super::lenient::from_json_value_lenient(value)
+    }
 }
@@ -0,0 +1,375 @@
+pub(crate) mod deserializer;
+
+use serde_json::Value;
+
+use crate::validation::error::Format;
+use crate::validation::{Errors, ErrorsBuilder};
+use crate::{RequiredError, TypeError};
+
+use deserializer::ValueDeserializer;
+
+/// Upper limit of the repairs, to stop on the documents which never become deserializable.
+const MAX_REPAIRS: usize = 1024;
+
+/// Message of the root error which tells that the errors are truncated by the limits.
+pub(crate) const TRUNCATED_MESSAGE: &str =
+    "The deserialization errors are truncated, because the document has too many of them.";
+
+/// Upper limit of the json values deserialized in total while repairing.
+///
+/// Each repair deserializes the whole document again,
+/// so the number of the repairs is bounded by the size of the document.
+const MAX_DESERIALIZED_VALUES: usize = 1 << 22;
+
+type DeserializeError = serde_path_to_error::Error<deserializer::Error>;
+
+/// Deserialize the json value, collecting the deserialization errors into the validation errors.
+///
+/// When the deserialization fails, the error is recorded at its path and the value is
+/// repaired with a placeholder, so that the rest of the document is still checked.
+/// The errors caused by the placeholders, on the paths around the repaired values,
+/// and the validation errors inside the repaired values are not reported.
+///
+/// When the repairs hit the limits, the errors so far are returned
+/// with the [`TRUNCATED_MESSAGE`] error at the root.
+pub(crate) fn from_json_value_lenient<T>(
+    mut value: Value,
+) -> Result<T, crate::Error<serde_json::Error>>
+where
+    T: serde::de::DeserializeOwned + crate::Validate,
+{
+    let mut builder = ErrorsBuilder::new();
+    let mut repaired: Vec<Issue> = vec![];
+    let mut budget = MAX_DESERIALIZED_VALUES / count_values(&value);
+    let mut result = deserialize::<T>(&value);
+
+    let model = loop {
+        let error = match result {
+            Ok(model) => break model,
+            Err(error) => error,
+        };
+        let Some(issue) = Issue::new(&error) else {
+            return Err(into_error(builder, error));
+        };
+        if repaired.iter().any(|other| other.path == issue.path) {
+            return Err(into_error(builder, error));
+        }
+        if !repaired.iter().any(|other| other.is_around(&issue)) {
+            issue.record(&mut builder);
+        }
+        if repaired.len() >= MAX_REPAIRS {
+            return Err(into_truncated_error(builder));
+        }
+
+        match issue.repair::<T>(&mut value, &mut budget) {
+            Some(repaired_result) => {
+                result = repaired_result;
+                repaired.push(issue);
+            }
+            None if budget == 0 => return Err(into_truncated_error(builder)),
+            None => return Err(into_error(builder, error)),
+        }
+    };
+
+    if let Err(errors) = model.validate() {
+        if let Some(errors) = repaired.iter().try_fold(errors, |errors, issue| {
+            remove_errors(errors, &issue.errors_path())
+        }) {
+            builder.merge(errors);
+        }
+    }
+
+    match builder.build() {
+        Ok(()) => Ok(model),
+        Err(errors) => Err(crate::Error::ValidationError(errors)),
+    }
+}
+
+fn deserialize<T>(value: &Value) -> Result<T, DeserializeError>
+where
+    T: serde::de::DeserializeOwned,
+{
+    serde_path_to_error::deserialize(ValueDeserializer(value))
+}
+
+fn into_error(builder: ErrorsBuilder, error: DeserializeError) -> crate::Error<serde_json::Error> {
+    match builder.build() {
+        Ok(()) => crate::Error::DeserializeError(serde::de::Error::custom(error.into_inner())),
+        Err(errors) => crate::Error::ValidationError(errors),
+    }
+}
+
+fn into_truncated_error(mut builder: ErrorsBuilder) -> crate::Error<serde_json::Error> {
+    builder.push_root(crate::validation::Error::Custom(
+        TRUNCATED_MESSAGE.to_string(),
+    ));
+    match builder.build() {
+        Ok(()) => unreachable!("the builder has the truncated error"),
+        Err(errors) => crate::Error::ValidationError(errors),
+    }
+}
+
+/// Number of the json values in the document, including itself.
+fn count_values(value: &Value) -> usize {
+    1 + match value {
+        Value::Array(array) => array.iter().map(count_values).sum(),
+        Value::Object(object) => object.values().map(count_values).sum(),
+        _ => 0,
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum Segment {
+    Key(String),
+    Index(usize),
+    /// Externally tagged enum variant, which is not shown in the validation errors.
+    Variant(String),
+}
+
+#[derive(Debug)]
+struct Issue {
+    path: Vec<Segment>,
+    kind: IssueKind,
+}
+
+#[derive(Debug)]
+enum IssueKind {
+    Type(String),
+    Required,
+}
+
+impl Issue {
+    fn new(error: &DeserializeError) -> Option<Self> {
+        let mut path = error
+            .path()
+            .iter()
+            .map(|segment| match segment {
+                serde_path_to_error::Segment::Map { key } => Some(Segment::Key(key.clone())),
+                serde_path_to_error::Segment::Seq { index } => Some(Segment::Index(*index)),
+                serde_path_to_error::Segment::Enum { variant } => {
+                    Some(Segment::Variant(variant.clone()))
+                }
+                serde_path_to_error::Segment::Unknown => None,
+            })
+            .collect::<Option<Vec<_>>>()?;
+
+        let kind = match error.inner() {
+            deserializer::Error::MissingField(field) => {
+                path.push(Segment::Key(field.to_string()));
+                IssueKind::Required
+            }
+            deserializer::Error::Custom(reason) => IssueKind::Type(reason.clone()),
+        };
+
+        Some(Self { path, kind })
+    }
+
+    /// Whether the other issue is on this path, or on a path inside or containing it,
+    /// where the value is built from the placeholder of this issue.
+    fn is_around(&self, other: &Issue) -> bool {
+        self.path.starts_with(&other.path) || other.path.starts_with(&self.path)
+    }
+
+    fn errors_path(&self) -> Vec<&Segment> {
+        self.path
+            .iter()
+            .filter(|segment| !matches!(segment, Segment::Variant(_)))
+            .collect()
+    }
+
+    fn record(&self, builder: &mut ErrorsBuilder) {
+        let error = match &self.kind {
+            IssueKind::Type(reason) => crate::validation::Error::Type(
+                Format::Default.into_message(TypeError::new(reason.as_str())),
+            ),
+            IssueKind::Required => {
+                crate::validation::Error::Required(Format::Default.into_message(RequiredError))
+            }
+        };
+
+        let mut errors_path = self.errors_path().into_iter();
+        let mut path = match errors_path.next() {
+            Some(Segment::Index(index)) => builder.index(*index),
+            Some(Segment::Key(key) | Segment::Variant(key)) => builder.at(key.clone()),
+            None => {
+                builder.push_root(error);
+                return;
+            }
+        };
+        for segment in errors_path {
+            path = match segment {
+                Segment::Index(index) => path.index(*index),
+                Segment::Key(key) | Segment::Variant(key) => path.at(key.clone()),
+            };
+        }
+        path.push(error);
+    }
+
+    /// Replace the value at the path with the first placeholder which gets past the issue.
+    ///
+    /// Each try consumes the budget by the size of the document, and stops when it runs out.
+    fn repair<T>(
+        &self,
+        value: &mut Value,
+        budget: &mut usize,
+    ) -> Option<Result<T, DeserializeError>>
+    where
+        T: serde::de::DeserializeOwned,
+    {
+        let placeholders = [
+            Some(Value::Null),
+            Some(Value::Bool(false)),
+            Some(Value::from(0)),
+            Some(Value::String(String::new())),
+            Some(Value::Array(vec![])),
+            Some(Value::Object(serde_json::Map::new())),
+        ];
+        let removal = match self.kind {
+            IssueKind::Type(_) => Some(None),
+            IssueKind::Required => None,
+        };
+
+        for placeholder in placeholders.into_iter().chain(removal) {
+            *budget = budget.checked_sub(1)?;
+            let Some(previous) = replace(value, &self.path, placeholder) else {
+                continue;
+            };
+            let result = deserialize::<T>(value);
+            match &result {
+                Err(error) if Issue::new(error).is_some_and(|issue| issue.path == self.path) => {
+                    replace(value, &self.path, previous);
+                }
+                _ => return Some(result),
+            }
+        }
+        None
+    }
+}
+
+/// Replace the value at the path, or remove it when the placeholder is `None`.
+///
+/// Returns the previous value, which is `None` when it did not exist,
+/// or `None` when the path cannot be replaced.
+fn replace(
+    value: &mut Value,
+    path: &[Segment],
+    placeholder: Option<Value>,
+) -> Option<Option<Value>> {
+    let Some((last, parent_path)) = path.split_last() else {
+        return placeholder.map(|placeholder| Some(std::mem::replace(value, placeholder)));
+    };
+
+    let mut parent = value;
+    for segment in parent_path {
+        parent = match (segment, parent) {
+            (Segment::Key(key) | Segment::Variant(key), Value::Object(map)) => map.get_mut(key)?,
+            (Segment::Index(index), Value::Array(array)) => array.get_mut(*index)?,
+            _ => return None,
+        };
+    }
+
+    match (last, parent, placeholder) {
+        (Segment::Key(key) | Segment::Variant(key), Value::Object(map), Some(placeholder)) => {
+            Some(map.insert(key.clone(), placeholder))
+        }
+        (Segment::Key(key) | Segment::Variant(key), Value::Object(map), None) => {
+            map.remove(key).map(Some)
+        }
+        (Segment::Index(index), Value::Array(array), Some(placeholder)) => array
+            .get_mut(*index)
+            .map(|item| Some(std::mem::replace(item, placeholder))),
+        _ => None,
+    }
+}
+
+/// Remove the errors at the path and under it.
+fn remove_errors(errors: Errors, path: &[&Segment]) -> Option<Errors> {
+    // the empty path removes all the errors.
+    let (first, rest) = path.split_first()?;
+
+    match (errors, first) {
+        (Errors::Object(mut object), Segment::Key(key)) => {
+            if let Some(index) = object.properties.get_index_of(key.as_str()) {
+                let (property, child) = object.properties.shift_remove_index(index)?;
+                if let Some(child) = remove_errors(child, rest) {
+                    object.properties.shift_insert(index, property, child);
+                }
+            }
+            if object.errors.is_empty() && object.properties.is_empty() {
+                None
+            } else {
+                Some(Errors::Object(object))
+            }
+        }
+        (Errors::Array(mut array), Segment::Index(index)) => {
+            if let Some(position) = array.items.get_index_of(index) {
+                let (index, child) = array.items.shift_remove_index(position)?;
+                if let Some(child) = remove_errors(child, rest) {
+                    array.items.shift_insert(position, index, child);
+                }
+            }
+            if array.errors.is_empty() && array.items.is_empty() {
+                None
+            } else {
+                Some(Errors::Array(array))
+            }
+        }
+        (errors, _) => Some(errors),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_replace() {
+        let mut value = json!({ "a": [1, { "b": 2 }] });
+        let path = [
+            Segment::Key("a".to_string()),
+            Segment::Index(1),
+            Segment::Key("b".to_string()),
+        ];
+
+        assert_eq!(
+            replace(&mut value, &path, Some(json!("x"))),
+            Some(Some(json!(2)))
+        );
+        assert_eq!(value, json!({ "a": [1, { "b": "x" }] }));
+
+        assert_eq!(replace(&mut value, &path, None), Some(Some(json!("x"))));
+        assert_eq!(value, json!({ "a": [1, {}] }));
+
+        assert_eq!(replace(&mut value, &path, Some(json!(3))), Some(None));
+        assert_eq!(replace(&mut value, &path[..2], None), None);
+    }
+
+    #[test]
+    fn test_remove_errors() {
+        let mut builder = ErrorsBuilder::new();
+        builder
+            .at("a")
+            .index(1)
+            .push(crate::validation::Error::Custom("x".to_string()));
+        builder
+            .at("b")
+            .push(crate::validation::Error::Custom("y".to_string()));
+        let errors = builder.build().unwrap_err();
+
+        let a = Segment::Key("a".to_string());
+        let index = Segment::Index(1);
+        let errors = remove_errors(errors, &[&a, &index]).unwrap();
+
+        assert_eq!(
+            errors.to_string(),
+            json!({
+                "errors": [],
+                "properties": {
+                    "b": { "errors": ["y"] }
+                }
+            })
+            .to_string()
+        );
+    }
+}
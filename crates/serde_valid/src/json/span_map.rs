@@ -0,0 +1,184 @@
+use crate::flatten::escape_pointer_token;
+use crate::location::{Span, SpanMap};
+
+/// Record the spans of all values in the json str.
+///
+/// ```rust
+/// use serde_valid::json::span_map_from_json_str;
+/// use serde_valid::location::Span;
+///
+/// let span_map = span_map_from_json_str(r#"{ "val": [1, 23] }"#);
+///
+/// assert_eq!(span_map.get("/val/1"), Some(Span::new(13, 15)));
+/// ```
+///
+/// Invalid json is recorded as far as it can be read.
+pub fn span_map_from_json_str(source: &str) -> SpanMap {
+    let mut scanner = Scanner {
+        source: source.as_bytes(),
+        position: 0,
+        span_map: SpanMap::new(),
+    };
+    scanner.scan_value(String::new());
+    scanner.span_map
+}
+
+struct Scanner<'a> {
+    source: &'a [u8],
+    position: usize,
+    span_map: SpanMap,
+}
+
+impl Scanner<'_> {
+    fn peek(&self) -> Option<u8> {
+        self.source.get(self.position).copied()
+    }
+
+    fn skip_whitespace(&mut self) {
+        while let Some(b' ' | b'\t' | b'\n' | b'\r') = self.peek() {
+            self.position += 1;
+        }
+    }
+
+    fn scan_value(&mut self, pointer: String) -> Option<()> {
+        self.skip_whitespace();
+        let start = self.position;
+        match self.peek()? {
+            b'{' => self.scan_object(&pointer)?,
+            b'[' => self.scan_array(&pointer)?,
+            b'"' => {
+                self.scan_string()?;
+            }
+            _ => self.scan_literal()?,
+        }
+        self.span_map
+            .insert(pointer, Span::new(start, self.position));
+        Some(())
+    }
+
+    fn scan_object(&mut self, pointer: &str) -> Option<()> {
+        self.position += 1;
+        loop {
+            self.skip_whitespace();
+            match self.peek()? {
+                b'}' => {
+                    self.position += 1;
+                    return Some(());
+                }
+                b',' => {
+                    self.position += 1;
+                }
+                b'"' => {
+                    let key = self.scan_string()?;
+                    self.skip_whitespace();
+                    if self.peek()? != b':' {
+                        return None;
+                    }
+                    self.position += 1;
+                    self.scan_value(format!("{pointer}/{}", escape_pointer_token(&key)))?;
+                }
+                _ => return None,
+            }
+        }
+    }
+
+    fn scan_array(&mut self, pointer: &str) -> Option<()> {
+        self.position += 1;
+        let mut index = 0;
+        loop {
+            self.skip_whitespace();
+            match self.peek()? {
+                b']' => {
+                    self.position += 1;
+                    return Some(());
+                }
+                b',' => {
+                    self.position += 1;
+                }
+                _ => {
+                    self.scan_value(format!("{pointer}/{index}"))?;
+                    index += 1;
+                }
+            }
+        }
+    }
+
+    fn scan_string(&mut self) -> Option<String> {
+        let start = self.position;
+        self.position += 1;
+        loop {
+            match self.peek()? {
+                b'"' => {
+                    self.position += 1;
+                    break;
+                }
+                b'\\' => self.position += 2,
+                _ => self.position += 1,
+            }
+        }
+        serde_json::from_slice(&self.source[start..self.position]).ok()
+    }
+
+    /// Stop with `None` on no progress, like a mismatched closing bracket of `[}`.
+    fn scan_literal(&mut self) -> Option<()> {
+        let start = self.position;
+        while let Some(byte) = self.peek() {
+            match byte {
+                b',' | b']' | b'}' | b' ' | b'\t' | b'\n' | b'\r' => break,
+                _ => self.position += 1,
+            }
+        }
+        (self.position > start).then_some(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_span_map_from_json_str() {
+        let source = r#"{"a": {"b/c": "x\"y", "d": [true, null, -1.5e3]}}"#;
+        let span_map = span_map_from_json_str(source);
+
+        let text = |pointer: &str| {
+            let span = span_map.get(pointer).unwrap();
+            &source[span.start..span.end]
+        };
+
+        assert_eq!(text(""), source);
+        assert_eq!(text("/a/b~1c"), r#""x\"y""#);
+        assert_eq!(text("/a/d"), "[true, null, -1.5e3]");
+        assert_eq!(text("/a/d/0"), "true");
+        assert_eq!(text("/a/d/2"), "-1.5e3");
+    }
+
+    #[test]
+    fn test_span_map_from_invalid_json_str() {
+        let span_map = span_map_from_json_str(r#"{"a": 1, "b": "#);
+
+        assert_eq!(span_map.get("/a"), Some(Span::new(6, 7)));
+        assert_eq!(span_map.get("/b"), None);
+    }
+
+    #[test]
+    fn test_span_map_from_mismatched_json_str() {
+        assert_eq!(span_map_from_json_str("[}").get(""), None);
+        assert_eq!(span_map_from_json_str("{]").get(""), None);
+
+        let span_map = span_map_from_json_str(r#"[1, {"a": }]"#);
+        assert_eq!(span_map.get("/0"), Some(Span::new(1, 2)));
+        assert_eq!(span_map.get("/1/a"), None);
+    }
+
+    #[test]
+    fn test_span_map_from_truncated_json_str() {
+        let source = r#"{"a": [1, 2, {"b": "x"#;
+        for end in 0..source.len() {
+            span_map_from_json_str(&source[..end]);
+        }
+
+        let span_map = span_map_from_json_str(&source[..13]);
+        assert_eq!(span_map.get("/a/1"), Some(Span::new(10, 11)));
+    }
+}
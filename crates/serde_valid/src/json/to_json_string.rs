@@ -34,6 +34,54 @@ pub trait ToJsonString {
     /// assert!(s.to_json_string_pretty().is_ok());
     /// ```
     fn to_json_string_pretty(&self) -> Result<String, serde_json::Error>;
+
+    /// Convert to json string after validation.
+    ///
+    /// ```rust
+    /// use serde::Serialize;
+    /// use serde_valid::json::ToJsonString;
+    /// use serde_valid::Validate;
+    ///
+    /// #[derive(Debug, Validate, Serialize)]
+    /// struct TestStruct {
+    ///     #[validate(maximum = 100)]
+    ///     val: i32,
+    /// }
+    /// let s = TestStruct { val: 1000 };
+    ///
+    /// assert!(s.to_json_string_validated().unwrap_err().is_validation_errors());
+    /// ```
+    fn to_json_string_validated(&self) -> Result<String, crate::Error<serde_json::Error>>
+    where
+        Self: crate::Validate,
+    {
+        self.validate().map_err(crate::Error::ValidationError)?;
+        Ok(self.to_json_string()?)
+    }
+
+    /// Convert to json pretty string after validation.
+    ///
+    /// ```rust
+    /// use serde::Serialize;
+    /// use serde_valid::json::ToJsonString;
+    /// use serde_valid::Validate;
+    ///
+    /// #[derive(Debug, Validate, Serialize)]
+    /// struct TestStruct {
+    ///     #[validate(maximum = 100)]
+    ///     val: i32,
+    /// }
+    /// let s = TestStruct { val: 1000 };
+    ///
+    /// assert!(s.to_json_string_pretty_validated().unwrap_err().is_validation_errors());
+    /// ```
+    fn to_json_string_pretty_validated(&self) -> Result<String, crate::Error<serde_json::Error>>
+    where
+        Self: crate::Validate,
+    {
+        self.validate().map_err(crate::Error::ValidationError)?;
+        Ok(self.to_json_string_pretty()?)
+    }
 }
 
 impl<T> ToJsonString for T
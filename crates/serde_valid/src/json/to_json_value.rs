@@ -16,6 +16,30 @@ pub trait ToJsonValue {
     /// assert!(s.to_json_value().is_ok());
     /// ```
     fn to_json_value(&self) -> Result<serde_json::Value, serde_json::Error>;
+
+    /// Convert to json value after validation.
+    ///
+    /// ```rust
+    /// use serde::Serialize;
+    /// use serde_valid::json::ToJsonValue;
+    /// use serde_valid::Validate;
+    ///
+    /// #[derive(Debug, Validate, Serialize)]
+    /// struct TestStruct {
+    ///     #[validate(maximum = 100)]
+    ///     val: i32,
+    /// }
+    /// let s = TestStruct { val: 1000 };
+    ///
+    /// assert!(s.to_json_value_validated().unwrap_err().is_validation_errors());
+    /// ```
+    fn to_json_value_validated(&self) -> Result<serde_json::Value, crate::Error<serde_json::Error>>
+    where
+        Self: crate::Validate,
+    {
+        self.validate().map_err(crate::Error::ValidationError)?;
+        Ok(self.to_json_value()?)
+    }
 }
 
 impl<T> ToJsonValue for T
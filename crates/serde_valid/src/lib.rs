@@ -39,8 +39,22 @@
 //!
 //! - `toml` - provide serialization/deserialization in `toml` format.
 //! - `yaml` - provide serialization/deserialization in `yaml` format.
+//! - `json5` - provide serialization/deserialization in `json5` format.
+//! - `ron` - provide serialization/deserialization in `ron` format.
+//! - `cbor` - provide serialization/deserialization in `cbor` format.
+//! - `msgpack` - provide serialization/deserialization in `MessagePack` format.
+//! - `xml` - provide serialization/deserialization in `xml` format.
+//! - `urlencoded` - provide serialization/deserialization of URL query strings and `application/x-www-form-urlencoded` bodies.
+//! - `env` - provide deserialization from the environment variables.
+//! - `csv` - provide deserialization of the csv records.
+//! - `axum` - provide [axum](https://github.com/tokio-rs/axum) extractors which validate the request.
+//! - `actix` - provide [actix-web](https://actix.rs/) extractors which validate the request.
+//! - `clap` - validate the command line arguments parsed by [clap](https://github.com/clap-rs/clap), reporting the errors by the flag names.
+//! - `chrono` - support the range and time-relative validations of [chrono](https://github.com/chronotope/chrono) dates and times.
+//! - `time` - support the range and time-relative validations of [time](https://github.com/time-rs/time) dates and times.
 //! - `i128` - support `i128`/`u128` type (default).
 //! - `fluent` - provide localization using [fluent](https://projectfluent.org/).
+//! - `problem_details` - provide [RFC 9457](https://datatracker.ietf.org/doc/html/rfc9457) problem details of the errors.
 //!
 //! ## Validations
 //!
@@ -72,6 +86,67 @@
 //! | [Duration](https://doc.rust-lang.org/core/time/struct.Duration.html) | `#[validate(custom = duration_exclusive_maximum(SECOND))]` | [duration_exclusive_maximum][`crate::utils::duration_exclusive_maximum`] |
 //! | [Duration](https://doc.rust-lang.org/core/time/struct.Duration.html) | `#[validate(custom = duration_exclusive_minimum(ZERO))]`   | [duration_exclusive_minimum][`crate::utils::duration_exclusive_minimum`] |
 //!
+//! With the `chrono` and `time` features, the range validations also accept the dates and times
+//! of [RFC 3339](https://datatracker.ietf.org/doc/html/rfc3339) as str literals, which are parsed at compile time.
+//!
+//! | Literal                  | [chrono](https://github.com/chronotope/chrono) | [time](https://github.com/time-rs/time) |
+//! | :----------------------- | :--------------------------------------------- | :-------------------------------------- |
+//! | `"2020-01-01"`           | `NaiveDate`                                    | `Date`                                  |
+//! | `"2020-01-01T00:00:00"`  | `NaiveDateTime`                                | `PrimitiveDateTime`                     |
+//! | `"2020-01-01T00:00:00Z"` | `DateTime<Tz>`                                 | `OffsetDateTime`                        |
+//!
+//! ```rust
+//! # #[cfg(feature = "chrono")]
+//! # {
+//! use chrono::{DateTime, Utc};
+//! use serde_valid::Validate;
+//!
+//! #[derive(Validate)]
+//! struct Token {
+//!     #[validate(minimum = "2020-01-01T00:00:00Z")]
+//!     issued_at: DateTime<Utc>,
+//! }
+//! # }
+//! ```
+//!
+//! Time-relative validations compare the dates and times with the current time,
+//! which is read through the [`Clock`](clock::Clock) trait.
+//! Tests can replace it with a fixed instant by [`clock::with_clock`] on the current thread,
+//! or by [`clock::set_clock`] on the whole process until its guard is dropped.
+//!
+//! | Serde Valid (validate derive)     | Serde Valid (validate trait) |
+//! | :-------------------------------- | :--------------------------- |
+//! | `#[validate(past)]`               | [`ValidatePast`]             |
+//! | `#[validate(future)]`             | [`ValidateFuture`]           |
+//! | `#[validate(within = "30d")]`     | [`ValidateWithin`]           |
+//! | `#[validate(older_than = "18y")]` | [`ValidateOlderThan`]        |
+//!
+//! They support `SystemTime`, and the dates and times above with the `chrono` and `time` features,
+//! where the types without the offset are taken as UTC.
+//! The duration of `within` is checked at compile time, using the units of `w`, `d`, `h`, `m`, `s`, `ms`, `us` and `ns`.
+//! The period of `older_than`, like the age of the birthdate, also accepts the calendar units of `y` and `mo`.
+//!
+//! ```rust
+//! use std::time::{Duration, SystemTime};
+//!
+//! use serde_valid::clock::{with_clock, FixedClock};
+//! use serde_valid::Validate;
+//!
+//! #[derive(Validate)]
+//! struct Invitation {
+//!     #[validate(future)]
+//!     #[validate(within = "30d")]
+//!     expires_at: SystemTime,
+//! }
+//!
+//! let now = SystemTime::UNIX_EPOCH + Duration::from_secs(1_700_000_000);
+//! let invitation = Invitation {
+//!     expires_at: now + Duration::from_secs(7 * 24 * 3600),
+//! };
+//!
+//! with_clock(FixedClock::new(now), || assert!(invitation.validate().is_ok()));
+//! ```
+//!
 //! ## Complete Constructor (Deserialization)
 //!
 //! Serde Valid support complete constructor method using by
@@ -129,6 +204,91 @@
 //! );
 //! ```
 //!
+//! ## Format-agnostic API
+//!
+//! [`serde_valid::from_str`](format::from_str), [`serde_valid::from_reader`](format::from_reader) and
+//! [`serde_valid::to_string`](format::to_string) work with any [`DataFormat`](format::DataFormat),
+//! and [`DynFormat`](format::DynFormat) picks the format at runtime by the file extension.
+//!
+//! ```rust
+//! use serde::Deserialize;
+//! use serde_valid::Validate;
+//! use serde_valid::format::{DynFormat, Json};
+//!
+//! #[derive(Debug, Deserialize, Validate)]
+//! struct Data {
+//!     #[validate(maximum = 100)]
+//!     val: i32,
+//! }
+//!
+//! assert!(serde_valid::from_str::<Json, Data>(r#"{ "val": 123 }"#).is_err());
+//!
+//! let format = DynFormat::from_path("data.json").unwrap();
+//! assert!(format.from_str::<Data>(r#"{ "val": 12 }"#).is_ok());
+//! ```
+//!
+//! ## Source Locations
+//!
+//! `from_json_str_with_location` attaches the line and the column of the source text to the validation errors.
+//! The `toml` and `yaml` features provide `from_toml_str_with_location` and `from_yaml_str_with_location` as well.
+//!
+//! ```rust
+//! use serde::Deserialize;
+//! use serde_valid::Validate;
+//! use serde_valid::json::FromJsonStr;
+//!
+//! #[derive(Debug, Deserialize, Validate)]
+//! struct Data {
+//!     #[validate(maximum = 100)]
+//!     val: i32,
+//! }
+//!
+//! let err = Data::from_json_str_with_location(r#"{ "val": 123 }"#).unwrap_err();
+//!
+//! assert_eq!(
+//!     err.to_string(),
+//!     concat!(
+//!         "error at /val: The number must be `<= 100`.\n",
+//!         " --> 1:10\n",
+//!         "  |\n",
+//!         "1 | { \"val\": 123 }\n",
+//!         "  |          ^^^\n",
+//!     )
+//! );
+//! ```
+//!
+//! ## Layered Configuration
+//!
+//! [`ConfigLoader`](config::ConfigLoader) merges the files and the environment variables in order, and validates the result.
+//! Each validation error shows the file and the line, or the environment variable, that supplied the failing value.
+//!
+//! ```rust
+//! use serde::Deserialize;
+//! use serde_valid::Validate;
+//! use serde_valid::config::ConfigLoader;
+//! use serde_valid::format::DynFormat;
+//!
+//! #[derive(Debug, Deserialize, Validate)]
+//! struct Config {
+//!     #[validate(maximum = 100)]
+//!     pool_size: u32,
+//! }
+//!
+//! let err = ConfigLoader::new()
+//!     .add_str("defaults.json", DynFormat::Json, r#"{ "pool_size": 10 }"#)
+//!     .add_vars_prefixed([("APP_POOL_SIZE", "200")], "APP_")
+//!     .load::<Config>()
+//!     .unwrap_err();
+//!
+//! assert_eq!(
+//!     err.to_string(),
+//!     concat!(
+//!         "error at /pool_size: The number must be `<= 100`.\n",
+//!         " --> environment variable APP_POOL_SIZE\n",
+//!     )
+//! );
+//! ```
+//!
 //! ## Custom Message
 //!
 //! For user custom message, Serde Valid provides `message_fn` or `message`.
@@ -214,6 +374,38 @@
 //! # }
 //! ```
 //!
+//! ## Warnings
+//!
+//! Validations with `severity = "warning"` do not fail `validate()`.
+//! Use `validate_report()` to get both the errors and the warnings.
+//!
+//! ```rust
+//! use serde_json::json;
+//! use serde_valid::Validate;
+//!
+//! #[derive(Validate)]
+//! struct Data {
+//!     #[validate(max_length = 5, severity = "warning")]
+//!     val: String,
+//! }
+//!
+//! let s = Data { val: String::from("abcdef") };
+//!
+//! assert!(s.validate().is_ok());
+//! assert_eq!(
+//!     s.validate_report().warnings.unwrap().to_string(),
+//!     json!({
+//!         "errors": [],
+//!         "properties": {
+//!             "val": {
+//!                 "errors": ["The length of the value must be `<= 5`."]
+//!             }
+//!         }
+//!     })
+//!     .to_string()
+//! );
+//! ```
+//!
 //! ## Custom Validation
 //! ### Single Error Validation
 //! You can use your custom validation using by `#[validate(custom = ...)]`.
@@ -571,9 +763,14 @@
 //! );
 //! ```
 
+pub mod clock;
+pub mod config;
+mod env_vars;
 pub mod error;
 mod features;
+pub mod format;
 pub mod json;
+pub mod location;
 mod traits;
 pub mod utils;
 pub mod validation;
@@ -581,21 +778,23 @@ pub mod validation;
 #[allow(deprecated)]
 pub use error::EnumerateError;
 pub use error::{
-    EnumError, Error, ExclusiveMaximumError, ExclusiveMinimumError, MaxItemsError, MaxLengthError,
-    MaxPropertiesError, MaximumError, MinItemsError, MinLengthError, MinPropertiesError,
-    MinimumError, MultipleOfError, PatternError, UniqueItemsError,
+    EnumError, Error, ExclusiveMaximumError, ExclusiveMinimumError, FutureError, MaxItemsError,
+    MaxLengthError, MaxPropertiesError, MaximumError, MinItemsError, MinLengthError,
+    MinPropertiesError, MinimumError, MultipleOfError, OlderThanError, PastError, PatternError,
+    RequiredError, TypeError, UniqueItemsError, WithinError,
 };
 #[allow(unused_imports)]
 pub use features::*;
+pub use format::{from_reader, from_str, to_string};
 use indexmap::IndexMap;
 use std::{borrow::Cow, collections::HashMap};
 #[allow(deprecated)]
 pub use validation::ValidateEnumerate;
 pub use validation::{
-    ValidateEnum, ValidateExclusiveMaximum, ValidateExclusiveMinimum, ValidateMaxItems,
-    ValidateMaxLength, ValidateMaxProperties, ValidateMaximum, ValidateMinItems, ValidateMinLength,
-    ValidateMinProperties, ValidateMinimum, ValidateMultipleOf, ValidatePattern,
-    ValidateUniqueItems,
+    ValidateEnum, ValidateExclusiveMaximum, ValidateExclusiveMinimum, ValidateFuture,
+    ValidateMaxItems, ValidateMaxLength, ValidateMaxProperties, ValidateMaximum, ValidateMinItems,
+    ValidateMinLength, ValidateMinProperties, ValidateMinimum, ValidateMultipleOf,
+    ValidateOlderThan, ValidatePast, ValidatePattern, ValidateUniqueItems, ValidateWithin,
 };
 
 pub mod export {
@@ -607,6 +806,14 @@ pub mod export {
 
 pub trait Validate {
     fn validate(&self) -> std::result::Result<(), self::validation::Errors>;
+
+    /// Validate and report both errors and non-fatal warnings.
+    ///
+    /// Rules declared with `severity = "warning"` are reported in
+    /// [`Report::warnings`](validation::Report::warnings) instead of failing [`Validate::validate`].
+    fn validate_report(&self) -> self::validation::Report {
+        self.validate().into()
+    }
 }
 
 impl<T> Validate for Vec<T>
@@ -614,21 +821,11 @@ where
     T: Validate,
 {
     fn validate(&self) -> std::result::Result<(), self::validation::Errors> {
-        let mut items = IndexMap::new();
-
-        for (index, item) in self.iter().enumerate() {
-            if let Err(errors) = item.validate() {
-                items.insert(index, errors);
-            }
-        }
+        self.validate_report().into_result()
+    }
 
-        if items.is_empty() {
-            Ok(())
-        } else {
-            Err(self::validation::Errors::Array(
-                validation::error::ArrayErrors::new(vec![], items),
-            ))
-        }
+    fn validate_report(&self) -> self::validation::Report {
+        validate_items_report(self.iter())
     }
 }
 
@@ -637,21 +834,11 @@ where
     T: Validate,
 {
     fn validate(&self) -> std::result::Result<(), self::validation::Errors> {
-        let mut items = IndexMap::new();
-
-        for (index, item) in self.iter().enumerate() {
-            if let Err(errors) = item.validate() {
-                items.insert(index, errors);
-            }
-        }
+        self.validate_report().into_result()
+    }
 
-        if items.is_empty() {
-            Ok(())
-        } else {
-            Err(self::validation::Errors::Array(
-                validation::error::ArrayErrors::new(vec![], items),
-            ))
-        }
+    fn validate_report(&self) -> self::validation::Report {
+        validate_items_report(self.iter())
     }
 }
 
@@ -661,21 +848,11 @@ where
     for<'a> &'a K: Into<String>,
 {
     fn validate(&self) -> std::result::Result<(), self::validation::Errors> {
-        let mut items = IndexMap::new();
-
-        for (key, value) in self.iter() {
-            if let Err(errors) = value.validate() {
-                items.insert(Cow::from(key.into()), errors);
-            }
-        }
+        self.validate_report().into_result()
+    }
 
-        if items.is_empty() {
-            Ok(())
-        } else {
-            Err(self::validation::Errors::Object(
-                validation::error::ObjectErrors::new(vec![], items),
-            ))
-        }
+    fn validate_report(&self) -> self::validation::Report {
+        validate_properties_report(self.iter())
     }
 }
 
@@ -685,21 +862,11 @@ where
     for<'a> &'a K: Into<String>,
 {
     fn validate(&self) -> std::result::Result<(), self::validation::Errors> {
-        let mut items = IndexMap::new();
-
-        for (key, value) in self.iter() {
-            if let Err(errors) = value.validate() {
-                items.insert(Cow::from(key.into()), errors);
-            }
-        }
+        self.validate_report().into_result()
+    }
 
-        if items.is_empty() {
-            Ok(())
-        } else {
-            Err(self::validation::Errors::Object(
-                validation::ObjectErrors::new(vec![], items),
-            ))
-        }
+    fn validate_report(&self) -> self::validation::Report {
+        validate_properties_report(self.iter())
     }
 }
 
@@ -713,6 +880,113 @@ where
             None => Ok(()),
         }
     }
+
+    fn validate_report(&self) -> self::validation::Report {
+        match self {
+            Some(value) => value.validate_report(),
+            None => self::validation::Report::default(),
+        }
+    }
+}
+
+macro_rules! impl_validate_for_tuple {
+    ($($name:ident $index:tt),+) => {
+        impl<$($name),+> Validate for ($($name,)+)
+        where
+            $($name: Validate,)+
+        {
+            fn validate(&self) -> std::result::Result<(), self::validation::Errors> {
+                self.validate_report().into_result()
+            }
+
+            fn validate_report(&self) -> self::validation::Report {
+                items_report([$(self.$index.validate_report()),+].into_iter())
+            }
+        }
+    };
+}
+
+impl_validate_for_tuple!(T0 0);
+impl_validate_for_tuple!(T0 0, T1 1);
+impl_validate_for_tuple!(T0 0, T1 1, T2 2);
+impl_validate_for_tuple!(T0 0, T1 1, T2 2, T3 3);
+impl_validate_for_tuple!(T0 0, T1 1, T2 2, T3 3, T4 4);
+impl_validate_for_tuple!(T0 0, T1 1, T2 2, T3 3, T4 4, T5 5);
+impl_validate_for_tuple!(T0 0, T1 1, T2 2, T3 3, T4 4, T5 5, T6 6);
+impl_validate_for_tuple!(T0 0, T1 1, T2 2, T3 3, T4 4, T5 5, T6 6, T7 7);
+impl_validate_for_tuple!(T0 0, T1 1, T2 2, T3 3, T4 4, T5 5, T6 6, T7 7, T8 8);
+impl_validate_for_tuple!(T0 0, T1 1, T2 2, T3 3, T4 4, T5 5, T6 6, T7 7, T8 8, T9 9);
+impl_validate_for_tuple!(T0 0, T1 1, T2 2, T3 3, T4 4, T5 5, T6 6, T7 7, T8 8, T9 9, T10 10);
+impl_validate_for_tuple!(T0 0, T1 1, T2 2, T3 3, T4 4, T5 5, T6 6, T7 7, T8 8, T9 9, T10 10, T11 11);
+
+fn validate_items_report<'a, T>(items: impl Iterator<Item = &'a T>) -> self::validation::Report
+where
+    T: Validate + 'a,
+{
+    items_report(items.map(Validate::validate_report))
+}
+
+/// Collect the reports of the items into [`ArrayErrors`](validation::error::ArrayErrors) keyed by the index.
+fn items_report(
+    reports: impl Iterator<Item = self::validation::Report>,
+) -> self::validation::Report {
+    let mut errors = IndexMap::new();
+    let mut warnings = IndexMap::new();
+
+    for (index, report) in reports.enumerate() {
+        if let Some(item_errors) = report.errors {
+            errors.insert(index, item_errors);
+        }
+        if let Some(item_warnings) = report.warnings {
+            warnings.insert(index, item_warnings);
+        }
+    }
+
+    let into_errors = |items: validation::ItemErrorsMap<validation::Error>| {
+        if items.is_empty() {
+            None
+        } else {
+            Some(self::validation::Errors::Array(
+                validation::error::ArrayErrors::new(vec![], items),
+            ))
+        }
+    };
+
+    self::validation::Report::new(into_errors(errors), into_errors(warnings))
+}
+
+fn validate_properties_report<'a, K, V>(
+    properties: impl Iterator<Item = (&'a K, &'a V)>,
+) -> self::validation::Report
+where
+    K: 'a,
+    V: Validate + 'a,
+    for<'b> &'b K: Into<String>,
+{
+    let mut errors = IndexMap::new();
+    let mut warnings = IndexMap::new();
+
+    for (key, value) in properties {
+        let report = value.validate_report();
+        if let Some(value_errors) = report.errors {
+            errors.insert(Cow::from(key.into()), value_errors);
+        }
+        if let Some(value_warnings) = report.warnings {
+            warnings.insert(Cow::from(key.into()), value_warnings);
+        }
+    }
+
+    let into_errors = |properties: validation::PropertyErrorsMap<validation::Error>| {
+        if properties.is_empty() {
+            None
+        } else {
+            Some(self::validation::Errors::Object(
+                validation::ObjectErrors::new(vec![], properties),
+            ))
+        }
+    };
+
+    self::validation::Report::new(into_errors(errors), into_errors(warnings))
 }
 
 pub use serde_valid_derive::Validate;
@@ -0,0 +1,12 @@
+//! Source locations of the validation errors.
+//!
+//! Some deserializers can record where each value was in the source text.
+//! The locations are attached to the validation errors through [`SpanMap`],
+//! and [`LocatedErrors`] renders the errors with the underlined source.
+mod error;
+mod located_errors;
+mod span;
+
+pub use error::Error;
+pub use located_errors::{LocatedError, LocatedErrors};
+pub use span::{Location, Span, SpanMap};
@@ -0,0 +1,69 @@
+use super::LocatedErrors;
+
+/// Error of the deserialization with the source locations.
+#[derive(Debug, thiserror::Error)]
+pub enum Error<E>
+where
+    E: 'static + std::error::Error,
+{
+    #[error(transparent)]
+    DeserializeError(#[from] E),
+
+    #[error("{0}")]
+    ValidationError(Box<LocatedErrors>),
+}
+
+impl<E> Error<E>
+where
+    E: 'static + std::error::Error,
+{
+    pub fn is_serde_error(&self) -> bool {
+        match self {
+            Self::DeserializeError(_) => true,
+            Self::ValidationError(_) => false,
+        }
+    }
+
+    pub fn as_serde_error(&self) -> Option<&E> {
+        match self {
+            Self::DeserializeError(error) => Some(error),
+            Self::ValidationError(_) => None,
+        }
+    }
+
+    pub fn is_validation_errors(&self) -> bool {
+        match self {
+            Self::DeserializeError(_) => false,
+            Self::ValidationError(_) => true,
+        }
+    }
+
+    pub fn as_validation_errors(&self) -> Option<&LocatedErrors> {
+        match self {
+            Self::DeserializeError(_) => None,
+            Self::ValidationError(errors) => Some(errors),
+        }
+    }
+
+    /// Set the origin of the source (e.g. file path) shown in the rendered errors.
+    pub fn with_origin(self, origin: impl Into<String>) -> Self {
+        match self {
+            Self::DeserializeError(error) => Self::DeserializeError(error),
+            Self::ValidationError(errors) => {
+                Self::ValidationError(Box::new(errors.with_origin(origin)))
+            }
+        }
+    }
+}
+
+impl<E> From<Error<E>> for crate::Error<E>
+where
+    E: 'static + std::error::Error,
+{
+    fn from(error: Error<E>) -> Self {
+        match error {
+            Error::DeserializeError(error) => crate::Error::DeserializeError(error),
+            Error::ValidationError(errors) => crate::Error::ValidationError(errors.into_errors()),
+        }
+    }
+}
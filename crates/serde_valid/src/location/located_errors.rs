@@ -0,0 +1,179 @@
+use crate::flatten::{FlatError, IntoFlat};
+
+use super::{Location, Span, SpanMap};
+
+/// Validation error with the location in the source text.
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize)]
+pub struct LocatedError {
+    /// [JSON Pointer](https://datatracker.ietf.org/doc/html/rfc6901) to the invalid value.
+    pub pointer: String,
+    pub message: String,
+    pub span: Option<Span>,
+    pub location: Option<Location>,
+    /// Source line of the invalid value.
+    pub snippet: Option<String>,
+}
+
+/// Validation errors with the source text.
+///
+/// [`Display`](std::fmt::Display) renders each error with the underlined source.
+///
+/// ```text
+/// error at /val: The number must be `<= 100`.
+///  --> config.json:2:10
+///   |
+/// 2 |   "val": 123
+///   |          ^^^
+/// ```
+#[derive(Debug, Clone)]
+pub struct LocatedErrors {
+    origin: Option<String>,
+    source: String,
+    errors: crate::validation::Errors,
+    located_errors: Vec<LocatedError>,
+}
+
+impl LocatedErrors {
+    pub fn new(
+        source: impl Into<String>,
+        span_map: &SpanMap,
+        errors: crate::validation::Errors,
+    ) -> Self {
+        let source = source.into();
+        let located_errors = (&errors)
+            .into_flat()
+            .into_iter()
+            .map(|FlatError { pointer, error }| {
+                let span = span_map.get(&pointer);
+                let location = span.map(|span| Location::from_offset(&source, span.start));
+                let snippet = location.and_then(|location| {
+                    source
+                        .lines()
+                        .nth(location.line - 1)
+                        .map(|line| line.trim_end_matches('\r').to_string())
+                });
+
+                LocatedError {
+                    pointer,
+                    message: error.to_string(),
+                    span,
+                    location,
+                    snippet,
+                }
+            })
+            .collect();
+
+        Self {
+            origin: None,
+            source,
+            errors,
+            located_errors,
+        }
+    }
+
+    /// Set the origin of the source (e.g. file path) shown in the rendered errors.
+    pub fn with_origin(mut self, origin: impl Into<String>) -> Self {
+        self.origin = Some(origin.into());
+        self
+    }
+
+    pub fn origin(&self) -> Option<&str> {
+        self.origin.as_deref()
+    }
+
+    pub fn source(&self) -> &str {
+        &self.source
+    }
+
+    pub fn errors(&self) -> &crate::validation::Errors {
+        &self.errors
+    }
+
+    pub fn into_errors(self) -> crate::validation::Errors {
+        self.errors
+    }
+
+    pub fn len(&self) -> usize {
+        self.located_errors.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.located_errors.is_empty()
+    }
+
+    pub fn iter(&self) -> std::slice::Iter<'_, LocatedError> {
+        self.located_errors.iter()
+    }
+
+    fn fmt_located_error(
+        &self,
+        f: &mut std::fmt::Formatter<'_>,
+        error: &LocatedError,
+    ) -> std::fmt::Result {
+        if error.pointer.is_empty() {
+            writeln!(f, "error: {}", error.message)?;
+        } else {
+            writeln!(f, "error at {}: {}", error.pointer, error.message)?;
+        }
+
+        let (Some(span), Some(location), Some(snippet)) =
+            (error.span, error.location, error.snippet.as_ref())
+        else {
+            if let Some(origin) = &self.origin {
+                writeln!(f, " --> {origin}")?;
+            }
+            return Ok(());
+        };
+
+        let gutter = location.line.to_string();
+        let padding = " ".repeat(gutter.len());
+        match &self.origin {
+            Some(origin) => writeln!(f, "{padding}--> {origin}:{location}")?,
+            None => writeln!(f, "{padding}--> {location}")?,
+        }
+
+        let column = location.column - 1;
+        let line_rest = snippet.chars().skip(column).collect::<String>();
+        let span_text = self
+            .source
+            .get(span.start..span.end.min(self.source.len()))
+            .unwrap_or_default();
+        let underline_length = span_text
+            .chars()
+            .count()
+            .min(line_rest.chars().count())
+            .max(1);
+
+        writeln!(f, "{padding} |")?;
+        writeln!(f, "{gutter} | {snippet}")?;
+        writeln!(
+            f,
+            "{padding} | {}{}",
+            " ".repeat(column),
+            "^".repeat(underline_length)
+        )
+    }
+}
+
+impl<'a> IntoIterator for &'a LocatedErrors {
+    type Item = &'a LocatedError;
+    type IntoIter = std::slice::Iter<'a, LocatedError>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.located_errors.iter()
+    }
+}
+
+impl std::fmt::Display for LocatedErrors {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for (index, error) in self.located_errors.iter().enumerate() {
+            if index != 0 {
+                writeln!(f)?;
+            }
+            self.fmt_located_error(f, error)?;
+        }
+        Ok(())
+    }
+}
+
+impl std::error::Error for LocatedErrors {}
@@ -0,0 +1,124 @@
+use indexmap::IndexMap;
+
+/// Byte range of the value in the source text.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, serde::Serialize, serde::Deserialize)]
+pub struct Span {
+    pub start: usize,
+    pub end: usize,
+}
+
+impl Span {
+    pub fn new(start: usize, end: usize) -> Self {
+        Self { start, end }
+    }
+}
+
+/// 1-based line and column in the source text.
+///
+/// Column counts characters, not bytes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, serde::Serialize, serde::Deserialize)]
+pub struct Location {
+    pub line: usize,
+    pub column: usize,
+}
+
+impl Location {
+    pub fn new(line: usize, column: usize) -> Self {
+        Self { line, column }
+    }
+
+    /// Convert the byte offset into the location.
+    pub fn from_offset(source: &str, offset: usize) -> Self {
+        let offset = floor_char_boundary(source, offset);
+        let line_start = source[..offset].rfind('\n').map_or(0, |index| index + 1);
+
+        Self {
+            line: source[..line_start].matches('\n').count() + 1,
+            column: source[line_start..offset].chars().count() + 1,
+        }
+    }
+}
+
+impl std::fmt::Display for Location {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}:{}", self.line, self.column)
+    }
+}
+
+/// Spans of the values keyed by [JSON Pointer](https://datatracker.ietf.org/doc/html/rfc6901).
+#[derive(Debug, Clone, Default)]
+pub struct SpanMap(IndexMap<String, Span>);
+
+impl SpanMap {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn insert(&mut self, pointer: impl Into<String>, span: Span) {
+        self.0.insert(pointer.into(), span);
+    }
+
+    /// Get the span of the pointer.
+    ///
+    /// If the pointer is not recorded (e.g. missing field), the span of the nearest parent is returned.
+    pub fn get(&self, pointer: &str) -> Option<Span> {
+        let mut pointer = pointer;
+        loop {
+            if let Some(span) = self.0.get(pointer) {
+                return Some(*span);
+            }
+            match pointer.rfind('/') {
+                Some(index) => pointer = &pointer[..index],
+                None => return None,
+            }
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    pub fn iter(&self) -> indexmap::map::Iter<'_, String, Span> {
+        self.0.iter()
+    }
+}
+
+fn floor_char_boundary(source: &str, offset: usize) -> usize {
+    let mut offset = offset.min(source.len());
+    while !source.is_char_boundary(offset) {
+        offset -= 1;
+    }
+    offset
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_location_from_offset() {
+        let source = "{\n  \"a\": 1,\n  \"あ\": 2\n}";
+
+        assert_eq!(Location::from_offset(source, 0), Location::new(1, 1));
+        assert_eq!(Location::from_offset(source, 9), Location::new(2, 8));
+        assert_eq!(
+            Location::from_offset(source, source.find('2').unwrap()),
+            Location::new(3, 8)
+        );
+    }
+
+    #[test]
+    fn test_span_map_get_parent() {
+        let mut span_map = SpanMap::new();
+        span_map.insert("", Span::new(0, 10));
+        span_map.insert("/a", Span::new(2, 4));
+
+        assert_eq!(span_map.get("/a"), Some(Span::new(2, 4)));
+        assert_eq!(span_map.get("/a/0"), Some(Span::new(2, 4)));
+        assert_eq!(span_map.get("/b"), Some(Span::new(0, 10)));
+    }
+}
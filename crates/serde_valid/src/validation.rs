@@ -1,10 +1,13 @@
 mod array;
 mod composited;
 pub mod custom;
+mod date_time;
 pub mod error;
 mod generic;
 mod numeric;
 mod object;
+mod relative_time;
+mod report;
 mod string;
 
 use crate::{
@@ -15,8 +18,8 @@ pub use composited::Composited;
 
 pub use array::{ValidateMaxItems, ValidateMinItems, ValidateUniqueItems};
 pub use error::{
-    ArrayErrors, Error, Errors, IntoError, ItemErrorsMap, ItemVecErrorsMap, ObjectErrors,
-    PropertyErrorsMap, PropertyVecErrorsMap, VecErrors,
+    ArrayErrors, Error, Errors, ErrorsBuilder, IntoError, ItemErrorsMap, ItemVecErrorsMap,
+    ObjectErrors, PropertyErrorsMap, PropertyVecErrorsMap, VecErrors,
 };
 pub use generic::ValidateEnum;
 #[allow(deprecated)]
@@ -27,7 +30,14 @@ pub use numeric::{
     ValidateMultipleOf,
 };
 pub use object::{ValidateMaxProperties, ValidateMinProperties};
-pub use serde_valid_literal::{Literal, Number, Pattern};
+pub use relative_time::{
+    ValidateCompositedFuture, ValidateCompositedOlderThan, ValidateCompositedPast,
+    ValidateCompositedWithin, ValidateFuture, ValidateOlderThan, ValidatePast, ValidateWithin,
+};
+pub use report::Report;
+pub use serde_valid_literal::{
+    Date, DateTime, Duration, Literal, NaiveDateTime, Number, Pattern, Period, RangeLimit,
+};
 pub use string::{ValidateMaxLength, ValidateMinLength, ValidatePattern};
 
 macro_rules! impl_composited_validation_1args {
@@ -1,9 +1,10 @@
 use crate::validation::error::IntoError;
 
 use crate::error::{
-    EnumError, ExclusiveMaximumError, ExclusiveMinimumError, MaxItemsError, MaxLengthError,
-    MaxPropertiesError, MaximumError, MinItemsError, MinLengthError, MinPropertiesError,
-    MinimumError, MultipleOfError, PatternError, UniqueItemsError,
+    EnumError, ExclusiveMaximumError, ExclusiveMinimumError, FutureError, MaxItemsError,
+    MaxLengthError, MaxPropertiesError, MaximumError, MinItemsError, MinLengthError,
+    MinPropertiesError, MinimumError, MultipleOfError, OlderThanError, PastError, PatternError,
+    UniqueItemsError, WithinError,
 };
 use indexmap::IndexMap;
 
@@ -71,6 +72,12 @@ impl_into_error!(ExclusiveMaximum => ExclusiveMaximumError);
 impl_into_error!(ExclusiveMinimum => ExclusiveMinimumError);
 impl_into_error!(MultipleOf => MultipleOfError);
 
+// Time
+impl_into_error!(Past => PastError);
+impl_into_error!(Future => FutureError);
+impl_into_error!(Within => WithinError);
+impl_into_error!(OlderThan => OlderThanError);
+
 // String
 impl_into_error!(MaxLength => MaxLengthError);
 impl_into_error!(MinLength => MinLengthError);
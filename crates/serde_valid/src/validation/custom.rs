@@ -30,6 +30,20 @@ impl IntoVecErrors for crate::validation::Error {
     }
 }
 
+impl IntoVecErrors for crate::validation::Errors {
+    fn into_vec_errors(self) -> Vec<crate::validation::Error> {
+        match self {
+            crate::validation::Errors::Array(array) => {
+                vec![crate::validation::Error::Items(array)]
+            }
+            crate::validation::Errors::Object(object) => {
+                vec![crate::validation::Error::Properties(object)]
+            }
+            crate::validation::Errors::NewType(errors) => errors,
+        }
+    }
+}
+
 #[cfg(test)]
 mod test {
     use super::*;
@@ -0,0 +1,113 @@
+//! Range validations of the dates and times of the `chrono` and `time` features.
+//!
+//! The limits are the [`Date`], [`NaiveDateTime`] and [`DateTime`] literals,
+//! which the derive macro parses from the RFC 3339 str literals at compile time.
+//! The limit out of the range of the type, like the one made by `new_unchecked`,
+//! fails the validation.
+
+use crate::validation::{
+    impl_generic_composited_validation_1args, ValidateCompositedExclusiveMaximum,
+    ValidateCompositedExclusiveMinimum, ValidateCompositedMaximum, ValidateCompositedMinimum,
+    ValidateExclusiveMaximum, ValidateExclusiveMinimum, ValidateMaximum, ValidateMinimum,
+};
+use crate::validation::{Date, DateTime, NaiveDateTime};
+use crate::{ExclusiveMaximumError, ExclusiveMinimumError, MaximumError, MinimumError};
+
+/// Implement the range validations, comparing the value with the limit converted by `$into`,
+/// which returns `None` for the invalid limit.
+#[allow(unused_macros)]
+macro_rules! impl_validate_date_time_range {
+    ([$($generics:tt)*] $type:ty, $limit:ty, $into:path) => {
+        impl<$($generics)*> crate::ValidateMinimum<$limit> for $type {
+            fn validate_minimum(&self, minimum: $limit) -> Result<(), crate::MinimumError> {
+                if matches!($into(minimum), Some(limit) if *self >= limit) {
+                    Ok(())
+                } else {
+                    Err(crate::MinimumError::new(minimum))
+                }
+            }
+        }
+
+        impl<$($generics)*> crate::ValidateMaximum<$limit> for $type {
+            fn validate_maximum(&self, maximum: $limit) -> Result<(), crate::MaximumError> {
+                if matches!($into(maximum), Some(limit) if *self <= limit) {
+                    Ok(())
+                } else {
+                    Err(crate::MaximumError::new(maximum))
+                }
+            }
+        }
+
+        impl<$($generics)*> crate::ValidateExclusiveMinimum<$limit> for $type {
+            fn validate_exclusive_minimum(
+                &self,
+                exclusive_minimum: $limit,
+            ) -> Result<(), crate::ExclusiveMinimumError> {
+                if matches!($into(exclusive_minimum), Some(limit) if *self > limit) {
+                    Ok(())
+                } else {
+                    Err(crate::ExclusiveMinimumError::new(exclusive_minimum))
+                }
+            }
+        }
+
+        impl<$($generics)*> crate::ValidateExclusiveMaximum<$limit> for $type {
+            fn validate_exclusive_maximum(
+                &self,
+                exclusive_maximum: $limit,
+            ) -> Result<(), crate::ExclusiveMaximumError> {
+                if matches!($into(exclusive_maximum), Some(limit) if *self < limit) {
+                    Ok(())
+                } else {
+                    Err(crate::ExclusiveMaximumError::new(exclusive_maximum))
+                }
+            }
+        }
+    };
+}
+
+#[cfg(feature = "chrono")]
+mod chrono;
+#[cfg(feature = "time")]
+mod time;
+
+macro_rules! impl_composited_date_time_range {
+    ($type:ty) => {
+        impl_generic_composited_validation_1args!(
+            ValidateCompositedMinimum,
+            validate_composited_minimum,
+            ValidateMinimum,
+            validate_minimum,
+            MinimumError,
+            $type
+        );
+        impl_generic_composited_validation_1args!(
+            ValidateCompositedMaximum,
+            validate_composited_maximum,
+            ValidateMaximum,
+            validate_maximum,
+            MaximumError,
+            $type
+        );
+        impl_generic_composited_validation_1args!(
+            ValidateCompositedExclusiveMinimum,
+            validate_composited_exclusive_minimum,
+            ValidateExclusiveMinimum,
+            validate_exclusive_minimum,
+            ExclusiveMinimumError,
+            $type
+        );
+        impl_generic_composited_validation_1args!(
+            ValidateCompositedExclusiveMaximum,
+            validate_composited_exclusive_maximum,
+            ValidateExclusiveMaximum,
+            validate_exclusive_maximum,
+            ExclusiveMaximumError,
+            $type
+        );
+    };
+}
+
+impl_composited_date_time_range!(Date);
+impl_composited_date_time_range!(NaiveDateTime);
+impl_composited_date_time_range!(DateTime);
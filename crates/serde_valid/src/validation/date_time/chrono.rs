@@ -0,0 +1,64 @@
+use ::chrono::TimeZone;
+
+use crate::validation::{Date, DateTime, NaiveDateTime};
+
+fn into_naive_date(date: Date) -> Option<::chrono::NaiveDate> {
+    ::chrono::NaiveDate::from_ymd_opt(date.year(), date.month().into(), date.day().into())
+}
+
+fn into_naive_date_time(date_time: NaiveDateTime) -> Option<::chrono::NaiveDateTime> {
+    into_naive_date(date_time.date())?.and_hms_nano_opt(
+        date_time.hour().into(),
+        date_time.minute().into(),
+        date_time.second().into(),
+        date_time.nanosecond(),
+    )
+}
+
+fn into_date_time(date_time: DateTime) -> Option<::chrono::DateTime<::chrono::FixedOffset>> {
+    ::chrono::FixedOffset::east_opt(date_time.offset_seconds())?
+        .from_local_datetime(&into_naive_date_time(date_time.naive())?)
+        .single()
+}
+
+impl_validate_date_time_range!([] ::chrono::NaiveDate, Date, into_naive_date);
+impl_validate_date_time_range!([] ::chrono::NaiveDateTime, NaiveDateTime, into_naive_date_time);
+impl_validate_date_time_range!(
+    [Tz: ::chrono::TimeZone] ::chrono::DateTime<Tz>,
+    DateTime,
+    into_date_time
+);
+
+#[cfg(test)]
+mod tests {
+    use ::chrono::{NaiveDate, TimeZone, Utc};
+
+    use crate::{ValidateExclusiveMaximum, ValidateMaximum, ValidateMinimum};
+
+    #[test]
+    fn test_validate_chrono_date_time_range() {
+        let minimum = "2020-01-01T09:00:00+09:00".parse().unwrap();
+        let value = Utc.with_ymd_and_hms(2020, 1, 1, 0, 0, 0).unwrap();
+
+        assert!(value.validate_minimum(minimum).is_ok());
+        assert!(value.validate_exclusive_maximum(minimum).is_err());
+    }
+
+    #[test]
+    fn test_validate_chrono_naive_date_range() {
+        let value = NaiveDate::from_ymd_opt(2020, 1, 2).unwrap();
+
+        assert!(value
+            .validate_maximum("2020-01-01".parse().unwrap())
+            .is_err());
+    }
+
+    #[test]
+    fn test_validate_chrono_invalid_limit() {
+        let value = NaiveDate::from_ymd_opt(2020, 1, 2).unwrap();
+        let limit = crate::validation::Date::new_unchecked(2020, 13, 40);
+
+        assert!(value.validate_minimum(limit).is_err());
+        assert!(value.validate_maximum(limit).is_err());
+    }
+}
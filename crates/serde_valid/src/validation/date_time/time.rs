@@ -0,0 +1,66 @@
+use crate::validation::{Date, DateTime, NaiveDateTime};
+
+fn into_date(date: Date) -> Option<::time::Date> {
+    ::time::Date::from_calendar_date(
+        date.year(),
+        ::time::Month::try_from(date.month()).ok()?,
+        date.day(),
+    )
+    .ok()
+}
+
+fn into_primitive_date_time(date_time: NaiveDateTime) -> Option<::time::PrimitiveDateTime> {
+    Some(::time::PrimitiveDateTime::new(
+        into_date(date_time.date())?,
+        ::time::Time::from_hms_nano(
+            date_time.hour(),
+            date_time.minute(),
+            date_time.second(),
+            date_time.nanosecond(),
+        )
+        .ok()?,
+    ))
+}
+
+fn into_offset_date_time(date_time: DateTime) -> Option<::time::OffsetDateTime> {
+    Some(
+        into_primitive_date_time(date_time.naive())?
+            .assume_offset(::time::UtcOffset::from_whole_seconds(date_time.offset_seconds()).ok()?),
+    )
+}
+
+impl_validate_date_time_range!([] ::time::Date, Date, into_date);
+impl_validate_date_time_range!([] ::time::PrimitiveDateTime, NaiveDateTime, into_primitive_date_time);
+impl_validate_date_time_range!([] ::time::OffsetDateTime, DateTime, into_offset_date_time);
+
+#[cfg(test)]
+mod tests {
+    use crate::{ValidateExclusiveMinimum, ValidateMaximum, ValidateMinimum};
+
+    #[test]
+    fn test_validate_time_offset_date_time_range() {
+        let minimum = "2020-01-01T09:00:00+09:00".parse().unwrap();
+        let value = ::time::OffsetDateTime::UNIX_EPOCH + ::time::Duration::days(18262);
+
+        assert!(value.validate_minimum(minimum).is_ok());
+        assert!(value.validate_exclusive_minimum(minimum).is_err());
+    }
+
+    #[test]
+    fn test_validate_time_invalid_limit() {
+        let value = ::time::OffsetDateTime::UNIX_EPOCH;
+        let limit = crate::validation::DateTime::new_unchecked(
+            crate::validation::NaiveDateTime::new_unchecked(
+                crate::validation::Date::new_unchecked(1970, 1, 1),
+                25,
+                0,
+                0,
+                0,
+            ),
+            0,
+        );
+
+        assert!(value.validate_minimum(limit).is_err());
+        assert!(value.validate_maximum(limit).is_err());
+    }
+}
@@ -1,4 +1,5 @@
 mod array_erros;
+mod builder;
 mod errors;
 mod format;
 mod into_error;
@@ -10,11 +11,13 @@ use std::borrow::Cow;
 #[allow(deprecated)]
 pub use crate::error::EnumerateError;
 pub use crate::error::{
-    EnumError, ExclusiveMaximumError, ExclusiveMinimumError, MaxItemsError, MaxLengthError,
-    MaxPropertiesError, MaximumError, MinItemsError, MinLengthError, MinPropertiesError,
-    MinimumError, MultipleOfError, PatternError, UniqueItemsError,
+    EnumError, ExclusiveMaximumError, ExclusiveMinimumError, FutureError, MaxItemsError,
+    MaxLengthError, MaxPropertiesError, MaximumError, MinItemsError, MinLengthError,
+    MinPropertiesError, MinimumError, MultipleOfError, OlderThanError, PastError, PatternError,
+    RequiredError, TypeError, UniqueItemsError, WithinError,
 };
 pub use array_erros::ArrayErrors;
+pub use builder::{ErrorsBuilder, ErrorsBuilderPath};
 pub use errors::Errors;
 pub use format::{Format, FormatDefault};
 use indexmap::IndexMap;
@@ -22,8 +25,13 @@ pub use into_error::IntoError;
 pub use message::Message;
 pub use object_errors::ObjectErrors;
 
+/// Validation error.
+///
+/// New variants may be added as new validations are supported,
+/// so a `match` on it needs a wildcard arm.
 #[derive(Debug, Clone, serde::Serialize, thiserror::Error)]
 #[serde(untagged)]
+#[non_exhaustive]
 pub enum Error {
     #[error("{0}")]
     #[serde(serialize_with = "serialize_error_message")]
@@ -45,6 +53,22 @@ pub enum Error {
     #[serde(serialize_with = "serialize_error_message")]
     MultipleOf(Message<MultipleOfError>),
 
+    #[error("{0}")]
+    #[serde(serialize_with = "serialize_error_message")]
+    Past(Message<PastError>),
+
+    #[error("{0}")]
+    #[serde(serialize_with = "serialize_error_message")]
+    Future(Message<FutureError>),
+
+    #[error("{0}")]
+    #[serde(serialize_with = "serialize_error_message")]
+    Within(Message<WithinError>),
+
+    #[error("{0}")]
+    #[serde(serialize_with = "serialize_error_message")]
+    OlderThan(Message<OlderThanError>),
+
     #[error("{0}")]
     #[serde(serialize_with = "serialize_error_message")]
     MinLength(Message<MinLengthError>),
@@ -85,6 +109,14 @@ pub enum Error {
     #[serde(serialize_with = "serialize_error_message")]
     Custom(String),
 
+    #[error("{0}")]
+    #[serde(serialize_with = "serialize_error_message")]
+    Type(Message<TypeError>),
+
+    #[error("{0}")]
+    #[serde(serialize_with = "serialize_error_message")]
+    Required(Message<RequiredError>),
+
     #[error(transparent)]
     Items(ArrayErrors),
 
@@ -97,6 +129,39 @@ pub enum Error {
     Fluent(crate::fluent::Message),
 }
 
+impl Error {
+    /// Machine-readable code of the validation.
+    pub fn code(&self) -> &'static str {
+        match self {
+            Self::Minimum(_) => "minimum",
+            Self::Maximum(_) => "maximum",
+            Self::ExclusiveMinimum(_) => "exclusive_minimum",
+            Self::ExclusiveMaximum(_) => "exclusive_maximum",
+            Self::MultipleOf(_) => "multiple_of",
+            Self::Past(_) => "past",
+            Self::Future(_) => "future",
+            Self::Within(_) => "within",
+            Self::OlderThan(_) => "older_than",
+            Self::MinLength(_) => "min_length",
+            Self::MaxLength(_) => "max_length",
+            Self::Pattern(_) => "pattern",
+            Self::MinItems(_) => "min_items",
+            Self::MaxItems(_) => "max_items",
+            Self::UniqueItems(_) => "unique_items",
+            Self::MinProperties(_) => "min_properties",
+            Self::MaxProperties(_) => "max_properties",
+            Self::Enum(_) => "enum",
+            Self::Custom(_) => "custom",
+            Self::Type(_) => "type",
+            Self::Required(_) => "required",
+            Self::Items(_) => "items",
+            Self::Properties(_) => "properties",
+            #[cfg(feature = "fluent")]
+            Self::Fluent(_) => "fluent",
+        }
+    }
+}
+
 fn serialize_error_message<T, S>(message: &T, serializer: S) -> Result<S::Ok, S::Error>
 where
     T: std::fmt::Display,
@@ -0,0 +1,154 @@
+use std::borrow::Cow;
+
+use indexmap::indexmap;
+
+use super::{ArrayErrors, Errors, ObjectErrors};
+
+/// Builder of [`Errors`] for hand-written validations.
+///
+/// # Examples
+/// ```rust
+/// use serde_json::json;
+/// use serde_valid::validation::{Error, ErrorsBuilder};
+///
+/// let mut builder = ErrorsBuilder::new();
+/// builder.push_root(Error::Custom("root error.".to_string()));
+/// builder
+///     .at("items")
+///     .index(3)
+///     .at("name")
+///     .push(Error::Custom("name error.".to_string()));
+///
+/// assert_eq!(
+///     builder.build().unwrap_err().to_string(),
+///     json!({
+///         "errors": ["root error."],
+///         "properties": {
+///             "items": {
+///                 "errors": [],
+///                 "items": {
+///                     "3": {
+///                         "errors": [],
+///                         "properties": {
+///                             "name": {
+///                                 "errors": ["name error."]
+///                             }
+///                         }
+///                     }
+///                 }
+///             }
+///         }
+///     })
+///     .to_string()
+/// );
+/// ```
+#[derive(Debug, Clone)]
+pub struct ErrorsBuilder<E = crate::validation::Error> {
+    errors: Option<Errors<E>>,
+}
+
+impl<E> ErrorsBuilder<E> {
+    pub fn new() -> Self {
+        Self { errors: None }
+    }
+
+    /// Push the error to the root of the tree.
+    pub fn push_root(&mut self, error: E) -> &mut Self {
+        self.merge(Errors::NewType(vec![error]));
+        self
+    }
+
+    /// Point to the property of the object.
+    pub fn at(&mut self, property: impl Into<Cow<'static, str>>) -> ErrorsBuilderPath<'_, E> {
+        ErrorsBuilderPath {
+            builder: self,
+            path: vec![PathSegment::Property(property.into())],
+        }
+    }
+
+    /// Point to the item of the array.
+    pub fn index(&mut self, index: usize) -> ErrorsBuilderPath<'_, E> {
+        ErrorsBuilderPath {
+            builder: self,
+            path: vec![PathSegment::Item(index)],
+        }
+    }
+
+    /// Merge the errors tree into the root of the tree.
+    pub fn merge(&mut self, errors: Errors<E>) -> &mut Self {
+        match self.errors.as_mut() {
+            Some(self_errors) => self_errors.merge(errors),
+            None => self.errors = Some(errors),
+        }
+        self
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.errors.is_none()
+    }
+
+    pub fn build(self) -> Result<(), Errors<E>> {
+        match self.errors {
+            Some(errors) => Err(errors),
+            None => Ok(()),
+        }
+    }
+}
+
+impl<E> Default for ErrorsBuilder<E> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[derive(Debug, Clone)]
+enum PathSegment {
+    Property(Cow<'static, str>),
+    Item(usize),
+}
+
+/// Path in the [`ErrorsBuilder`] tree.
+///
+/// Created by [`ErrorsBuilder::at`] or [`ErrorsBuilder::index`].
+#[derive(Debug)]
+pub struct ErrorsBuilderPath<'a, E = crate::validation::Error> {
+    builder: &'a mut ErrorsBuilder<E>,
+    path: Vec<PathSegment>,
+}
+
+impl<E> ErrorsBuilderPath<'_, E> {
+    /// Point to the property of the object.
+    pub fn at(mut self, property: impl Into<Cow<'static, str>>) -> Self {
+        self.path.push(PathSegment::Property(property.into()));
+        self
+    }
+
+    /// Point to the item of the array.
+    pub fn index(mut self, index: usize) -> Self {
+        self.path.push(PathSegment::Item(index));
+        self
+    }
+
+    /// Push the error to the pointed path.
+    pub fn push(self, error: E) {
+        self.merge(Errors::NewType(vec![error]));
+    }
+
+    /// Merge the errors tree into the pointed path.
+    pub fn merge(self, errors: Errors<E>) {
+        let errors = self
+            .path
+            .into_iter()
+            .rev()
+            .fold(errors, |errors, segment| match segment {
+                PathSegment::Property(property) => {
+                    Errors::Object(ObjectErrors::new(vec![], indexmap! { property => errors }))
+                }
+                PathSegment::Item(index) => {
+                    Errors::Array(ArrayErrors::new(vec![], indexmap! { index => errors }))
+                }
+            });
+
+        self.builder.merge(errors);
+    }
+}
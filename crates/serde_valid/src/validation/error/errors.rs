@@ -30,51 +30,59 @@ where
     }
 }
 
-impl<E> Errors<E>
-where
-    E: Clone,
-{
+impl<E> Errors<E> {
+    /// Deep merge other errors into self.
+    ///
+    /// Root errors are concatenated, and nested errors of the same item or property are merged recursively.
+    ///
+    /// When the shapes conflict (`Array` and `Object`), the result becomes `Object`,
+    /// and the array items are stored as properties keyed by their index.
     pub fn merge(&mut self, other: Errors<E>) {
-        match self {
-            Errors::Array(a) => match other {
-                Errors::Array(b) => {
-                    a.errors.extend(b.errors);
-
-                    for (index, item) in b.items {
-                        match a.items.get_mut(&index) {
-                            Some(errors) => errors.merge(item),
-                            None => {
-                                a.items.insert(index, item);
-                            }
-                        };
-                    }
-                }
-                Errors::Object(_) => {
-                    unreachable!("conflict Array and Object in serde_valid::validation::Errors")
-                }
-                Errors::NewType(errors) => {
-                    a.errors.extend(errors);
-                }
-            },
-            Errors::NewType(a) => match other {
-                Errors::Array(b) => {
-                    a.extend(b.errors);
-                    *self = Errors::Array(ArrayErrors::new(a.to_vec(), b.items));
-                }
-                Errors::Object(_) => {
-                    unreachable!("conflict Array and Object in serde_valid::validation::Errors")
-                }
-                Errors::NewType(b) => {
-                    a.extend(b);
-                }
-            },
-            Errors::Object(_) => {
-                unimplemented!("Object does not support yet.")
+        let errors = std::mem::replace(self, Errors::NewType(vec![]));
+
+        *self = match (errors, other) {
+            (Errors::Array(a), Errors::Array(b)) => Errors::Array(a.merge(b)),
+            (Errors::Object(a), Errors::Object(b)) => Errors::Object(a.merge(b)),
+            (Errors::NewType(mut a), Errors::NewType(b)) => {
+                a.extend(b);
+                Errors::NewType(a)
             }
+            (Errors::Array(mut a), Errors::NewType(b)) => {
+                a.errors.extend(b);
+                Errors::Array(a)
+            }
+            (Errors::NewType(a), Errors::Array(b)) => {
+                Errors::Array(ArrayErrors::new(a, Default::default()).merge(b))
+            }
+            (Errors::Object(mut a), Errors::NewType(b)) => {
+                a.errors.extend(b);
+                Errors::Object(a)
+            }
+            (Errors::NewType(a), Errors::Object(b)) => {
+                Errors::Object(ObjectErrors::new(a, Default::default()).merge(b))
+            }
+            (Errors::Array(a), Errors::Object(b)) => Errors::Object(ObjectErrors::from(a).merge(b)),
+            (Errors::Object(a), Errors::Array(b)) => Errors::Object(a.merge(b.into())),
+        }
+    }
+}
+
+impl<E> Extend<Errors<E>> for Errors<E> {
+    fn extend<T: IntoIterator<Item = Errors<E>>>(&mut self, iter: T) {
+        for errors in iter {
+            self.merge(errors);
         }
     }
 }
 
+impl<E> FromIterator<Errors<E>> for Errors<E> {
+    fn from_iter<T: IntoIterator<Item = Errors<E>>>(iter: T) -> Self {
+        let mut errors = Errors::NewType(vec![]);
+        errors.extend(iter);
+        errors
+    }
+}
+
 impl<E> std::fmt::Display for Errors<E>
 where
     E: serde::Serialize + std::fmt::Display,
@@ -94,3 +102,118 @@ where
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::validation::Error;
+    use indexmap::indexmap;
+    use serde_json::json;
+
+    fn custom(message: &str) -> Error {
+        Error::Custom(message.to_string())
+    }
+
+    #[test]
+    fn test_merge_object_errors() {
+        let mut errors = Errors::Object(ObjectErrors::new(
+            vec![custom("a")],
+            indexmap! {
+                "val".into() => Errors::NewType(vec![custom("b")]),
+            },
+        ));
+        errors.merge(Errors::Object(ObjectErrors::new(
+            vec![custom("c")],
+            indexmap! {
+                "val".into() => Errors::NewType(vec![custom("d")]),
+                "other".into() => Errors::NewType(vec![custom("e")]),
+            },
+        )));
+
+        assert_eq!(
+            serde_json::to_value(&errors).unwrap(),
+            json!({
+                "errors": ["a", "c"],
+                "properties": {
+                    "val": { "errors": ["b", "d"] },
+                    "other": { "errors": ["e"] }
+                }
+            })
+        );
+    }
+
+    #[test]
+    fn test_merge_new_type_into_object_errors() {
+        let mut errors = Errors::NewType(vec![custom("a")]);
+        errors.merge(Errors::Object(ObjectErrors::new(
+            vec![custom("b")],
+            indexmap! {
+                "val".into() => Errors::NewType(vec![custom("c")]),
+            },
+        )));
+
+        assert_eq!(
+            serde_json::to_value(&errors).unwrap(),
+            json!({
+                "errors": ["a", "b"],
+                "properties": {
+                    "val": { "errors": ["c"] }
+                }
+            })
+        );
+    }
+
+    #[test]
+    fn test_merge_conflict_array_and_object_errors() {
+        let mut errors = Errors::Array(ArrayErrors::new(
+            vec![custom("a")],
+            indexmap! {
+                0 => Errors::NewType(vec![custom("b")]),
+            },
+        ));
+        errors.merge(Errors::Object(ObjectErrors::new(
+            vec![],
+            indexmap! {
+                "0".into() => Errors::NewType(vec![custom("c")]),
+                "val".into() => Errors::NewType(vec![custom("d")]),
+            },
+        )));
+
+        assert_eq!(
+            serde_json::to_value(&errors).unwrap(),
+            json!({
+                "errors": ["a"],
+                "properties": {
+                    "0": { "errors": ["b", "c"] },
+                    "val": { "errors": ["d"] }
+                }
+            })
+        );
+    }
+
+    #[test]
+    fn test_collect_errors() {
+        let errors = vec![
+            Errors::NewType(vec![custom("a")]),
+            Errors::Array(ArrayErrors::new(
+                vec![],
+                indexmap! {
+                    1 => Errors::NewType(vec![custom("b")]),
+                },
+            )),
+            Errors::NewType(vec![custom("c")]),
+        ]
+        .into_iter()
+        .collect::<Errors>();
+
+        assert_eq!(
+            serde_json::to_value(&errors).unwrap(),
+            json!({
+                "errors": ["a", "c"],
+                "items": {
+                    "1": { "errors": ["b"] }
+                }
+            })
+        );
+    }
+}
@@ -1,6 +1,8 @@
 use serde::ser::SerializeStruct;
 
-use super::{PropertyErrorsMap, VecErrors};
+use std::borrow::Cow;
+
+use super::{ArrayErrors, PropertyErrorsMap, VecErrors};
 
 #[derive(Debug, Clone, thiserror::Error)]
 pub struct ObjectErrors<E = crate::validation::Error> {
@@ -27,6 +29,33 @@ impl<E> ObjectErrors<E> {
     pub fn new(errors: VecErrors<E>, properties: PropertyErrorsMap<E>) -> Self {
         Self { errors, properties }
     }
+
+    pub fn merge(mut self, other: ObjectErrors<E>) -> Self {
+        self.errors.extend(other.errors);
+
+        for (property, errors) in other.properties {
+            match self.properties.get_mut(&property) {
+                Some(self_errors) => self_errors.merge(errors),
+                None => {
+                    self.properties.insert(property, errors);
+                }
+            };
+        }
+        self
+    }
+}
+
+impl<E> From<ArrayErrors<E>> for ObjectErrors<E> {
+    fn from(array_errors: ArrayErrors<E>) -> Self {
+        Self::new(
+            array_errors.errors,
+            array_errors
+                .items
+                .into_iter()
+                .map(|(index, errors)| (Cow::Owned(index.to_string()), errors))
+                .collect(),
+        )
+    }
 }
 
 impl<E> std::fmt::Display for ObjectErrors<E>
@@ -0,0 +1,301 @@
+//! Validations of the dates and times relative to the current time of the [`Clock`](crate::clock::Clock).
+//!
+//! The derive macro reads the current time by [`crate::clock::now`] once for each field,
+//! and the chrono and time types without the offset are taken as UTC.
+
+use std::time::{Duration, SystemTime};
+
+use indexmap::IndexMap;
+
+use crate::validation::{Composited, Period};
+use crate::{FutureError, OlderThanError, PastError, WithinError};
+
+mod calendar;
+
+/// Past validation of the date and time.
+///
+/// ```rust
+/// use std::time::{Duration, SystemTime};
+///
+/// use serde_valid::ValidatePast;
+///
+/// let now = SystemTime::now();
+///
+/// assert!((now - Duration::from_secs(1)).validate_past(now).is_ok());
+/// assert!(now.validate_past(now).is_err());
+/// ```
+pub trait ValidatePast {
+    fn validate_past(&self, now: SystemTime) -> Result<(), PastError>;
+}
+
+/// Future validation of the date and time.
+///
+/// ```rust
+/// use std::time::{Duration, SystemTime};
+///
+/// use serde_valid::ValidateFuture;
+///
+/// let now = SystemTime::now();
+///
+/// assert!((now + Duration::from_secs(1)).validate_future(now).is_ok());
+/// assert!(now.validate_future(now).is_err());
+/// ```
+pub trait ValidateFuture {
+    fn validate_future(&self, now: SystemTime) -> Result<(), FutureError>;
+}
+
+/// Validation of the date and time being within the duration before or after the current time.
+///
+/// ```rust
+/// use std::time::{Duration, SystemTime};
+///
+/// use serde_valid::ValidateWithin;
+///
+/// let now = SystemTime::now();
+/// let within = Duration::from_secs(60);
+///
+/// assert!((now - within).validate_within(now, within).is_ok());
+/// assert!((now + within * 2).validate_within(now, within).is_err());
+/// ```
+pub trait ValidateWithin {
+    fn validate_within(&self, now: SystemTime, within: Duration) -> Result<(), WithinError>;
+}
+
+/// Validation of the date and time being at least the period before the current time,
+/// like the birthdate of the age over 18 years.
+///
+/// The years and the months of the period follow the calendar in UTC.
+///
+/// ```rust
+/// use std::time::{Duration, SystemTime};
+///
+/// use serde_valid::validation::Period;
+/// use serde_valid::ValidateOlderThan;
+///
+/// // 2024-05-10T00:00:00Z
+/// let now = SystemTime::UNIX_EPOCH + Duration::from_secs(19_853 * 86400);
+/// // 2006-05-10T00:00:00Z
+/// let birthdate = SystemTime::UNIX_EPOCH + Duration::from_secs(13_278 * 86400);
+///
+/// assert!(birthdate.validate_older_than(now, Period::years(18)).is_ok());
+/// assert!((birthdate + Duration::from_secs(1))
+///     .validate_older_than(now, Period::years(18))
+///     .is_err());
+/// ```
+pub trait ValidateOlderThan {
+    fn validate_older_than(
+        &self,
+        now: SystemTime,
+        older_than: Period,
+    ) -> Result<(), OlderThanError>;
+}
+
+/// Signed distance from the current time.
+///
+/// `Ok` is the distance after the current time, and `Err` is the distance before it.
+trait SinceNow {
+    fn since_now(&self, now: SystemTime) -> Result<Duration, Duration>;
+}
+
+impl SinceNow for SystemTime {
+    fn since_now(&self, now: SystemTime) -> Result<Duration, Duration> {
+        self.duration_since(now).map_err(|error| error.duration())
+    }
+}
+
+macro_rules! impl_validate_relative_time {
+    ([$($generics:tt)*] $type:ty) => {
+        impl<$($generics)*> crate::ValidatePast for $type {
+            fn validate_past(&self, now: SystemTime) -> Result<(), crate::PastError> {
+                match SinceNow::since_now(self, now) {
+                    Err(distance) if !distance.is_zero() => Ok(()),
+                    _ => Err(crate::PastError),
+                }
+            }
+        }
+
+        impl<$($generics)*> crate::ValidateFuture for $type {
+            fn validate_future(&self, now: SystemTime) -> Result<(), crate::FutureError> {
+                match SinceNow::since_now(self, now) {
+                    Ok(distance) if !distance.is_zero() => Ok(()),
+                    _ => Err(crate::FutureError),
+                }
+            }
+        }
+
+        impl<$($generics)*> crate::ValidateWithin for $type {
+            fn validate_within(
+                &self,
+                now: SystemTime,
+                within: Duration,
+            ) -> Result<(), crate::WithinError> {
+                let (Ok(distance) | Err(distance)) = SinceNow::since_now(self, now);
+                if distance <= within {
+                    Ok(())
+                } else {
+                    Err(crate::WithinError::new(within))
+                }
+            }
+        }
+
+        impl<$($generics)*> crate::ValidateOlderThan for $type {
+            fn validate_older_than(
+                &self,
+                now: SystemTime,
+                older_than: crate::validation::Period,
+            ) -> Result<(), crate::OlderThanError> {
+                let since = crate::validation::relative_time::calendar::period_before(now, older_than)
+                    .map(|before| SinceNow::since_now(self, before));
+                match since {
+                    Some(Err(_)) => Ok(()),
+                    Some(Ok(distance)) if distance.is_zero() => Ok(()),
+                    _ => Err(crate::OlderThanError::new(older_than)),
+                }
+            }
+        }
+    };
+}
+
+impl_validate_relative_time!([] SystemTime);
+
+#[cfg(feature = "chrono")]
+mod chrono;
+#[cfg(feature = "time")]
+mod time;
+
+macro_rules! impl_composited_relative_time_validation {
+    (
+        pub trait $ValidateCompositedTrait:ident {
+            fn $validate_composited_method:ident(
+                &self,
+                $($arg:ident: $arg_type:ty),+$(,)*
+            ) -> Result<(), Composited<$Error:ty>>;
+        }
+        via $ValidateTrait:ident::$validate_method:ident;
+    ) => {
+        pub trait $ValidateCompositedTrait {
+            fn $validate_composited_method(
+                &self,
+                $($arg: $arg_type),+
+            ) -> Result<(), Composited<$Error>>;
+        }
+
+        impl<T> $ValidateCompositedTrait for T
+        where
+            T: $ValidateTrait,
+        {
+            fn $validate_composited_method(
+                &self,
+                $($arg: $arg_type),+
+            ) -> Result<(), Composited<$Error>> {
+                self.$validate_method($($arg),+).map_err(Composited::Single)
+            }
+        }
+
+        impl<T> $ValidateCompositedTrait for Vec<T>
+        where
+            T: $ValidateCompositedTrait,
+        {
+            fn $validate_composited_method(
+                &self,
+                $($arg: $arg_type),+
+            ) -> Result<(), Composited<$Error>> {
+                let errors: IndexMap<usize, Composited<$Error>> = self
+                    .iter()
+                    .enumerate()
+                    .filter_map(|(index, item)| {
+                        item.$validate_composited_method($($arg),+)
+                            .err()
+                            .map(|error| (index, error))
+                    })
+                    .collect();
+
+                if errors.is_empty() {
+                    Ok(())
+                } else {
+                    Err(Composited::Array(errors))
+                }
+            }
+        }
+
+        impl<T, const N: usize> $ValidateCompositedTrait for [T; N]
+        where
+            T: $ValidateCompositedTrait,
+        {
+            fn $validate_composited_method(
+                &self,
+                $($arg: $arg_type),+
+            ) -> Result<(), Composited<$Error>> {
+                let errors: IndexMap<usize, Composited<$Error>> = self
+                    .iter()
+                    .enumerate()
+                    .filter_map(|(index, item)| {
+                        item.$validate_composited_method($($arg),+)
+                            .err()
+                            .map(|error| (index, error))
+                    })
+                    .collect();
+
+                if errors.is_empty() {
+                    Ok(())
+                } else {
+                    Err(Composited::Array(errors))
+                }
+            }
+        }
+
+        impl<T> $ValidateCompositedTrait for Option<T>
+        where
+            T: $ValidateCompositedTrait,
+        {
+            fn $validate_composited_method(
+                &self,
+                $($arg: $arg_type),+
+            ) -> Result<(), Composited<$Error>> {
+                match self {
+                    Some(value) => value.$validate_composited_method($($arg),+),
+                    None => Ok(()),
+                }
+            }
+        }
+    };
+}
+
+impl_composited_relative_time_validation!(
+    pub trait ValidateCompositedPast {
+        fn validate_composited_past(&self, now: SystemTime) -> Result<(), Composited<PastError>>;
+    }
+    via ValidatePast::validate_past;
+);
+
+impl_composited_relative_time_validation!(
+    pub trait ValidateCompositedFuture {
+        fn validate_composited_future(
+            &self,
+            now: SystemTime,
+        ) -> Result<(), Composited<FutureError>>;
+    }
+    via ValidateFuture::validate_future;
+);
+
+impl_composited_relative_time_validation!(
+    pub trait ValidateCompositedWithin {
+        fn validate_composited_within(
+            &self,
+            now: SystemTime,
+            within: Duration,
+        ) -> Result<(), Composited<WithinError>>;
+    }
+    via ValidateWithin::validate_within;
+);
+
+impl_composited_relative_time_validation!(
+    pub trait ValidateCompositedOlderThan {
+        fn validate_composited_older_than(
+            &self,
+            now: SystemTime,
+            older_than: Period,
+        ) -> Result<(), Composited<OlderThanError>>;
+    }
+    via ValidateOlderThan::validate_older_than;
+);
@@ -0,0 +1,133 @@
+//! Calendar arithmetic of [`SystemTime`] in UTC.
+
+use std::time::{Duration, SystemTime};
+
+use serde_valid_literal::days_in_month;
+
+use crate::validation::Period;
+
+const SECONDS_PER_DAY: i64 = 24 * 3600;
+
+/// The instant of the period before `now`, subtracting the months by the calendar in UTC.
+///
+/// The day is clamped to the end of the month, like `2023-02-28` 1 year before `2024-02-29`.
+pub(super) fn period_before(now: SystemTime, period: Period) -> Option<SystemTime> {
+    let days = days_since_epoch(now)?;
+    let (year, month, day) = civil_from_days(days);
+
+    let months = year * 12 + i64::from(month - 1) - i64::from(period.months());
+    let (year, month) = (months.div_euclid(12), months.rem_euclid(12) as u32 + 1);
+    let day = day.min(days_in_month(year, month));
+
+    let seconds =
+        u64::try_from((days - days_from_civil(year, month, day)) * SECONDS_PER_DAY).ok()?;
+    now.checked_sub(Duration::from_secs(seconds))?
+        .checked_sub(period.duration())
+}
+
+/// Days since the unix epoch, rounded down.
+fn days_since_epoch(now: SystemTime) -> Option<i64> {
+    let seconds = match now.duration_since(SystemTime::UNIX_EPOCH) {
+        Ok(duration) => i64::try_from(duration.as_secs()).ok()?,
+        Err(error) => {
+            let duration = error.duration();
+            let seconds = i64::try_from(duration.as_secs()).ok()?;
+            -seconds - i64::from(duration.subsec_nanos() > 0)
+        }
+    };
+    Some(seconds.div_euclid(SECONDS_PER_DAY))
+}
+
+/// The date of the days since the unix epoch in the proleptic Gregorian calendar.
+fn civil_from_days(days: i64) -> (i64, u32, u32) {
+    let days = days + 719_468;
+    let era = days.div_euclid(146_097);
+    let day_of_era = days.rem_euclid(146_097);
+    let year_of_era =
+        (day_of_era - day_of_era / 1460 + day_of_era / 36_524 - day_of_era / 146_096) / 365;
+    let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+    let month_from_march = (5 * day_of_year + 2) / 153;
+    let day = (day_of_year - (153 * month_from_march + 2) / 5 + 1) as u32;
+    let month = if month_from_march < 10 {
+        month_from_march + 3
+    } else {
+        month_from_march - 9
+    } as u32;
+    let year = year_of_era + era * 400 + i64::from(month <= 2);
+
+    (year, month, day)
+}
+
+/// The days since the unix epoch of the date in the proleptic Gregorian calendar.
+fn days_from_civil(year: i64, month: u32, day: u32) -> i64 {
+    let year = year - i64::from(month <= 2);
+    let era = year.div_euclid(400);
+    let year_of_era = year.rem_euclid(400);
+    let month_from_march = i64::from((month + 9) % 12);
+    let day_of_year = (153 * month_from_march + 2) / 5 + i64::from(day) - 1;
+    let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+
+    era * 146_097 + day_of_era - 719_468
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn date(year: i64, month: u32, day: u32) -> SystemTime {
+        let days = days_from_civil(year, month, day);
+        match u64::try_from(days * SECONDS_PER_DAY) {
+            Ok(seconds) => SystemTime::UNIX_EPOCH + Duration::from_secs(seconds),
+            Err(_) => {
+                SystemTime::UNIX_EPOCH - Duration::from_secs((-days * SECONDS_PER_DAY) as u64)
+            }
+        }
+    }
+
+    #[test]
+    fn test_civil_days_round_trip() {
+        for days in [-800_000, -1, 0, 1, 59, 60, 11_016, 19_782, 800_000] {
+            let (year, month, day) = civil_from_days(days);
+            assert_eq!(days_from_civil(year, month, day), days);
+        }
+        assert_eq!(civil_from_days(0), (1970, 1, 1));
+        assert_eq!(civil_from_days(-1), (1969, 12, 31));
+        assert_eq!(civil_from_days(19_782), (2024, 2, 29));
+    }
+
+    #[test]
+    fn test_period_before() {
+        let years = |years: u32| Period::years(years);
+
+        assert_eq!(
+            period_before(date(2024, 5, 10), years(18)),
+            Some(date(2006, 5, 10))
+        );
+        assert_eq!(
+            period_before(date(2025, 2, 28), years(1)),
+            Some(date(2024, 2, 28))
+        );
+        assert_eq!(
+            period_before(date(2024, 2, 29), years(1)),
+            Some(date(2023, 2, 28))
+        );
+        assert_eq!(
+            period_before(date(1970, 3, 31), Period::new(1, Duration::ZERO)),
+            Some(date(1970, 2, 28))
+        );
+        assert_eq!(
+            period_before(date(1980, 1, 1), years(20)),
+            Some(date(1960, 1, 1))
+        );
+    }
+
+    #[test]
+    fn test_period_before_keeps_time_of_day() {
+        let now = date(2024, 5, 10) + Duration::from_secs(3600);
+
+        assert_eq!(
+            period_before(now, "18y1h".parse().unwrap()),
+            Some(date(2006, 5, 10))
+        );
+    }
+}
@@ -0,0 +1,75 @@
+use std::time::{Duration, SystemTime};
+
+use super::SinceNow;
+
+fn signed(delta: ::chrono::TimeDelta) -> Result<Duration, Duration> {
+    let distance = delta.abs().to_std().unwrap_or(Duration::MAX);
+    if delta < ::chrono::TimeDelta::zero() {
+        Err(distance)
+    } else {
+        Ok(distance)
+    }
+}
+
+fn utc_now(now: SystemTime) -> ::chrono::DateTime<::chrono::Utc> {
+    now.into()
+}
+
+impl SinceNow for ::chrono::NaiveDate {
+    fn since_now(&self, now: SystemTime) -> Result<Duration, Duration> {
+        signed(self.signed_duration_since(utc_now(now).date_naive()))
+    }
+}
+
+impl SinceNow for ::chrono::NaiveDateTime {
+    fn since_now(&self, now: SystemTime) -> Result<Duration, Duration> {
+        signed(self.signed_duration_since(utc_now(now).naive_utc()))
+    }
+}
+
+impl<Tz: ::chrono::TimeZone> SinceNow for ::chrono::DateTime<Tz> {
+    fn since_now(&self, now: SystemTime) -> Result<Duration, Duration> {
+        signed(
+            self.naive_utc()
+                .signed_duration_since(utc_now(now).naive_utc()),
+        )
+    }
+}
+
+impl_validate_relative_time!([] ::chrono::NaiveDate);
+impl_validate_relative_time!([] ::chrono::NaiveDateTime);
+impl_validate_relative_time!([Tz: ::chrono::TimeZone] ::chrono::DateTime<Tz>);
+
+#[cfg(test)]
+mod tests {
+    use std::time::{Duration, SystemTime};
+
+    use ::chrono::{NaiveDate, TimeZone, Utc};
+
+    use crate::{ValidateFuture, ValidatePast, ValidateWithin};
+
+    #[test]
+    fn test_validate_chrono_date_time_relative() {
+        let now = SystemTime::UNIX_EPOCH + Duration::from_secs(86400);
+        let value = Utc.with_ymd_and_hms(1970, 1, 1, 12, 0, 0).unwrap();
+
+        assert!(value.validate_past(now).is_ok());
+        assert!(value.validate_future(now).is_err());
+        assert!(value
+            .validate_within(now, Duration::from_secs(12 * 3600))
+            .is_ok());
+        assert!(value
+            .validate_within(now, Duration::from_secs(12 * 3600 - 1))
+            .is_err());
+    }
+
+    #[test]
+    fn test_validate_chrono_naive_date_today() {
+        let now = SystemTime::UNIX_EPOCH + Duration::from_secs(86400 + 3600);
+        let today = NaiveDate::from_ymd_opt(1970, 1, 2).unwrap();
+
+        assert!(today.validate_past(now).is_err());
+        assert!(today.validate_future(now).is_err());
+        assert!(today.succ_opt().unwrap().validate_future(now).is_ok());
+    }
+}
@@ -0,0 +1,60 @@
+use std::time::{Duration, SystemTime};
+
+use super::SinceNow;
+
+fn signed(delta: ::time::Duration) -> Result<Duration, Duration> {
+    if delta.is_negative() {
+        Err(delta.unsigned_abs())
+    } else {
+        Ok(delta.unsigned_abs())
+    }
+}
+
+fn utc_now(now: SystemTime) -> ::time::OffsetDateTime {
+    now.into()
+}
+
+impl SinceNow for ::time::Date {
+    fn since_now(&self, now: SystemTime) -> Result<Duration, Duration> {
+        signed(*self - utc_now(now).date())
+    }
+}
+
+impl SinceNow for ::time::PrimitiveDateTime {
+    fn since_now(&self, now: SystemTime) -> Result<Duration, Duration> {
+        let now = utc_now(now);
+        signed(*self - ::time::PrimitiveDateTime::new(now.date(), now.time()))
+    }
+}
+
+impl SinceNow for ::time::OffsetDateTime {
+    fn since_now(&self, now: SystemTime) -> Result<Duration, Duration> {
+        signed(*self - utc_now(now))
+    }
+}
+
+impl_validate_relative_time!([] ::time::Date);
+impl_validate_relative_time!([] ::time::PrimitiveDateTime);
+impl_validate_relative_time!([] ::time::OffsetDateTime);
+
+#[cfg(test)]
+mod tests {
+    use std::time::{Duration, SystemTime};
+
+    use crate::{ValidateFuture, ValidatePast, ValidateWithin};
+
+    #[test]
+    fn test_validate_time_offset_date_time_relative() {
+        let now = SystemTime::UNIX_EPOCH + Duration::from_secs(86400);
+        let value = ::time::OffsetDateTime::UNIX_EPOCH + ::time::Duration::days(2);
+
+        assert!(value.validate_future(now).is_ok());
+        assert!(value.validate_past(now).is_err());
+        assert!(value
+            .validate_within(now, Duration::from_secs(86400))
+            .is_ok());
+        assert!(value
+            .validate_within(now, Duration::from_secs(86399))
+            .is_err());
+    }
+}
@@ -0,0 +1,81 @@
+use super::Errors;
+
+/// Validation result that keeps non-fatal warnings apart from errors.
+///
+/// Rules declared with `severity = "warning"` are reported in [`Report::warnings`],
+/// and do not make [`Validate::validate`](crate::Validate::validate) fail.
+///
+/// # Examples
+/// ```rust
+/// use serde_json::json;
+/// use serde_valid::Validate;
+///
+/// #[derive(Validate)]
+/// struct Config {
+///     #[validate(max_length = 8, severity = "warning")]
+///     name: String,
+/// }
+///
+/// let config = Config {
+///     name: "long config name".to_string(),
+/// };
+///
+/// assert!(config.validate().is_ok());
+///
+/// let report = config.validate_report();
+///
+/// assert!(report.errors.is_none());
+/// assert_eq!(
+///     report.warnings.unwrap().to_string(),
+///     json!({
+///         "errors": [],
+///         "properties": {
+///             "name": {
+///                 "errors": ["The length of the value must be `<= 8`."]
+///             }
+///         }
+///     })
+///     .to_string()
+/// );
+/// ```
+#[derive(Debug, Clone, serde::Serialize)]
+pub struct Report<E = crate::validation::Error> {
+    pub errors: Option<Errors<E>>,
+    pub warnings: Option<Errors<E>>,
+}
+
+impl<E> Report<E> {
+    pub fn new(errors: Option<Errors<E>>, warnings: Option<Errors<E>>) -> Self {
+        Self { errors, warnings }
+    }
+
+    pub fn is_ok(&self) -> bool {
+        self.errors.is_none()
+    }
+
+    pub fn has_warnings(&self) -> bool {
+        self.warnings.is_some()
+    }
+
+    pub fn into_result(self) -> Result<(), Errors<E>> {
+        match self.errors {
+            Some(errors) => Err(errors),
+            None => Ok(()),
+        }
+    }
+}
+
+impl<E> Default for Report<E> {
+    fn default() -> Self {
+        Self {
+            errors: None,
+            warnings: None,
+        }
+    }
+}
+
+impl<E> From<Result<(), Errors<E>>> for Report<E> {
+    fn from(result: Result<(), Errors<E>>) -> Self {
+        Self::new(result.err(), None)
+    }
+}
@@ -0,0 +1,207 @@
+#[cfg(feature = "actix")]
+mod tests {
+    use actix_web::http::{header, StatusCode};
+    use actix_web::{error, test, web, App, HttpResponse};
+    use serde::Deserialize;
+    use serde_json::json;
+    use serde_valid::actix::{Valid, ValidationErrorHandler};
+    use serde_valid::Validate;
+
+    #[derive(Debug, Deserialize, Validate)]
+    struct User {
+        #[validate(min_length = 1)]
+        name: String,
+        #[validate(maximum = 150)]
+        age: u32,
+    }
+
+    #[derive(Debug, Deserialize, Validate)]
+    struct Page {
+        #[validate(minimum = 1)]
+        page: u32,
+    }
+
+    async fn create_json(Valid(web::Json(user)): Valid<web::Json<User>>) -> String {
+        user.name
+    }
+
+    async fn create_form(Valid(web::Form(user)): Valid<web::Form<User>>) -> String {
+        user.name
+    }
+
+    async fn list(Valid(web::Query(page)): Valid<web::Query<Page>>) -> String {
+        page.page.to_string()
+    }
+
+    fn app() -> App<
+        impl actix_web::dev::ServiceFactory<
+            actix_web::dev::ServiceRequest,
+            Config = (),
+            Response = actix_web::dev::ServiceResponse,
+            Error = actix_web::Error,
+            InitError = (),
+        >,
+    > {
+        App::new()
+            .route("/json", web::post().to(create_json))
+            .route("/form", web::post().to(create_form))
+            .route("/users", web::get().to(list))
+    }
+
+    #[actix_web::test]
+    async fn valid_json_is_ok() {
+        let app = test::init_service(app()).await;
+        let request = test::TestRequest::post()
+            .uri("/json")
+            .set_json(json!({ "name": "taro", "age": 20 }))
+            .to_request();
+
+        let response = test::call_service(&app, request).await;
+
+        assert_eq!(response.status(), StatusCode::OK);
+        assert_eq!(test::read_body(response).await, "taro");
+    }
+
+    #[cfg(not(feature = "problem_details"))]
+    #[actix_web::test]
+    async fn valid_json_is_rejected_with_errors() {
+        let app = test::init_service(app()).await;
+        let request = test::TestRequest::post()
+            .uri("/json")
+            .set_json(json!({ "name": "", "age": 200 }))
+            .to_request();
+
+        let response = test::call_service(&app, request).await;
+
+        assert_eq!(response.status(), StatusCode::UNPROCESSABLE_ENTITY);
+        assert_eq!(
+            response.headers().get(header::CONTENT_TYPE).unwrap(),
+            "application/json"
+        );
+        assert_eq!(
+            test::read_body_json::<serde_json::Value, _>(response).await,
+            json!({
+                "errors": [],
+                "properties": {
+                    "name": {
+                        "errors": ["The length of the value must be `>= 1`."]
+                    },
+                    "age": {
+                        "errors": ["The number must be `<= 150`."]
+                    }
+                }
+            })
+        );
+    }
+
+    #[cfg(feature = "problem_details")]
+    #[actix_web::test]
+    async fn valid_json_is_rejected_with_problem_details() {
+        let app = test::init_service(app()).await;
+        let request = test::TestRequest::post()
+            .uri("/json")
+            .set_json(json!({ "name": "", "age": 200 }))
+            .to_request();
+
+        let response = test::call_service(&app, request).await;
+
+        assert_eq!(response.status(), StatusCode::UNPROCESSABLE_ENTITY);
+        assert_eq!(
+            response.headers().get(header::CONTENT_TYPE).unwrap(),
+            "application/problem+json"
+        );
+        assert_eq!(
+            test::read_body_json::<serde_json::Value, _>(response).await,
+            json!({
+                "type": "about:blank",
+                "title": "Unprocessable Content",
+                "status": 422,
+                "detail": "The request has 2 validation errors.",
+                "errors": [
+                    {
+                        "pointer": "/name",
+                        "message": "The length of the value must be `>= 1`.",
+                        "code": "min_length"
+                    },
+                    {
+                        "pointer": "/age",
+                        "message": "The number must be `<= 150`.",
+                        "code": "maximum"
+                    }
+                ]
+            })
+        );
+    }
+
+    #[actix_web::test]
+    async fn valid_json_keeps_extractor_error() {
+        let app = test::init_service(app()).await;
+        let request = test::TestRequest::post()
+            .uri("/json")
+            .set_json(json!({ "name": "taro" }))
+            .to_request();
+
+        let response = test::call_service(&app, request).await;
+
+        assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+    }
+
+    #[actix_web::test]
+    async fn valid_form() {
+        let app = test::init_service(app()).await;
+
+        let request = test::TestRequest::post()
+            .uri("/form")
+            .set_form([("name", "taro"), ("age", "20")])
+            .to_request();
+        let response = test::call_service(&app, request).await;
+        assert_eq!(response.status(), StatusCode::OK);
+
+        let request = test::TestRequest::post()
+            .uri("/form")
+            .set_form([("name", "taro"), ("age", "200")])
+            .to_request();
+        let response = test::call_service(&app, request).await;
+        assert_eq!(response.status(), StatusCode::UNPROCESSABLE_ENTITY);
+    }
+
+    #[actix_web::test]
+    async fn valid_query() {
+        let app = test::init_service(app()).await;
+
+        let request = test::TestRequest::get().uri("/users?page=2").to_request();
+        let response = test::call_service(&app, request).await;
+        assert_eq!(test::read_body(response).await, "2");
+
+        let request = test::TestRequest::get().uri("/users?page=0").to_request();
+        let response = test::call_service(&app, request).await;
+        assert_eq!(response.status(), StatusCode::UNPROCESSABLE_ENTITY);
+        #[cfg(not(feature = "problem_details"))]
+        assert_eq!(
+            test::read_body_json::<serde_json::Value, _>(response).await["properties"]["page"],
+            json!({ "errors": ["The number must be `>= 1`."] })
+        );
+    }
+
+    #[actix_web::test]
+    async fn custom_error_handler() {
+        let app = test::init_service(app().app_data(ValidationErrorHandler::new(|errors, req| {
+            error::InternalError::from_response(
+                "invalid request",
+                HttpResponse::BadRequest().body(format!("{} {errors}", req.path())),
+            )
+            .into()
+        })))
+        .await;
+        let request = test::TestRequest::post()
+            .uri("/json")
+            .set_json(json!({ "name": "taro", "age": 200 }))
+            .to_request();
+
+        let response = test::call_service(&app, request).await;
+
+        assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+        let body = test::read_body(response).await;
+        assert!(body.starts_with(b"/json {"));
+    }
+}
@@ -0,0 +1,224 @@
+#[cfg(feature = "axum")]
+mod tests {
+    use axum::body::Body;
+    use axum::extract::{Path, Query};
+    use axum::http::{header, Request, StatusCode};
+    use axum::response::IntoResponse;
+    use axum::routing::{get, post};
+    use axum::{Extension, Form, Json, Router};
+    use http_body_util::BodyExt;
+    use serde::Deserialize;
+    use serde_json::json;
+    use serde_valid::axum::{Valid, ValidationErrorHandler};
+    use serde_valid::Validate;
+    use tower::ServiceExt;
+
+    #[derive(Debug, Deserialize, Validate)]
+    struct User {
+        #[validate(min_length = 1)]
+        name: String,
+        #[validate(maximum = 150)]
+        age: u32,
+    }
+
+    #[derive(Debug, Deserialize, Validate)]
+    struct Page {
+        #[validate(minimum = 1)]
+        page: u32,
+    }
+
+    #[derive(Debug, Deserialize, Validate)]
+    struct UserId {
+        #[validate(minimum = 1)]
+        id: u32,
+    }
+
+    async fn create_json(Valid(Json(user)): Valid<Json<User>>) -> String {
+        user.name
+    }
+
+    async fn create_form(Valid(Form(user)): Valid<Form<User>>) -> String {
+        user.name
+    }
+
+    async fn list(Valid(Query(page)): Valid<Query<Page>>) -> String {
+        page.page.to_string()
+    }
+
+    async fn show(Valid(Path(user_id)): Valid<Path<UserId>>, Json(user): Json<User>) -> String {
+        format!("{}:{}", user_id.id, user.name)
+    }
+
+    fn app() -> Router {
+        Router::new()
+            .route("/json", post(create_json))
+            .route("/form", post(create_form))
+            .route("/users", get(list))
+            .route("/users/{id}", post(show))
+    }
+
+    fn json_request(uri: &str, body: serde_json::Value) -> Request<Body> {
+        Request::post(uri)
+            .header(header::CONTENT_TYPE, "application/json")
+            .body(Body::from(body.to_string()))
+            .unwrap()
+    }
+
+    async fn body_string(response: axum::response::Response) -> String {
+        let bytes = response.into_body().collect().await.unwrap().to_bytes();
+        String::from_utf8(bytes.to_vec()).unwrap()
+    }
+
+    #[tokio::test]
+    async fn valid_json_is_ok() {
+        let response = app()
+            .oneshot(json_request("/json", json!({ "name": "taro", "age": 20 })))
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::OK);
+        assert_eq!(body_string(response).await, "taro");
+    }
+
+    #[cfg(not(feature = "problem_details"))]
+    #[tokio::test]
+    async fn valid_json_is_rejected_with_errors() {
+        let response = app()
+            .oneshot(json_request("/json", json!({ "name": "", "age": 200 })))
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::UNPROCESSABLE_ENTITY);
+        assert_eq!(response.headers()[header::CONTENT_TYPE], "application/json");
+        assert_eq!(
+            serde_json::from_str::<serde_json::Value>(&body_string(response).await).unwrap(),
+            json!({
+                "errors": [],
+                "properties": {
+                    "name": {
+                        "errors": ["The length of the value must be `>= 1`."]
+                    },
+                    "age": {
+                        "errors": ["The number must be `<= 150`."]
+                    }
+                }
+            })
+        );
+    }
+
+    #[cfg(feature = "problem_details")]
+    #[tokio::test]
+    async fn valid_json_is_rejected_with_problem_details() {
+        let response = app()
+            .oneshot(json_request("/json", json!({ "name": "", "age": 200 })))
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::UNPROCESSABLE_ENTITY);
+        assert_eq!(
+            response.headers()[header::CONTENT_TYPE],
+            "application/problem+json"
+        );
+        assert_eq!(
+            serde_json::from_str::<serde_json::Value>(&body_string(response).await).unwrap(),
+            json!({
+                "type": "about:blank",
+                "title": "Unprocessable Content",
+                "status": 422,
+                "detail": "The request has 2 validation errors.",
+                "errors": [
+                    {
+                        "pointer": "/name",
+                        "message": "The length of the value must be `>= 1`.",
+                        "code": "min_length"
+                    },
+                    {
+                        "pointer": "/age",
+                        "message": "The number must be `<= 150`.",
+                        "code": "maximum"
+                    }
+                ]
+            })
+        );
+    }
+
+    #[tokio::test]
+    async fn valid_json_keeps_extractor_rejection() {
+        let response = app()
+            .oneshot(json_request("/json", json!({ "name": "taro" })))
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::UNPROCESSABLE_ENTITY);
+        assert!(body_string(response).await.contains("missing field `age`"));
+    }
+
+    #[tokio::test]
+    async fn valid_form() {
+        let request = |body: &'static str| {
+            Request::post("/form")
+                .header(header::CONTENT_TYPE, "application/x-www-form-urlencoded")
+                .body(Body::from(body))
+                .unwrap()
+        };
+
+        let response = app().oneshot(request("name=taro&age=20")).await.unwrap();
+        assert_eq!(response.status(), StatusCode::OK);
+
+        let response = app().oneshot(request("name=taro&age=200")).await.unwrap();
+        assert_eq!(response.status(), StatusCode::UNPROCESSABLE_ENTITY);
+    }
+
+    #[tokio::test]
+    async fn valid_query() {
+        let request = |uri: &str| Request::get(uri).body(Body::empty()).unwrap();
+
+        let response = app().oneshot(request("/users?page=2")).await.unwrap();
+        assert_eq!(body_string(response).await, "2");
+
+        let response = app().oneshot(request("/users?page=0")).await.unwrap();
+        assert_eq!(response.status(), StatusCode::UNPROCESSABLE_ENTITY);
+    }
+
+    #[tokio::test]
+    async fn valid_path() {
+        let response = app()
+            .oneshot(json_request(
+                "/users/3",
+                json!({ "name": "taro", "age": 20 }),
+            ))
+            .await
+            .unwrap();
+        assert_eq!(body_string(response).await, "3:taro");
+
+        let response = app()
+            .oneshot(json_request(
+                "/users/0",
+                json!({ "name": "taro", "age": 20 }),
+            ))
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::UNPROCESSABLE_ENTITY);
+        #[cfg(not(feature = "problem_details"))]
+        assert_eq!(
+            serde_json::from_str::<serde_json::Value>(&body_string(response).await).unwrap()
+                ["properties"]["id"],
+            json!({ "errors": ["The number must be `>= 1`."] })
+        );
+    }
+
+    #[tokio::test]
+    async fn custom_error_handler() {
+        let app = app().layer(Extension(ValidationErrorHandler::new(|errors| {
+            (StatusCode::BAD_REQUEST, format!("invalid: {errors}")).into_response()
+        })));
+
+        let response = app
+            .oneshot(json_request("/json", json!({ "name": "taro", "age": 200 })))
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+        assert!(body_string(response).await.starts_with("invalid: {"));
+    }
+}
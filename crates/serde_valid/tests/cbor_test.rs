@@ -0,0 +1,85 @@
+#[cfg(feature = "cbor")]
+mod tests {
+    use serde::{Deserialize, Serialize};
+    use serde_json::json;
+    use serde_valid::cbor::{FromCborReader, FromCborSlice, ToCborVec, ToCborWriter};
+    use serde_valid::Validate;
+
+    #[derive(Debug, Deserialize, Serialize, Validate)]
+    struct TestStruct {
+        #[validate(min_length = 1)]
+        name: String,
+        #[validate(maximum = 100)]
+        val: i32,
+    }
+
+    #[test]
+    fn cbor_round_trip_is_ok() {
+        let s = TestStruct {
+            name: "abc".to_string(),
+            val: 10,
+        };
+        let bytes = s.to_cbor_vec().unwrap();
+        let mut buffer = Vec::new();
+        s.to_cbor_writer(&mut buffer).unwrap();
+
+        assert_eq!(bytes, buffer);
+
+        let s = TestStruct::from_cbor_slice(&bytes).unwrap();
+        assert_eq!(s.name, "abc");
+        assert_eq!(s.val, 10);
+        assert!(TestStruct::from_cbor_reader(bytes.as_slice()).is_ok());
+    }
+
+    #[test]
+    fn cbor_slice_validation_error() {
+        let bytes = TestStruct {
+            name: "".to_string(),
+            val: 101,
+        }
+        .to_cbor_vec()
+        .unwrap();
+        let err = TestStruct::from_cbor_slice(&bytes).unwrap_err();
+
+        assert_eq!(
+            serde_json::to_value(err.as_validation_errors().unwrap()).unwrap(),
+            json!({
+                "errors": [],
+                "properties": {
+                    "name": {
+                        "errors": ["The length of the value must be `>= 1`."]
+                    },
+                    "val": {
+                        "errors": ["The number must be `<= 100`."]
+                    }
+                }
+            })
+        );
+    }
+
+    #[test]
+    fn cbor_deserialize_error() {
+        let err = TestStruct::from_cbor_slice(b"\xa1\x63val").unwrap_err();
+
+        assert!(err.is_serde_error());
+    }
+
+    #[test]
+    fn cbor_validated_error() {
+        let s = TestStruct {
+            name: "abc".to_string(),
+            val: 101,
+        };
+        let mut buffer = Vec::new();
+
+        assert!(s
+            .to_cbor_vec_validated()
+            .unwrap_err()
+            .is_validation_errors());
+        assert!(s
+            .to_cbor_writer_validated(&mut buffer)
+            .unwrap_err()
+            .is_validation_errors());
+        assert!(buffer.is_empty());
+    }
+}
@@ -0,0 +1,170 @@
+#[cfg(feature = "chrono")]
+mod tests {
+    use chrono::{DateTime, FixedOffset, NaiveDate, NaiveDateTime, TimeZone, Utc};
+    use serde_json::json;
+    use serde_valid::Validate;
+
+    #[derive(Debug, Validate)]
+    struct TestStruct {
+        #[validate(minimum = "2020-01-01T00:00:00Z")]
+        #[validate(exclusive_maximum = "2030-01-01T00:00:00+09:00")]
+        created_at: DateTime<Utc>,
+        #[validate(maximum = "2030-12-31")]
+        birthday: NaiveDate,
+        #[validate(exclusive_minimum = "2020-01-01T00:00:00")]
+        local: NaiveDateTime,
+        #[validate(minimum = "2020-01-01T00:00:00Z")]
+        expires_at: Option<DateTime<FixedOffset>>,
+    }
+
+    fn naive(year: i32, month: u32, day: u32) -> NaiveDateTime {
+        NaiveDate::from_ymd_opt(year, month, day)
+            .unwrap()
+            .and_hms_opt(0, 0, 0)
+            .unwrap()
+    }
+
+    #[test]
+    fn chrono_range_is_ok() {
+        let s = TestStruct {
+            created_at: Utc.with_ymd_and_hms(2020, 1, 1, 0, 0, 0).unwrap(),
+            birthday: NaiveDate::from_ymd_opt(2030, 12, 31).unwrap(),
+            local: naive(2020, 1, 2),
+            expires_at: None,
+        };
+
+        assert!(s.validate().is_ok());
+    }
+
+    #[test]
+    fn chrono_range_is_err() {
+        let s = TestStruct {
+            created_at: Utc.with_ymd_and_hms(2029, 12, 31, 15, 0, 0).unwrap(),
+            birthday: NaiveDate::from_ymd_opt(2031, 1, 1).unwrap(),
+            local: naive(2020, 1, 1),
+            expires_at: Some(
+                FixedOffset::east_opt(9 * 3600)
+                    .unwrap()
+                    .with_ymd_and_hms(2020, 1, 1, 8, 59, 59)
+                    .unwrap(),
+            ),
+        };
+
+        assert_eq!(
+            serde_json::to_value(s.validate().unwrap_err()).unwrap(),
+            json!({
+                "errors": [],
+                "properties": {
+                    "created_at": {
+                        "errors": ["The date must be `< 2030-01-01T00:00:00+09:00`."]
+                    },
+                    "birthday": {
+                        "errors": ["The date must be `<= 2030-12-31`."]
+                    },
+                    "local": {
+                        "errors": ["The date must be `> 2020-01-01T00:00:00`."]
+                    },
+                    "expires_at": {
+                        "errors": ["The date must be `>= 2020-01-01T00:00:00Z`."]
+                    }
+                }
+            })
+        );
+    }
+
+    #[test]
+    fn chrono_range_vec() {
+        #[derive(Debug, Validate)]
+        struct TestStruct {
+            #[validate(minimum = "2020-01-01")]
+            dates: Vec<NaiveDate>,
+        }
+
+        let s = TestStruct {
+            dates: vec![
+                NaiveDate::from_ymd_opt(2020, 1, 1).unwrap(),
+                NaiveDate::from_ymd_opt(2019, 12, 31).unwrap(),
+            ],
+        };
+
+        assert_eq!(
+            serde_json::to_value(s.validate().unwrap_err()).unwrap()["properties"]["dates"],
+            json!({
+                "errors": [],
+                "items": {
+                    "1": {
+                        "errors": ["The date must be `>= 2020-01-01`."]
+                    }
+                }
+            })
+        );
+    }
+
+    #[test]
+    fn chrono_relative_time() {
+        use serde_valid::clock::{with_clock, FixedClock};
+
+        #[derive(Debug, Validate)]
+        struct TestStruct {
+            #[validate(past)]
+            birthday: NaiveDate,
+            #[validate(future)]
+            #[validate(within = "30d")]
+            expires_at: DateTime<FixedOffset>,
+        }
+
+        let now = Utc.with_ymd_and_hms(2024, 6, 1, 12, 0, 0).unwrap();
+        let s = TestStruct {
+            birthday: NaiveDate::from_ymd_opt(2024, 6, 1).unwrap(),
+            expires_at: (now + chrono::Duration::days(31)).fixed_offset(),
+        };
+
+        with_clock(FixedClock::new(now.into()), || {
+            assert_eq!(
+                serde_json::to_value(s.validate().unwrap_err()).unwrap(),
+                json!({
+                    "errors": [],
+                    "properties": {
+                        "birthday": {
+                            "errors": ["The date must be in the past."]
+                        },
+                        "expires_at": {
+                            "errors": ["The date must be within `30d` from now."]
+                        }
+                    }
+                })
+            );
+        });
+    }
+
+    #[test]
+    fn chrono_older_than() {
+        use serde_valid::clock::{with_clock, FixedClock};
+
+        #[derive(Debug, Validate)]
+        struct TestStruct {
+            #[validate(older_than = "18y")]
+            birthdate: NaiveDate,
+        }
+
+        let now = Utc.with_ymd_and_hms(2024, 2, 29, 12, 0, 0).unwrap();
+        let birthdate = |year, month, day| TestStruct {
+            birthdate: NaiveDate::from_ymd_opt(year, month, day).unwrap(),
+        };
+
+        with_clock(FixedClock::new(now.into()), || {
+            assert!(birthdate(2006, 2, 28).validate().is_ok());
+            assert_eq!(
+                serde_json::to_value(birthdate(2006, 3, 1).validate().unwrap_err()).unwrap(),
+                json!({
+                    "errors": [],
+                    "properties": {
+                        "birthdate": {
+                            "errors": ["The date must be at least `18y` ago."]
+                        }
+                    }
+                })
+            );
+        });
+    }
+}
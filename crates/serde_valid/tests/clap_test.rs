@@ -0,0 +1,114 @@
+#[cfg(feature = "clap")]
+mod tests {
+    use clap::error::ErrorKind;
+    use clap::{Args, Parser};
+    use serde_valid::clap::ParseValidated;
+    use serde_valid::Validate;
+
+    #[derive(Debug, Parser, Validate)]
+    struct Cli {
+        #[arg(long, default_value_t = 4)]
+        #[validate(minimum = 1)]
+        #[validate(maximum = 64)]
+        max_workers: u32,
+
+        #[arg(short, long)]
+        #[validate(min_length = 1)]
+        name: Option<String>,
+
+        #[validate(max_items = 2)]
+        inputs: Vec<String>,
+
+        #[command(flatten)]
+        #[validate]
+        db: DbArgs,
+    }
+
+    #[derive(Debug, Args, Validate)]
+    struct DbArgs {
+        #[arg(long, default_value_t = 10)]
+        #[validate(maximum = 100)]
+        db_pool_size: u32,
+    }
+
+    #[test]
+    fn parse_validated_is_ok() {
+        let cli = Cli::try_parse_validated_from(["app", "--max-workers", "8", "a.txt"]).unwrap();
+
+        assert_eq!(cli.max_workers, 8);
+        assert_eq!(cli.inputs, vec!["a.txt"]);
+    }
+
+    #[test]
+    fn parse_validated_names_the_flags() {
+        let err = Cli::try_parse_validated_from([
+            "app",
+            "--max-workers",
+            "100",
+            "-n",
+            "",
+            "a.txt",
+            "b.txt",
+            "c.txt",
+        ])
+        .unwrap_err();
+
+        assert_eq!(err.kind(), ErrorKind::ValueValidation);
+        assert_eq!(err.exit_code(), 2);
+
+        let message = err.render().to_string();
+        assert!(message.starts_with(
+            "error: invalid value for '--max-workers': The number must be `<= 64`.\n\
+             invalid value for '--name': The length of the value must be `>= 1`.\n\
+             invalid value for '<INPUTS>': The length of the items must be `<= 2`.\n"
+        ));
+        assert!(message.contains("Usage: "));
+    }
+
+    #[test]
+    fn parse_validated_flattened_args() {
+        let err = Cli::try_parse_validated_from(["app", "--db-pool-size", "1000"]).unwrap_err();
+
+        assert!(err.render().to_string().starts_with(
+            "error: invalid value for '--db-pool-size': The number must be `<= 100`.\n"
+        ));
+    }
+
+    #[test]
+    fn parse_validated_keeps_parse_error() {
+        let err = Cli::try_parse_validated_from(["app", "--max-workers", "x"]).unwrap_err();
+
+        assert_eq!(err.kind(), ErrorKind::ValueValidation);
+        assert!(err.render().to_string().contains("invalid digit"));
+    }
+
+    #[test]
+    fn parse_validated_root_errors() {
+        fn workers_are_less_than_pool(cli: &Pool) -> Result<(), serde_valid::validation::Error> {
+            if cli.workers < cli.pool {
+                Ok(())
+            } else {
+                Err(serde_valid::validation::Error::Custom(
+                    "workers must be less than pool.".to_string(),
+                ))
+            }
+        }
+
+        #[derive(Debug, Parser, Validate)]
+        #[validate(custom = workers_are_less_than_pool)]
+        struct Pool {
+            #[arg(long)]
+            workers: u32,
+            #[arg(long)]
+            pool: u32,
+        }
+
+        let err =
+            Pool::try_parse_validated_from(["app", "--workers", "8", "--pool", "4"]).unwrap_err();
+
+        assert!(err
+            .render()
+            .to_string()
+            .starts_with("error: workers must be less than pool.\n"));
+    }
+}
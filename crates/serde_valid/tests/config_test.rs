@@ -0,0 +1,331 @@
+use serde::Deserialize;
+use serde_json::json;
+use serde_valid::config::{ConfigLoader, Error, Provenance};
+use serde_valid::format::DynFormat;
+use serde_valid::location::Location;
+use serde_valid::Validate;
+
+#[derive(Debug, Deserialize, Validate)]
+struct Config {
+    #[validate(max_items = 2)]
+    hosts: Vec<String>,
+    #[validate]
+    db: Db,
+}
+
+#[derive(Debug, Deserialize, Validate)]
+struct Db {
+    #[validate(min_length = 1)]
+    host: String,
+    #[validate(maximum = 100)]
+    pool_size: u32,
+}
+
+const DEFAULTS: &str = r#"{
+  "hosts": ["a"],
+  "db": {
+    "host": "localhost",
+    "pool_size": 10
+  }
+}"#;
+
+#[test]
+fn config_is_ok() {
+    let config = ConfigLoader::new()
+        .add_str("defaults.json", DynFormat::Json, DEFAULTS)
+        .add_str(
+            "prod.json",
+            DynFormat::Json,
+            r#"{ "db": { "pool_size": 20 } }"#,
+        )
+        .add_vars_prefixed([("APP_HOSTS", r#"["b", "c"]"#)], "APP_")
+        .load::<Config>()
+        .unwrap();
+
+    assert_eq!(config.hosts, vec!["b", "c"]);
+    assert_eq!(config.db.host, "localhost");
+    assert_eq!(config.db.pool_size, 20);
+}
+
+#[test]
+fn config_errors_have_provenance() {
+    let err = ConfigLoader::new()
+        .add_str("defaults.json", DynFormat::Json, DEFAULTS)
+        .add_str("prod.json", DynFormat::Json, r#"{ "db": { "host": "" } }"#)
+        .add_vars_prefixed([("APP_DB__POOL_SIZE", "200"), ("OTHER", "1")], "APP_")
+        .load::<Config>()
+        .unwrap_err();
+    let errors = err.as_validation_errors().unwrap();
+
+    assert_eq!(
+        serde_json::to_value(errors.iter().collect::<Vec<_>>()).unwrap(),
+        json!([
+            {
+                "pointer": "/db/host",
+                "message": "The length of the value must be `>= 1`.",
+                "provenance": {
+                    "file": {
+                        "origin": "prod.json",
+                        "location": { "line": 1, "column": 19 }
+                    }
+                }
+            },
+            {
+                "pointer": "/db/pool_size",
+                "message": "The number must be `<= 100`.",
+                "provenance": {
+                    "env": { "variable": "APP_DB__POOL_SIZE" }
+                }
+            }
+        ])
+    );
+    assert_eq!(
+        err.to_string(),
+        concat!(
+            "error at /db/host: The length of the value must be `>= 1`.\n",
+            " --> prod.json:1:19\n",
+            "\n",
+            "error at /db/pool_size: The number must be `<= 100`.\n",
+            " --> environment variable APP_DB__POOL_SIZE\n",
+        )
+    );
+}
+
+#[test]
+fn config_replaced_array_has_provenance_of_later_layer() {
+    let err = ConfigLoader::new()
+        .add_str("defaults.json", DynFormat::Json, DEFAULTS)
+        .add_str(
+            "prod.json",
+            DynFormat::Json,
+            r#"{ "hosts": ["a", "b", "c"] }"#,
+        )
+        .load::<Config>()
+        .unwrap_err();
+    let errors = err.as_validation_errors().unwrap();
+
+    assert_eq!(
+        errors.iter().next().unwrap().provenance,
+        Some(Provenance::File {
+            origin: "prod.json".to_string(),
+            location: Some(Location::new(1, 12)),
+        })
+    );
+}
+
+#[test]
+fn config_deserialize_error_has_provenance() {
+    let err = ConfigLoader::new()
+        .add_str("defaults.json", DynFormat::Json, DEFAULTS)
+        .add_vars_prefixed([("APP_DB__POOL_SIZE", "many")], "APP_")
+        .load::<Config>()
+        .unwrap_err();
+
+    match err {
+        Error::DeserializeError {
+            pointer,
+            provenance,
+            ..
+        } => {
+            assert_eq!(pointer, "/db/pool_size");
+            assert_eq!(
+                provenance,
+                Some(Provenance::Env {
+                    variable: "APP_DB__POOL_SIZE".to_string()
+                })
+            );
+        }
+        err => panic!("unexpected error: {err}"),
+    }
+}
+
+#[test]
+fn config_env_keeps_string_of_json_like_value() {
+    #[derive(Debug, Deserialize, Validate)]
+    struct Config {
+        password: String,
+        version: String,
+        nickname: String,
+        pool_size: u32,
+        debug: bool,
+    }
+
+    let config = ConfigLoader::new()
+        .add_vars_prefixed(
+            [
+                ("APP_PASSWORD", "12345"),
+                ("APP_VERSION", "1.0"),
+                ("APP_NICKNAME", "null"),
+                ("APP_POOL_SIZE", "10"),
+                ("APP_DEBUG", "true"),
+            ],
+            "APP_",
+        )
+        .load::<Config>()
+        .unwrap();
+
+    assert_eq!(config.password, "12345");
+    assert_eq!(config.version, "1.0");
+    assert_eq!(config.nickname, "null");
+    assert_eq!(config.pool_size, 10);
+    assert!(config.debug);
+}
+
+#[test]
+fn config_env_keeps_string_of_json_like_item() {
+    #[derive(Debug, Deserialize, Validate)]
+    struct Config {
+        tags: Vec<String>,
+    }
+
+    let config = ConfigLoader::new()
+        .add_vars_prefixed([("APP_TAGS", r#"[1, "a", true]"#)], "APP_")
+        .load::<Config>()
+        .unwrap();
+
+    assert_eq!(config.tags, vec!["1", "a", "true"]);
+}
+
+#[test]
+fn config_env_renamed_fields() {
+    #[derive(Debug, Deserialize, Validate)]
+    struct Config {
+        #[serde(rename = "maxWorkers")]
+        #[validate(maximum = 10)]
+        max_workers: u32,
+        #[serde(rename = "queueName")]
+        queue_name: String,
+    }
+
+    let loader = |max_workers: &str| {
+        ConfigLoader::new()
+            .add_str("defaults.json", DynFormat::Json, r#"{ "maxWorkers": 5 }"#)
+            .add_vars_prefixed(
+                [("APP_MAXWORKERS", max_workers), ("APP_QUEUENAME", "jobs")],
+                "APP_",
+            )
+            .load::<Config>()
+    };
+
+    let config = loader("8").unwrap();
+    assert_eq!(config.max_workers, 8);
+    assert_eq!(config.queue_name, "jobs");
+
+    let err = loader("20").unwrap_err();
+
+    assert_eq!(
+        err.to_string(),
+        concat!(
+            "error at /maxWorkers: The number must be `<= 10`.\n",
+            " --> environment variable APP_MAXWORKERS\n",
+        )
+    );
+}
+
+#[test]
+fn config_missing_file() {
+    let path = std::env::temp_dir().join("serde_valid_config_test_missing.json");
+
+    let err = ConfigLoader::new()
+        .add_file(&path)
+        .load::<Config>()
+        .unwrap_err();
+    assert!(matches!(err, Error::Io { .. }));
+
+    let config = ConfigLoader::new()
+        .add_str("defaults.json", DynFormat::Json, DEFAULTS)
+        .add_optional_file(&path)
+        .load::<Config>();
+    assert!(config.is_ok());
+}
+
+#[test]
+fn config_unknown_format() {
+    let err = ConfigLoader::new()
+        .add_file("config.ini")
+        .load::<Config>()
+        .unwrap_err();
+
+    assert!(matches!(err, Error::UnknownFormat(_)));
+}
+
+#[cfg(all(feature = "toml", feature = "yaml"))]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn config_toml_yaml_env() {
+        let dir = std::env::temp_dir().join(format!("serde_valid_config_{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        let defaults = dir.join("defaults.toml");
+        let prod = dir.join("prod.yaml");
+        std::fs::write(
+            &defaults,
+            "hosts = [\"a\"]\n\n[db]\nhost = \"localhost\"\npool_size = 10\n",
+        )
+        .unwrap();
+        std::fs::write(&prod, "db:\n  host: \"\"\nhosts: [a, b, c]\n").unwrap();
+
+        let result = ConfigLoader::new()
+            .add_file(&defaults)
+            .add_file(&prod)
+            .add_vars_prefixed([("APP_DB__POOL_SIZE", "200")], "APP_")
+            .load::<Config>();
+        std::fs::remove_dir_all(&dir).unwrap();
+        let err = result.unwrap_err();
+
+        let provenances = err
+            .as_validation_errors()
+            .unwrap()
+            .iter()
+            .map(|error| (error.pointer.as_str(), error.provenance.clone().unwrap()))
+            .collect::<Vec<_>>();
+        let prod = prod.display().to_string();
+
+        assert_eq!(
+            provenances,
+            vec![
+                (
+                    "/hosts",
+                    Provenance::File {
+                        origin: prod.clone(),
+                        location: Some(Location::new(3, 8)),
+                    }
+                ),
+                (
+                    "/db/host",
+                    Provenance::File {
+                        origin: prod,
+                        location: Some(Location::new(2, 9)),
+                    }
+                ),
+                (
+                    "/db/pool_size",
+                    Provenance::Env {
+                        variable: "APP_DB__POOL_SIZE".to_string()
+                    }
+                ),
+            ]
+        );
+    }
+
+    #[test]
+    fn config_toml_line() {
+        let err = ConfigLoader::new()
+            .add_str(
+                "defaults.toml",
+                DynFormat::Toml,
+                "hosts = []\n\n[db]\nhost = \"localhost\"\npool_size = 1000\n",
+            )
+            .load::<Config>()
+            .unwrap_err();
+
+        assert_eq!(
+            err.to_string(),
+            concat!(
+                "error at /db/pool_size: The number must be `<= 100`.\n",
+                " --> defaults.toml:5:13\n",
+            )
+        );
+    }
+}
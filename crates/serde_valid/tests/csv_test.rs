@@ -0,0 +1,129 @@
+#[cfg(feature = "csv")]
+mod tests {
+    use serde::Deserialize;
+    use serde_json::json;
+    use serde_valid::csv::{from_csv_reader, from_csv_reader_skip_invalid};
+    use serde_valid::Validate;
+
+    #[derive(Debug, Deserialize, Validate)]
+    struct Customer {
+        #[serde(rename = "Name")]
+        #[validate(min_length = 1)]
+        name: String,
+        #[serde(rename = "Age")]
+        #[validate(maximum = 150)]
+        age: u32,
+        #[serde(rename = "Email")]
+        #[validate(pattern = r"^.+@.+$")]
+        email: Option<String>,
+    }
+
+    const INPUT: &str = "Name,Age,Email
+Taro,20,taro@example.com
+,200,
+Saburo,x,saburo@example.com
+Shiro,30
+Goro,40,goro
+Rokuro,50,
+";
+
+    #[test]
+    fn csv_is_ok() {
+        let customers = from_csv_reader::<Customer, _>(
+            "Name,Age,Email\nTaro,20,taro@example.com\nJiro,30,\n".as_bytes(),
+        )
+        .unwrap();
+
+        assert_eq!(customers.len(), 2);
+        assert_eq!(customers[1].name, "Jiro");
+        assert_eq!(customers[1].email, None);
+    }
+
+    #[test]
+    fn csv_errors_are_keyed_by_line_and_header() {
+        let err = from_csv_reader::<Customer, _>(INPUT.as_bytes()).unwrap_err();
+
+        assert_eq!(
+            serde_json::to_value(err.as_validation_errors().unwrap()).unwrap(),
+            json!({
+                "errors": [],
+                "items": {
+                    "3": {
+                        "errors": [],
+                        "properties": {
+                            "Name": {
+                                "errors": ["The length of the value must be `>= 1`."]
+                            },
+                            "Age": {
+                                "errors": ["The number must be `<= 150`."]
+                            }
+                        }
+                    },
+                    "4": {
+                        "errors": [],
+                        "properties": {
+                            "Age": {
+                                "errors": ["The value cannot be deserialized: invalid digit found in string."]
+                            }
+                        }
+                    },
+                    "5": {
+                        "errors": ["The value cannot be deserialized: found record with 2 fields, but the previous record has 3 fields."]
+                    },
+                    "6": {
+                        "errors": [],
+                        "properties": {
+                            "Email": {
+                                "errors": ["The value must match the pattern of \"^.+@.+$\"."]
+                            }
+                        }
+                    }
+                }
+            })
+        );
+    }
+
+    #[test]
+    fn csv_skip_invalid_returns_valid_records() {
+        let customers = from_csv_reader_skip_invalid::<Customer, _>(INPUT.as_bytes()).unwrap();
+
+        assert_eq!(
+            customers
+                .records
+                .iter()
+                .map(|customer| customer.name.as_str())
+                .collect::<Vec<_>>(),
+            vec!["Taro", "Rokuro"]
+        );
+        assert_eq!(
+            serde_json::to_value(customers.errors.unwrap()).unwrap()["items"]
+                .as_object()
+                .unwrap()
+                .keys()
+                .collect::<Vec<_>>(),
+            vec!["3", "4", "5", "6"]
+        );
+    }
+
+    #[test]
+    fn csv_missing_column() {
+        let err = from_csv_reader::<Customer, _>("Name,Email\nTaro,\n".as_bytes()).unwrap_err();
+
+        assert_eq!(
+            serde_json::to_value(err.as_validation_errors().unwrap()).unwrap(),
+            json!({
+                "errors": [],
+                "items": {
+                    "2": {
+                        "errors": [],
+                        "properties": {
+                            "Age": {
+                                "errors": ["The value is required."]
+                            }
+                        }
+                    }
+                }
+            })
+        );
+    }
+}
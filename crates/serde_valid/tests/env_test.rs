@@ -0,0 +1,192 @@
+#[cfg(feature = "env")]
+mod tests {
+    use serde::Deserialize;
+    use serde_json::json;
+    use serde_valid::env::FromEnv;
+    use serde_valid::Validate;
+
+    #[derive(Debug, Deserialize, Validate)]
+    struct Config {
+        #[validate(min_length = 1)]
+        name: String,
+        debug: bool,
+        #[validate(max_items = 2)]
+        #[validate(unique_items)]
+        hosts: Vec<String>,
+        ports: Vec<u16>,
+        level: Option<Level>,
+        #[validate]
+        db: Db,
+    }
+
+    #[derive(Debug, Deserialize, Validate)]
+    #[validate(custom = pool_is_larger_than_min)]
+    struct Db {
+        #[validate(min_length = 1)]
+        host: String,
+        #[validate(maximum = 100)]
+        max_pool: u32,
+        min_pool: u32,
+    }
+
+    #[derive(Debug, PartialEq, Deserialize, Validate)]
+    #[serde(rename_all = "lowercase")]
+    enum Level {
+        Info,
+        Debug,
+    }
+
+    fn pool_is_larger_than_min(db: &Db) -> Result<(), serde_valid::validation::Error> {
+        if db.max_pool < db.min_pool {
+            Err(serde_valid::validation::Error::Custom(
+                "max_pool must be larger than min_pool.".to_string(),
+            ))
+        } else {
+            Ok(())
+        }
+    }
+
+    fn vars(overrides: &[(&'static str, &'static str)]) -> Vec<(&'static str, &'static str)> {
+        let mut vars = vec![
+            ("APP_NAME", "service"),
+            ("APP_DEBUG", "true"),
+            ("APP_HOSTS", "a, b"),
+            ("APP_PORTS", "80,443"),
+            ("APP_DB__HOST", "localhost"),
+            ("APP_DB__MAX_POOL", "10"),
+            ("APP_DB__MIN_POOL", "1"),
+            ("OTHER_NAME", "other"),
+        ];
+        for (key, value) in overrides {
+            vars.retain(|(other, _)| other != key);
+            vars.push((key, value));
+        }
+        vars
+    }
+
+    #[test]
+    fn env_is_ok() {
+        let config = Config::from_vars_prefixed(vars(&[("APP_LEVEL", "debug")]), "APP_").unwrap();
+
+        assert_eq!(config.name, "service");
+        assert!(config.debug);
+        assert_eq!(config.hosts, vec!["a", "b"]);
+        assert_eq!(config.ports, vec![80, 443]);
+        assert_eq!(config.level, Some(Level::Debug));
+        assert_eq!(config.db.host, "localhost");
+        assert_eq!(config.db.max_pool, 10);
+    }
+
+    #[test]
+    fn env_validation_errors_are_keyed_by_variable() {
+        let err = Config::from_vars_prefixed(
+            vars(&[
+                ("APP_NAME", ""),
+                ("APP_HOSTS", "a,a,b"),
+                ("APP_DB__MAX_POOL", "200"),
+                ("APP_DB__MIN_POOL", "300"),
+            ]),
+            "APP_",
+        )
+        .unwrap_err();
+
+        assert_eq!(
+            serde_json::to_value(err.as_validation_errors().unwrap()).unwrap(),
+            json!({
+                "errors": [],
+                "properties": {
+                    "APP_NAME": {
+                        "errors": ["The length of the value must be `>= 1`."]
+                    },
+                    "APP_HOSTS": {
+                        "errors": [
+                            "The length of the items must be `<= 2`.",
+                            "The items must be unique."
+                        ]
+                    },
+                    "APP_DB": {
+                        "errors": ["max_pool must be larger than min_pool."]
+                    },
+                    "APP_DB__MAX_POOL": {
+                        "errors": ["The number must be `<= 100`."]
+                    }
+                }
+            })
+        );
+    }
+
+    #[test]
+    fn env_parse_error_has_variable() {
+        let err =
+            Config::from_vars_prefixed(vars(&[("APP_PORTS", "80,http")]), "APP_").unwrap_err();
+        let err = err.as_serde_error().unwrap();
+
+        assert_eq!(err.variable(), Some("APP_PORTS"));
+        assert!(err
+            .to_string()
+            .starts_with("APP_PORTS: invalid value \"http\""));
+    }
+
+    #[test]
+    fn env_missing_variable() {
+        let vars = vars(&[])
+            .into_iter()
+            .filter(|(key, _)| *key != "APP_DB__HOST");
+        let err = Config::from_vars_prefixed(vars, "APP_").unwrap_err();
+        let err = err.as_serde_error().unwrap();
+
+        assert_eq!(err.variable(), Some("APP_DB__HOST"));
+        assert_eq!(
+            err.to_string(),
+            "APP_DB__HOST: missing environment variable"
+        );
+    }
+
+    #[test]
+    fn env_unknown_variant() {
+        let err = Config::from_vars_prefixed(vars(&[("APP_LEVEL", "trace")]), "APP_").unwrap_err();
+
+        assert_eq!(err.as_serde_error().unwrap().variable(), Some("APP_LEVEL"));
+    }
+
+    #[test]
+    fn env_renamed_fields() {
+        #[derive(Debug, Deserialize, Validate)]
+        struct Config {
+            #[serde(rename = "maxWorkers")]
+            #[validate(maximum = 10)]
+            max_workers: u32,
+            #[serde(rename = "jobQueue")]
+            #[validate]
+            job_queue: Queue,
+        }
+
+        #[derive(Debug, Deserialize, Validate)]
+        struct Queue {
+            #[serde(rename = "queueName")]
+            #[validate(min_length = 1)]
+            queue_name: String,
+        }
+
+        let err = Config::from_vars_prefixed(
+            [("APP_MAXWORKERS", "20"), ("APP_JobQueue__queueName", "")],
+            "APP_",
+        )
+        .unwrap_err();
+
+        assert_eq!(
+            serde_json::to_value(err.as_validation_errors().unwrap()).unwrap(),
+            json!({
+                "errors": [],
+                "properties": {
+                    "APP_MAXWORKERS": {
+                        "errors": ["The number must be `<= 10`."]
+                    },
+                    "APP_JobQueue__queueName": {
+                        "errors": ["The length of the value must be `>= 1`."]
+                    }
+                }
+            })
+        );
+    }
+}
@@ -0,0 +1,111 @@
+use serde_json::json;
+use serde_valid::validation::{Error, ErrorsBuilder};
+use serde_valid::Validate;
+
+#[test]
+fn errors_builder_same_as_derive() {
+    #[derive(Validate)]
+    struct TestStruct {
+        #[validate(min_length = 4)]
+        val: String,
+        #[validate]
+        inner: Vec<TestInnerStruct>,
+    }
+
+    #[derive(Validate)]
+    struct TestInnerStruct {
+        #[validate(maximum = 5)]
+        num: i32,
+    }
+
+    let s = TestStruct {
+        val: "abc".to_string(),
+        inner: vec![TestInnerStruct { num: 1 }, TestInnerStruct { num: 6 }],
+    };
+
+    let mut builder = ErrorsBuilder::new();
+    builder.at("val").push(Error::Custom(
+        "The length of the value must be `>= 4`.".to_string(),
+    ));
+    builder
+        .at("inner")
+        .index(1)
+        .at("num")
+        .push(Error::Custom("The number must be `<= 5`.".to_string()));
+
+    assert_eq!(
+        s.validate().unwrap_err().to_string(),
+        builder.build().unwrap_err().to_string()
+    );
+}
+
+#[test]
+fn errors_builder_in_custom_validation() {
+    fn points_validation(points: &[(i32, i32)]) -> Result<(), serde_valid::validation::Errors> {
+        let mut builder = ErrorsBuilder::new();
+        for (index, (x, y)) in points.iter().enumerate() {
+            if *x < 0 {
+                builder
+                    .index(index)
+                    .at("x")
+                    .push(Error::Custom("x must be positive.".to_string()));
+            }
+            if *y < 0 {
+                builder
+                    .index(index)
+                    .at("y")
+                    .push(Error::Custom("y must be positive.".to_string()));
+            }
+        }
+        builder.build()
+    }
+
+    #[derive(Validate)]
+    struct TestStruct {
+        #[validate(custom = points_validation)]
+        points: Vec<(i32, i32)>,
+    }
+
+    let s = TestStruct {
+        points: vec![(1, 1), (-1, 2), (3, -3)],
+    };
+
+    assert_eq!(
+        s.validate().unwrap_err().to_string(),
+        json!({
+            "errors": [],
+            "properties": {
+                "points": {
+                    "errors": [],
+                    "items": {
+                        "1": {
+                            "errors": [],
+                            "properties": {
+                                "x": {
+                                    "errors": ["x must be positive."]
+                                }
+                            }
+                        },
+                        "2": {
+                            "errors": [],
+                            "properties": {
+                                "y": {
+                                    "errors": ["y must be positive."]
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+        })
+        .to_string()
+    );
+}
+
+#[test]
+fn errors_builder_is_empty() {
+    let builder = ErrorsBuilder::<Error>::new();
+
+    assert!(builder.is_empty());
+    assert!(builder.build().is_ok());
+}
@@ -0,0 +1,172 @@
+use serde::{Deserialize, Serialize};
+use serde_json::json;
+use serde_valid::format::{DataFormat, DynFormat, Json};
+use serde_valid::Validate;
+
+#[derive(Debug, Deserialize, Serialize, Validate)]
+struct Config {
+    #[validate(min_length = 1)]
+    name: String,
+    #[validate(maximum = 100)]
+    workers: u32,
+}
+
+fn expected_errors() -> serde_json::Value {
+    json!({
+        "errors": [],
+        "properties": {
+            "workers": {
+                "errors": ["The number must be `<= 100`."]
+            }
+        }
+    })
+}
+
+#[test]
+fn format_json_is_ok() {
+    let config =
+        serde_valid::from_str::<Json, Config>(r#"{ "name": "app", "workers": 4 }"#).unwrap();
+
+    assert_eq!(config.workers, 4);
+    assert_eq!(
+        serde_valid::to_string::<Json, _>(&config).unwrap(),
+        r#"{"name":"app","workers":4}"#
+    );
+}
+
+#[test]
+fn format_json_reader_validation_error() {
+    let err = serde_valid::from_reader::<Json, Config, _>(
+        r#"{ "name": "app", "workers": 200 }"#.as_bytes(),
+    )
+    .unwrap_err();
+
+    assert_eq!(
+        serde_json::to_value(err.as_validation_errors().unwrap()).unwrap(),
+        expected_errors()
+    );
+}
+
+#[test]
+fn format_third_party() {
+    /// `key=value` lines, which are converted into a json object of strings.
+    struct KeyValue;
+
+    impl DataFormat for KeyValue {
+        type DeserializeError = serde_json::Error;
+        type SerializeError = std::fmt::Error;
+
+        fn deserialize_str<T>(str: &str) -> Result<T, Self::DeserializeError>
+        where
+            T: serde::de::DeserializeOwned,
+        {
+            let object = str
+                .lines()
+                .filter_map(|line| line.split_once('='))
+                .map(|(key, value)| {
+                    let value = serde_json::from_str(value).unwrap_or(json!(value));
+                    (key.trim().to_string(), value)
+                })
+                .collect::<serde_json::Map<_, _>>();
+            serde_json::from_value(object.into())
+        }
+
+        fn deserialize_reader<T, R>(mut reader: R) -> Result<T, Self::DeserializeError>
+        where
+            T: serde::de::DeserializeOwned,
+            R: std::io::Read,
+        {
+            let mut buffer = String::new();
+            reader
+                .read_to_string(&mut buffer)
+                .map_err(serde_json::Error::io)?;
+            Self::deserialize_str(&buffer)
+        }
+
+        fn serialize_string<T>(_value: &T) -> Result<String, Self::SerializeError>
+        where
+            T: ?Sized + serde::Serialize,
+        {
+            Err(std::fmt::Error)
+        }
+    }
+
+    let err = serde_valid::from_str::<KeyValue, Config>("name=app\nworkers=200\n").unwrap_err();
+
+    assert_eq!(
+        serde_json::to_value(err.as_validation_errors().unwrap()).unwrap(),
+        expected_errors()
+    );
+}
+
+#[test]
+fn dyn_format_from_path() {
+    assert_eq!(DynFormat::from_path("config.JSON"), Some(DynFormat::Json));
+    assert_eq!(DynFormat::from_path("/etc/app/config.unknown"), None);
+    assert_eq!(DynFormat::from_path("config"), None);
+}
+
+#[test]
+fn dyn_format_json() {
+    let format = DynFormat::from_extension("json").unwrap();
+    let err = format
+        .from_str::<Config>(r#"{ "name": "app", "workers": 200 }"#)
+        .unwrap_err();
+
+    assert_eq!(
+        serde_json::to_value(err.as_validation_errors().unwrap()).unwrap(),
+        expected_errors()
+    );
+
+    let err = format
+        .from_str::<Config>(r#"{ "name": "app" "#)
+        .unwrap_err();
+    assert!(err
+        .as_serde_error()
+        .unwrap()
+        .to_string()
+        .contains("EOF while parsing"));
+}
+
+#[cfg(all(feature = "toml", feature = "yaml"))]
+mod dyn_formats {
+    use super::*;
+
+    #[test]
+    fn dyn_format_errors_are_same_for_all_formats() {
+        for (path, source) in [
+            ("config.json", r#"{ "name": "app", "workers": 200 }"#),
+            ("config.toml", "name = \"app\"\nworkers = 200\n"),
+            ("config.yaml", "name: app\nworkers: 200\n"),
+            ("config.yml", "name: app\nworkers: 200\n"),
+        ] {
+            let format = DynFormat::from_path(path).unwrap();
+            let err = format
+                .from_reader::<Config, _>(source.as_bytes())
+                .unwrap_err();
+
+            assert_eq!(
+                serde_json::to_value(err.as_validation_errors().unwrap()).unwrap(),
+                expected_errors(),
+                "{path}"
+            );
+        }
+    }
+
+    #[test]
+    fn dyn_format_to_string() {
+        let config = Config {
+            name: "app".to_string(),
+            workers: 4,
+        };
+
+        assert_eq!(
+            DynFormat::Toml.to_string(&config).unwrap(),
+            "name = \"app\"\nworkers = 4\n"
+        );
+        assert_eq!(
+            DynFormat::Yaml.to_string(&config).unwrap(),
+            "name: app\nworkers: 4\n"
+        );
+    }
+}
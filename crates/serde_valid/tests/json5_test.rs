@@ -0,0 +1,98 @@
+#[cfg(feature = "json5")]
+mod tests {
+    use serde::{Deserialize, Serialize};
+    use serde_json::json;
+    use serde_valid::json5::{
+        FromJson5Reader, FromJson5Slice, FromJson5Str, FromJson5Value, ToJson5String,
+    };
+    use serde_valid::Validate;
+
+    #[derive(Debug, Deserialize, Serialize, Validate)]
+    struct TestStruct {
+        #[validate(min_length = 1)]
+        name: String,
+        #[validate(maximum = 100)]
+        val: i32,
+    }
+
+    const SOURCE: &str = r#"{
+        // comment
+        name: 'abc',
+        val: 0x10,
+    }"#;
+
+    #[test]
+    fn json5_str_is_ok() {
+        let s = TestStruct::from_json5_str(SOURCE).unwrap();
+
+        assert_eq!(s.name, "abc");
+        assert_eq!(s.val, 16);
+    }
+
+    #[test]
+    fn json5_slice_and_reader_is_ok() {
+        assert!(TestStruct::from_json5_slice(SOURCE.as_bytes()).is_ok());
+        assert!(TestStruct::from_json5_reader(SOURCE.as_bytes()).is_ok());
+    }
+
+    #[test]
+    fn json5_str_validation_error() {
+        let err = TestStruct::from_json5_str("{ name: '', val: 101, }").unwrap_err();
+
+        assert_eq!(
+            err.as_validation_errors().unwrap().to_string(),
+            json!({
+                "errors": [],
+                "properties": {
+                    "name": {
+                        "errors": ["The length of the value must be `>= 1`."]
+                    },
+                    "val": {
+                        "errors": ["The number must be `<= 100`."]
+                    }
+                }
+            })
+            .to_string()
+        );
+    }
+
+    #[test]
+    fn json5_deserialize_error() {
+        assert!(TestStruct::from_json5_str("{ name: }")
+            .unwrap_err()
+            .is_serde_error());
+        assert!(TestStruct::from_json5_slice(b"\xff")
+            .unwrap_err()
+            .is_serde_error());
+        assert!(TestStruct::from_json5_value(json!({ "name": 1, "val": 1 }))
+            .unwrap_err()
+            .is_serde_error());
+    }
+
+    #[test]
+    fn json5_value_validation_error() {
+        assert!(
+            TestStruct::from_json5_value(json!({ "name": "a", "val": 1000 }))
+                .unwrap_err()
+                .is_validation_errors()
+        );
+    }
+
+    #[test]
+    fn to_json5_string() {
+        let s = TestStruct {
+            name: "a".to_string(),
+            val: 1,
+        };
+        assert_eq!(s.to_json5_string().unwrap(), r#"{"name":"a","val":1}"#);
+
+        let s = TestStruct {
+            name: "".to_string(),
+            val: 1,
+        };
+        assert!(s
+            .to_json5_string_validated()
+            .unwrap_err()
+            .is_validation_errors());
+    }
+}
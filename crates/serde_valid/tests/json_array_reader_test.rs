@@ -0,0 +1,73 @@
+use serde::Deserialize;
+use serde_json::json;
+use serde_valid::json::{from_json_array_reader, validate_json_array_reader};
+use serde_valid::Validate;
+
+#[derive(Debug, Deserialize, Validate)]
+struct TestStruct {
+    #[validate(maximum = 100)]
+    val: i32,
+}
+
+#[test]
+fn json_array_reader_is_ok() {
+    let mut values = vec![];
+    from_json_array_reader(
+        r#" [{"val": 1}, {"val": 2}] "#.as_bytes(),
+        |item: TestStruct| values.push(item.val),
+    )
+    .unwrap();
+
+    assert_eq!(values, vec![1, 2]);
+}
+
+#[test]
+fn json_array_reader_collects_errors_by_index() {
+    let mut values = vec![];
+    let err = from_json_array_reader(
+        r#"[{"val": 101}, {"val": 2}, {"val": 300}]"#.as_bytes(),
+        |item: TestStruct| values.push(item.val),
+    )
+    .unwrap_err();
+
+    assert_eq!(values, vec![2]);
+    assert_eq!(
+        serde_json::to_value(err.as_validation_errors().unwrap()).unwrap(),
+        json!({
+            "errors": [],
+            "items": {
+                "0": {
+                    "errors": [],
+                    "properties": {
+                        "val": { "errors": ["The number must be `<= 100`."] }
+                    }
+                },
+                "2": {
+                    "errors": [],
+                    "properties": {
+                        "val": { "errors": ["The number must be `<= 100`."] }
+                    }
+                }
+            }
+        })
+    );
+}
+
+#[test]
+fn json_array_reader_empty() {
+    assert!(validate_json_array_reader::<TestStruct, _>("[]".as_bytes()).is_ok());
+}
+
+#[test]
+fn json_array_reader_deserialize_error() {
+    let err =
+        validate_json_array_reader::<TestStruct, _>(r#"[{"val": 1}, {"val": "2"}]"#.as_bytes())
+            .unwrap_err();
+    assert!(err.is_serde_error());
+
+    let err = validate_json_array_reader::<TestStruct, _>(r#"{"val": 1}"#.as_bytes()).unwrap_err();
+    assert!(err.is_serde_error());
+
+    let err = validate_json_array_reader::<TestStruct, _>(r#"[] []"#.as_bytes()).unwrap_err();
+    assert!(err.is_serde_error());
+}
@@ -0,0 +1,117 @@
+use serde::Deserialize;
+use serde_json::json;
+use serde_valid::json::from_json_lines_reader;
+use serde_valid::Validate;
+
+#[derive(Debug, Deserialize, Validate)]
+struct TestStruct {
+    #[validate(min_length = 1)]
+    name: String,
+    #[validate(maximum = 100)]
+    val: i32,
+}
+
+const INPUT: &str = r#"{"name": "a", "val": 1}
+{"name": "", "val": 200}
+
+{"name": "c", "val": "3"}
+{"name": "d", "val": 4}
+"#;
+
+#[test]
+fn json_lines_iterator() {
+    let results = from_json_lines_reader::<TestStruct, _>(INPUT.as_bytes()).collect::<Vec<_>>();
+
+    assert_eq!(results.len(), 4);
+    assert_eq!(results[0].as_ref().unwrap().name, "a");
+
+    let err = results[1].as_ref().unwrap_err();
+    assert_eq!(err.line, 2);
+    assert!(err.error.is_validation_errors());
+
+    let err = results[2].as_ref().unwrap_err();
+    assert_eq!(err.line, 4);
+    assert!(err.error.is_serde_error());
+    assert!(err.to_string().starts_with("line 4: "));
+
+    assert_eq!(results[3].as_ref().unwrap().val, 4);
+}
+
+#[test]
+fn json_lines_invalid_utf8_line() {
+    let input = b"{\"name\": \"a\", \"val\": 1}\n{\"name\": \"\xff\", \"val\": 2}\n{\"name\": \"c\", \"val\": 3}\n";
+    let results = from_json_lines_reader::<TestStruct, _>(&input[..]).collect::<Vec<_>>();
+
+    assert_eq!(results.len(), 3);
+    assert!(results[0].is_ok());
+
+    let err = results[1].as_ref().unwrap_err();
+    assert_eq!(err.line, 2);
+    assert!(err
+        .error
+        .as_serde_error()
+        .is_some_and(|error| !error.is_io()));
+
+    assert_eq!(results[2].as_ref().unwrap().name, "c");
+}
+
+#[test]
+fn json_lines_summarize() {
+    let err = from_json_lines_reader::<TestStruct, _>(INPUT.as_bytes())
+        .summarize()
+        .unwrap_err();
+
+    assert_eq!(
+        serde_json::to_value(err.as_validation_errors().unwrap()).unwrap(),
+        json!({
+            "errors": [],
+            "items": {
+                "2": {
+                    "errors": [],
+                    "properties": {
+                        "name": {
+                            "errors": ["The length of the value must be `>= 1`."]
+                        },
+                        "val": {
+                            "errors": ["The number must be `<= 100`."]
+                        }
+                    }
+                },
+                "4": {
+                    "errors": [
+                        "The value cannot be deserialized: invalid type: string \"3\", expected i32 at line 1 column 24."
+                    ]
+                }
+            }
+        })
+    );
+}
+
+#[test]
+fn json_lines_summarize_is_ok() {
+    let input = "{\"name\": \"a\", \"val\": 1}\r\n{\"name\": \"b\", \"val\": 2}";
+
+    assert!(from_json_lines_reader::<TestStruct, _>(input.as_bytes())
+        .summarize()
+        .is_ok());
+}
+
+#[test]
+fn json_lines_io_error() {
+    struct BrokenReader;
+
+    impl std::io::Read for BrokenReader {
+        fn read(&mut self, _: &mut [u8]) -> std::io::Result<usize> {
+            Err(std::io::Error::other("broken"))
+        }
+    }
+
+    let mut lines = from_json_lines_reader::<TestStruct, _>(BrokenReader);
+    assert!(lines.next().unwrap().unwrap_err().error.is_serde_error());
+    assert!(lines.next().is_none());
+
+    let err = from_json_lines_reader::<TestStruct, _>(BrokenReader)
+        .summarize()
+        .unwrap_err();
+    assert!(err.as_serde_error().unwrap().is_io());
+}
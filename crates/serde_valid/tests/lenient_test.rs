@@ -0,0 +1,291 @@
+use serde::Deserialize;
+use serde_json::json;
+use serde_valid::json::{FromJsonStr, FromJsonValue};
+use serde_valid::Validate;
+
+#[derive(Debug, Deserialize, Validate)]
+struct TestStruct {
+    #[validate(minimum = 0)]
+    age: i32,
+    #[validate(min_length = 1)]
+    name: String,
+    #[validate]
+    items: Vec<TestItem>,
+}
+
+#[derive(Debug, Deserialize, Validate)]
+struct TestItem {
+    #[validate(maximum = 10)]
+    count: u8,
+    #[validate(min_length = 1)]
+    label: Option<String>,
+}
+
+#[test]
+fn lenient_is_ok() {
+    let s = TestStruct::from_json_value_lenient(json!({
+        "age": 20,
+        "name": "taro",
+        "items": [{ "count": 1 }]
+    }));
+
+    assert!(s.is_ok());
+}
+
+#[test]
+fn lenient_collects_all_errors() {
+    let err = TestStruct::from_json_value_lenient(json!({
+        "age": "ten",
+        "items": [
+            { "count": 100, "label": "" },
+            { "count": -1 },
+            { "label": 1 }
+        ]
+    }))
+    .unwrap_err();
+
+    assert_eq!(
+        serde_json::to_value(err.as_validation_errors().unwrap()).unwrap(),
+        json!({
+            "errors": [],
+            "properties": {
+                "age": {
+                    "errors": ["The value cannot be deserialized: invalid type: string \"ten\", expected i32."]
+                },
+                "name": {
+                    "errors": ["The value is required."]
+                },
+                "items": {
+                    "errors": [],
+                    "items": {
+                        "0": {
+                            "errors": [],
+                            "properties": {
+                                "count": {
+                                    "errors": ["The number must be `<= 10`."]
+                                },
+                                "label": {
+                                    "errors": ["The length of the value must be `>= 1`."]
+                                }
+                            }
+                        },
+                        "1": {
+                            "errors": [],
+                            "properties": {
+                                "count": {
+                                    "errors": ["The value cannot be deserialized: invalid value: integer `-1`, expected u8."]
+                                }
+                            }
+                        },
+                        "2": {
+                            "errors": [],
+                            "properties": {
+                                "count": {
+                                    "errors": ["The value is required."]
+                                },
+                                "label": {
+                                    "errors": ["The value cannot be deserialized: invalid type: integer `1`, expected a string."]
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+        })
+    );
+}
+
+#[test]
+fn lenient_skips_validation_of_repaired_values() {
+    #[derive(Debug, Deserialize, Validate)]
+    struct TestStruct {
+        #[validate(min_length = 1)]
+        name: String,
+        #[validate(min_length = 1)]
+        nickname: String,
+    }
+
+    let err = TestStruct::from_json_value_lenient(json!({ "nickname": "" })).unwrap_err();
+
+    assert_eq!(
+        serde_json::to_value(err.as_validation_errors().unwrap()).unwrap(),
+        json!({
+            "errors": [],
+            "properties": {
+                "name": {
+                    "errors": ["The value is required."]
+                },
+                "nickname": {
+                    "errors": ["The length of the value must be `>= 1`."]
+                }
+            }
+        })
+    );
+}
+
+#[test]
+fn lenient_unknown_field() {
+    #[derive(Debug, Deserialize, Validate)]
+    #[serde(deny_unknown_fields)]
+    struct TestStruct {
+        #[validate(maximum = 10)]
+        val: i32,
+    }
+
+    let err = TestStruct::from_json_value_lenient(json!({ "val": 20, "unknown": 1 })).unwrap_err();
+    let errors = err.as_validation_errors().unwrap();
+
+    assert_eq!(
+        serde_json::to_value(errors).unwrap()["properties"]["val"],
+        json!({ "errors": ["The number must be `<= 10`."] })
+    );
+    assert!(
+        serde_json::to_value(errors).unwrap()["properties"]["unknown"]["errors"][0]
+            .as_str()
+            .unwrap()
+            .contains("unknown field `unknown`")
+    );
+}
+
+#[test]
+fn lenient_root_type_error() {
+    let err = TestStruct::from_json_value_lenient(json!("x")).unwrap_err();
+
+    assert_eq!(
+        serde_json::to_value(err.as_validation_errors().unwrap()).unwrap()["errors"],
+        json!(["The value cannot be deserialized: invalid type: string \"x\", expected struct TestStruct."])
+    );
+}
+
+#[test]
+fn lenient_str() {
+    let err = TestStruct::from_json_str_lenient(
+        r#"{ "age": -1, "name": "taro", "items": [{ "count": "1" }] }"#,
+    )
+    .unwrap_err();
+
+    assert_eq!(
+        serde_json::to_value(err.as_validation_errors().unwrap()).unwrap(),
+        json!({
+            "errors": [],
+            "properties": {
+                "age": {
+                    "errors": ["The number must be `>= 0`."]
+                },
+                "items": {
+                    "errors": [],
+                    "items": {
+                        "0": {
+                            "errors": [],
+                            "properties": {
+                                "count": {
+                                    "errors": ["The value cannot be deserialized: invalid type: string \"1\", expected u8."]
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+        })
+    );
+}
+
+#[test]
+fn lenient_str_syntax_error() {
+    let err = TestStruct::from_json_str_lenient(r#"{ "age": "#).unwrap_err();
+
+    assert!(err.is_serde_error());
+}
+
+#[test]
+fn lenient_records_issue_on_repaired_path() {
+    #[derive(Debug, Deserialize)]
+    struct RawRange {
+        min: u8,
+        max: u8,
+    }
+
+    #[derive(Debug, Deserialize, Validate)]
+    #[serde(try_from = "RawRange")]
+    struct Range {
+        min: u8,
+        max: u8,
+    }
+
+    impl TryFrom<RawRange> for Range {
+        type Error = String;
+
+        fn try_from(raw: RawRange) -> Result<Self, Self::Error> {
+            if raw.min < raw.max {
+                Ok(Self {
+                    min: raw.min,
+                    max: raw.max,
+                })
+            } else {
+                Err("min must be less than max".to_string())
+            }
+        }
+    }
+
+    #[derive(Debug, Deserialize, Validate)]
+    struct TestStruct {
+        #[validate]
+        range: Range,
+    }
+
+    let s =
+        TestStruct::from_json_value_lenient(json!({ "range": { "min": 1, "max": 2 } })).unwrap();
+    assert_eq!((s.range.min, s.range.max), (1, 2));
+
+    let err = TestStruct::from_json_value_lenient(json!({ "range": { "min": "x", "max": 0 } }))
+        .unwrap_err();
+
+    assert_eq!(
+        serde_json::to_value(err.as_validation_errors().unwrap()).unwrap(),
+        json!({
+            "errors": [],
+            "properties": {
+                "range": {
+                    "errors": [],
+                    "properties": {
+                        "min": {
+                            "errors": [
+                                "The value cannot be deserialized: invalid type: string \"x\", expected u8."
+                            ]
+                        }
+                    }
+                }
+            }
+        })
+    );
+}
+
+#[test]
+fn lenient_repairs_are_truncated_by_document_size() {
+    let items = vec![json!({ "count": "x" }); 100_000];
+    let err = TestStruct::from_json_value_lenient(json!({
+        "age": 20,
+        "name": "taro",
+        "items": items
+    }))
+    .unwrap_err();
+    let errors = serde_json::to_value(err.as_validation_errors().unwrap()).unwrap();
+
+    assert_eq!(
+        errors["errors"],
+        json!([
+            "The deserialization errors are truncated, because the document has too many of them."
+        ])
+    );
+    assert_eq!(
+        errors["properties"]["items"]["items"]["0"],
+        json!({
+            "errors": [],
+            "properties": {
+                "count": {
+                    "errors": ["The value cannot be deserialized: invalid type: string \"x\", expected u8."]
+                }
+            }
+        })
+    );
+}
@@ -0,0 +1,239 @@
+use serde::Deserialize;
+use serde_valid::json::{FromJsonSlice, FromJsonStr};
+use serde_valid::location::Location;
+use serde_valid::Validate;
+
+#[derive(Debug, Deserialize, Validate)]
+#[validate(custom = |s| check_names(&s.items))]
+struct TestStruct {
+    #[validate(min_length = 3)]
+    name: String,
+    #[validate]
+    items: Vec<TestItem>,
+}
+
+#[derive(Debug, Deserialize, Validate)]
+struct TestItem {
+    #[validate(maximum = 10)]
+    count: i32,
+}
+
+fn check_names(items: &[TestItem]) -> Result<(), serde_valid::validation::Error> {
+    if items.len() > 2 {
+        Err(serde_valid::validation::Error::Custom(
+            "Too many items.".to_string(),
+        ))
+    } else {
+        Ok(())
+    }
+}
+
+const SOURCE: &str = r#"{
+  "name": "ab",
+  "items": [
+    { "count": 1 },
+    { "count": 100 },
+    { "count": 2 }
+  ]
+}"#;
+
+#[test]
+fn json_str_with_location() {
+    let err = TestStruct::from_json_str_with_location(SOURCE).unwrap_err();
+    let errors = err.as_validation_errors().unwrap();
+
+    assert_eq!(
+        errors
+            .iter()
+            .map(|error| (error.pointer.as_str(), error.location))
+            .collect::<Vec<_>>(),
+        vec![
+            ("", Some(Location::new(1, 1))),
+            ("/name", Some(Location::new(2, 11))),
+            ("/items/1/count", Some(Location::new(5, 16))),
+        ]
+    );
+    assert_eq!(
+        errors.iter().nth(2).unwrap().snippet.as_deref(),
+        Some(r#"    { "count": 100 },"#)
+    );
+}
+
+#[test]
+fn json_str_with_location_render() {
+    let err = TestStruct::from_json_str_with_location(SOURCE)
+        .unwrap_err()
+        .with_origin("test.json");
+
+    assert_eq!(
+        err.to_string(),
+        [
+            "error: Too many items.",
+            " --> test.json:1:1",
+            "  |",
+            "1 | {",
+            "  | ^",
+            "",
+            "error at /name: The length of the value must be `>= 3`.",
+            " --> test.json:2:11",
+            "  |",
+            r#"2 |   "name": "ab","#,
+            "  |           ^^^^",
+            "",
+            "error at /items/1/count: The number must be `<= 10`.",
+            " --> test.json:5:16",
+            "  |",
+            r#"5 |     { "count": 100 },"#,
+            "  |                ^^^",
+            "",
+        ]
+        .join("\n")
+    );
+}
+
+#[test]
+fn json_slice_with_location_into_error() {
+    let err = TestStruct::from_json_slice_with_location(SOURCE.as_bytes()).unwrap_err();
+    let err: serde_valid::Error<serde_json::Error> = err.into();
+
+    assert!(err.is_validation_errors());
+}
+
+#[test]
+fn json_str_with_location_deserialize_error() {
+    let err = TestStruct::from_json_str_with_location(r#"{ "name": 1 }"#).unwrap_err();
+
+    assert!(err.is_serde_error());
+}
+
+#[cfg(feature = "toml")]
+mod toml {
+    use super::TestStruct;
+    use serde_valid::location::Location;
+    use serde_valid::toml::{FromTomlSlice, FromTomlStr};
+
+    const SOURCE: &str = r#"name = "ab"
+
+[[items]]
+count = 1
+
+[[items]]
+count = 100
+
+[[items]]
+count = 2
+"#;
+
+    #[test]
+    fn toml_str_with_location() {
+        let err = TestStruct::from_toml_str_with_location(SOURCE)
+            .unwrap_err()
+            .with_origin("test.toml");
+        let errors = err.as_validation_errors().unwrap();
+
+        assert_eq!(
+            errors
+                .iter()
+                .map(|error| (error.pointer.as_str(), error.location))
+                .collect::<Vec<_>>(),
+            vec![
+                ("", Some(Location::new(1, 1))),
+                ("/name", Some(Location::new(1, 8))),
+                ("/items/1/count", Some(Location::new(7, 9))),
+            ]
+        );
+        assert!(err.to_string().contains(
+            &[
+                "error at /items/1/count: The number must be `<= 10`.",
+                " --> test.toml:7:9",
+                "  |",
+                "7 | count = 100",
+                "  |         ^^^",
+            ]
+            .join("\n")
+        ));
+    }
+
+    #[test]
+    fn toml_slice_with_location() {
+        let err = TestStruct::from_toml_slice_with_location(SOURCE.as_bytes()).unwrap_err();
+        let errors = err.as_validation_errors().unwrap();
+
+        assert_eq!(errors.len(), 3);
+    }
+
+    #[test]
+    fn toml_slice_with_location_invalid_utf8() {
+        let err = TestStruct::from_toml_slice_with_location(b"name = \"\xff\"").unwrap_err();
+
+        assert!(err.is_serde_error());
+    }
+
+    #[test]
+    fn toml_str_with_location_deserialize_error() {
+        let err = TestStruct::from_toml_str_with_location("name = 1").unwrap_err();
+
+        assert!(err.is_serde_error());
+    }
+}
+
+#[cfg(feature = "yaml")]
+mod yaml {
+    use super::TestStruct;
+    use serde_valid::location::Location;
+    use serde_valid::yaml::{FromYamlSlice, FromYamlStr};
+
+    const SOURCE: &str = r#"---
+# test values
+name: "ab"
+items:
+  - count: 1
+  - count: 100 # too many
+  - { count: 2 }
+"#;
+
+    #[test]
+    fn yaml_str_with_location() {
+        let err = TestStruct::from_yaml_str_with_location(SOURCE)
+            .unwrap_err()
+            .with_origin("test.yaml");
+        let errors = err.as_validation_errors().unwrap();
+
+        assert_eq!(
+            errors
+                .iter()
+                .map(|error| (error.pointer.as_str(), error.location))
+                .collect::<Vec<_>>(),
+            vec![
+                ("", Some(Location::new(3, 1))),
+                ("/name", Some(Location::new(3, 7))),
+                ("/items/1/count", Some(Location::new(6, 12))),
+            ]
+        );
+        assert!(err.to_string().contains(
+            &[
+                "error at /items/1/count: The number must be `<= 10`.",
+                " --> test.yaml:6:12",
+                "  |",
+                "6 |   - count: 100 # too many",
+                "  |            ^^^",
+            ]
+            .join("\n")
+        ));
+    }
+
+    #[test]
+    fn yaml_slice_with_location() {
+        let err = TestStruct::from_yaml_slice_with_location(SOURCE.as_bytes()).unwrap_err();
+        let errors = err.as_validation_errors().unwrap();
+
+        assert_eq!(errors.len(), 3);
+    }
+
+    #[test]
+    fn yaml_str_with_location_deserialize_error() {
+        let err = TestStruct::from_yaml_str_with_location("name: [1]").unwrap_err();
+
+        assert!(err.is_serde_error());
+    }
+}
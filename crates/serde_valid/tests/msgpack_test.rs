@@ -0,0 +1,148 @@
+#[cfg(feature = "msgpack")]
+mod tests {
+    use serde::{Deserialize, Serialize};
+    use serde_json::json;
+    use serde_valid::json::FromJsonStr;
+    use serde_valid::msgpack::{
+        FromMsgpackReader, FromMsgpackSlice, ToMsgpackVec, ToMsgpackWriter,
+    };
+    use serde_valid::Validate;
+
+    #[derive(Debug, Deserialize, Serialize, Validate)]
+    struct TestStruct {
+        #[validate(min_length = 1)]
+        name: String,
+        #[validate(maximum = 100)]
+        val: i32,
+        #[validate(max_items = 2)]
+        #[validate]
+        items: Vec<TestItem>,
+    }
+
+    #[derive(Debug, Deserialize, Serialize, Validate)]
+    struct TestItem {
+        #[validate(minimum = 0)]
+        count: i32,
+    }
+
+    fn invalid() -> TestStruct {
+        TestStruct {
+            name: "".to_string(),
+            val: 101,
+            items: vec![
+                TestItem { count: 1 },
+                TestItem { count: -1 },
+                TestItem { count: -2 },
+            ],
+        }
+    }
+
+    #[test]
+    fn msgpack_round_trip_is_ok() {
+        let s = TestStruct {
+            name: "abc".to_string(),
+            val: 10,
+            items: vec![TestItem { count: 1 }],
+        };
+
+        for bytes in [
+            s.to_msgpack_vec().unwrap(),
+            s.to_msgpack_vec_named().unwrap(),
+        ] {
+            let s = TestStruct::from_msgpack_slice(&bytes).unwrap();
+            assert_eq!(s.name, "abc");
+            assert_eq!(s.items[0].count, 1);
+            assert!(TestStruct::from_msgpack_reader(bytes.as_slice()).is_ok());
+        }
+    }
+
+    #[test]
+    fn msgpack_writer_is_same_as_vec() {
+        let s = invalid();
+        let mut compact = Vec::new();
+        let mut named = Vec::new();
+        s.to_msgpack_writer(&mut compact).unwrap();
+        s.to_msgpack_writer_named(&mut named).unwrap();
+
+        assert_eq!(compact, s.to_msgpack_vec().unwrap());
+        assert_eq!(named, s.to_msgpack_vec_named().unwrap());
+    }
+
+    #[test]
+    fn msgpack_errors_are_same_as_json() {
+        let s = invalid();
+        let json_err = TestStruct::from_json_str(&serde_json::to_string(&s).unwrap()).unwrap_err();
+        let json_errors = serde_json::to_value(json_err.as_validation_errors().unwrap()).unwrap();
+
+        for bytes in [
+            s.to_msgpack_vec().unwrap(),
+            s.to_msgpack_vec_named().unwrap(),
+        ] {
+            let err = TestStruct::from_msgpack_slice(&bytes).unwrap_err();
+
+            assert_eq!(
+                serde_json::to_value(err.as_validation_errors().unwrap()).unwrap(),
+                json_errors
+            );
+        }
+
+        assert_eq!(
+            json_errors,
+            json!({
+                "errors": [],
+                "properties": {
+                    "name": {
+                        "errors": ["The length of the value must be `>= 1`."]
+                    },
+                    "val": {
+                        "errors": ["The number must be `<= 100`."]
+                    },
+                    "items": {
+                        "errors": ["The length of the items must be `<= 2`."],
+                        "items": {
+                            "1": {
+                                "errors": [],
+                                "properties": {
+                                    "count": {
+                                        "errors": ["The number must be `>= 0`."]
+                                    }
+                                }
+                            },
+                            "2": {
+                                "errors": [],
+                                "properties": {
+                                    "count": {
+                                        "errors": ["The number must be `>= 0`."]
+                                    }
+                                }
+                            }
+                        }
+                    }
+                }
+            })
+        );
+    }
+
+    #[test]
+    fn msgpack_deserialize_error() {
+        let err = TestStruct::from_msgpack_slice(b"\x81\xa4name").unwrap_err();
+
+        assert!(err.is_serde_error());
+    }
+
+    #[test]
+    fn msgpack_validated_error() {
+        let s = invalid();
+        let mut buffer = Vec::new();
+
+        assert!(s
+            .to_msgpack_vec_validated()
+            .unwrap_err()
+            .is_validation_errors());
+        assert!(s
+            .to_msgpack_writer_named_validated(&mut buffer)
+            .unwrap_err()
+            .is_validation_errors());
+        assert!(buffer.is_empty());
+    }
+}
@@ -0,0 +1,90 @@
+#[cfg(feature = "problem_details")]
+mod tests {
+    use serde::Deserialize;
+    use serde_json::json;
+    use serde_valid::json::FromJsonStr;
+    use serde_valid::problem_details::{ProblemDetails, ProblemError, CONTENT_TYPE};
+    use serde_valid::Validate;
+
+    #[derive(Debug, Deserialize, Validate)]
+    struct TestStruct {
+        #[validate(minimum = 0)]
+        age: i32,
+        #[validate]
+        #[validate(max_items = 1)]
+        items: Vec<TestInnerStruct>,
+    }
+
+    #[derive(Debug, Deserialize, Validate)]
+    struct TestInnerStruct {
+        #[serde(rename = "a/b")]
+        #[validate(min_length = 2)]
+        name: String,
+    }
+
+    #[test]
+    fn problem_details_deserialize_error() {
+        let err = TestStruct::from_json_str(r#"{ "age": "ten", "items": [] }"#).unwrap_err();
+        let problem = ProblemDetails::from(&err);
+
+        assert_eq!(problem.status, 400);
+        assert_eq!(problem.title, "Bad Request");
+        assert_eq!(problem.detail, Some(err.to_string()));
+        assert!(problem.errors.is_empty());
+        assert_eq!(problem.content_type(), CONTENT_TYPE);
+    }
+
+    #[test]
+    fn problem_details_validation_error() {
+        let err = TestStruct::from_json_str(
+            r#"{ "age": -1, "items": [{ "a/b": "x" }, { "a/b": "yy" }] }"#,
+        )
+        .unwrap_err();
+        let problem = ProblemDetails::from(err).with_instance("/users/1");
+
+        assert_eq!(problem.status, 422);
+        assert_eq!(
+            problem.errors,
+            vec![
+                ProblemError::new("/age", "The number must be `>= 0`.", "minimum"),
+                ProblemError::new(
+                    "/items",
+                    "The length of the items must be `<= 1`.",
+                    "max_items"
+                ),
+                ProblemError::new(
+                    "/items/0/a~1b",
+                    "The length of the value must be `>= 2`.",
+                    "min_length"
+                ),
+            ]
+        );
+        assert_eq!(
+            serde_json::to_value(&problem).unwrap(),
+            json!({
+                "type": "about:blank",
+                "title": "Unprocessable Content",
+                "status": 422,
+                "detail": "The request has 3 validation errors.",
+                "instance": "/users/1",
+                "errors": [
+                    {
+                        "pointer": "/age",
+                        "message": "The number must be `>= 0`.",
+                        "code": "minimum"
+                    },
+                    {
+                        "pointer": "/items",
+                        "message": "The length of the items must be `<= 1`.",
+                        "code": "max_items"
+                    },
+                    {
+                        "pointer": "/items/0/a~1b",
+                        "message": "The length of the value must be `>= 2`.",
+                        "code": "min_length"
+                    }
+                ]
+            })
+        );
+    }
+}
@@ -0,0 +1,231 @@
+use std::time::{Duration, SystemTime};
+
+use serde_json::json;
+use serde_valid::clock::{set_clock, with_clock, FixedClock};
+use serde_valid::Validate;
+
+const DAY: Duration = Duration::from_secs(24 * 3600);
+
+fn now() -> SystemTime {
+    SystemTime::UNIX_EPOCH + Duration::from_secs(1_700_000_000)
+}
+
+#[derive(Debug, Validate)]
+struct TestStruct {
+    #[validate(past)]
+    created_at: SystemTime,
+    #[validate(future)]
+    #[validate(within = "30d")]
+    expires_at: SystemTime,
+}
+
+#[test]
+fn relative_time_is_ok() {
+    let s = TestStruct {
+        created_at: now() - DAY,
+        expires_at: now() + DAY * 30,
+    };
+
+    with_clock(FixedClock::new(now()), || assert!(s.validate().is_ok()));
+}
+
+#[test]
+fn relative_time_is_err() {
+    let s = TestStruct {
+        created_at: now(),
+        expires_at: now() + DAY * 30 + Duration::from_secs(1),
+    };
+
+    with_clock(FixedClock::new(now()), || {
+        assert_eq!(
+            serde_json::to_value(s.validate().unwrap_err()).unwrap(),
+            json!({
+                "errors": [],
+                "properties": {
+                    "created_at": {
+                        "errors": ["The date must be in the past."]
+                    },
+                    "expires_at": {
+                        "errors": ["The date must be within `30d` from now."]
+                    }
+                }
+            })
+        );
+    });
+}
+
+#[test]
+fn relative_time_follows_clock() {
+    let s = TestStruct {
+        created_at: now() - DAY,
+        expires_at: now() + DAY,
+    };
+
+    with_clock(FixedClock::new(now() + DAY * 2), || {
+        assert_eq!(
+            serde_json::to_value(s.validate().unwrap_err()).unwrap(),
+            json!({
+                "errors": [],
+                "properties": {
+                    "expires_at": {
+                        "errors": ["The date must be in the future."]
+                    }
+                }
+            })
+        );
+    });
+}
+
+#[test]
+fn relative_time_vec_and_option() {
+    #[derive(Debug, Validate)]
+    struct TestStruct {
+        #[validate(past)]
+        history: Vec<SystemTime>,
+        #[validate(within = "1h30m")]
+        deadline: Option<SystemTime>,
+    }
+
+    let s = TestStruct {
+        history: vec![now() - DAY, now() + DAY],
+        deadline: None,
+    };
+
+    with_clock(FixedClock::new(now()), || {
+        assert_eq!(
+            serde_json::to_value(s.validate().unwrap_err()).unwrap(),
+            json!({
+                "errors": [],
+                "properties": {
+                    "history": {
+                        "errors": [],
+                        "items": {
+                            "1": {
+                                "errors": ["The date must be in the past."]
+                            }
+                        }
+                    }
+                }
+            })
+        );
+    });
+}
+
+#[test]
+fn relative_time_within_expr() {
+    const WITHIN: Duration = Duration::from_secs(60);
+
+    #[derive(Debug, Validate)]
+    struct TestStruct {
+        #[validate(within = WITHIN)]
+        seen_at: SystemTime,
+    }
+
+    let s = TestStruct {
+        seen_at: now() - Duration::from_secs(61),
+    };
+
+    with_clock(FixedClock::new(now()), || {
+        assert_eq!(
+            serde_json::to_value(s.validate().unwrap_err()).unwrap(),
+            json!({
+                "errors": [],
+                "properties": {
+                    "seen_at": {
+                        "errors": ["The date must be within `1m` from now."]
+                    }
+                }
+            })
+        );
+    });
+}
+
+#[test]
+fn relative_time_custom_err_message_fn() {
+    fn error_message(_params: &serde_valid::FutureError) -> String {
+        "this is custom message.".to_string()
+    }
+
+    #[derive(Validate)]
+    struct TestStruct {
+        #[validate(future, message_fn = error_message)]
+        expires_at: SystemTime,
+    }
+
+    let s = TestStruct {
+        expires_at: now() - DAY,
+    };
+
+    with_clock(FixedClock::new(now()), || {
+        assert_eq!(
+            serde_json::to_value(s.validate().unwrap_err()).unwrap(),
+            json!({
+                "errors": [],
+                "properties": {
+                    "expires_at": {
+                        "errors": ["this is custom message."]
+                    }
+                }
+            })
+        );
+    });
+}
+
+#[test]
+fn relative_time_older_than() {
+    #[derive(Debug, Validate)]
+    struct TestStruct {
+        #[validate(older_than = "18y")]
+        birthdate: SystemTime,
+        #[validate(older_than = OLDER_THAN)]
+        joined_at: Option<SystemTime>,
+    }
+
+    const OLDER_THAN: Duration = Duration::from_secs(3600);
+
+    // 2024-05-10T00:00:00Z and 2006-05-10T00:00:00Z.
+    let now = SystemTime::UNIX_EPOCH + DAY * 19_853;
+    let adult = SystemTime::UNIX_EPOCH + DAY * 13_278;
+
+    with_clock(FixedClock::new(now), || {
+        let s = TestStruct {
+            birthdate: adult,
+            joined_at: Some(now - Duration::from_secs(3600)),
+        };
+        assert!(s.validate().is_ok());
+
+        let s = TestStruct {
+            birthdate: adult + DAY,
+            joined_at: Some(now - Duration::from_secs(3599)),
+        };
+        assert_eq!(
+            serde_json::to_value(s.validate().unwrap_err()).unwrap(),
+            json!({
+                "errors": [],
+                "properties": {
+                    "birthdate": {
+                        "errors": ["The date must be at least `18y` ago."]
+                    },
+                    "joined_at": {
+                        "errors": ["The date must be at least `1h` ago."]
+                    }
+                }
+            })
+        );
+    });
+}
+
+#[test]
+fn relative_time_process_clock() {
+    let s = TestStruct {
+        created_at: now() - DAY,
+        expires_at: now() + DAY,
+    };
+
+    // the other tests of this file use `with_clock`, which takes precedence.
+    let clock = set_clock(FixedClock::new(now()));
+    let result = std::thread::spawn(move || s.validate()).join().unwrap();
+    drop(clock);
+
+    assert!(result.is_ok());
+}
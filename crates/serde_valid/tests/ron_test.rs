@@ -0,0 +1,150 @@
+#[cfg(feature = "ron")]
+mod tests {
+    use serde::{Deserialize, Serialize};
+    use serde_json::json;
+    use serde_valid::ron::{FromRonReader, FromRonSlice, FromRonStr, ToRonString, ToRonWriter};
+    use serde_valid::Validate;
+
+    #[derive(Debug, Deserialize, Serialize, Validate)]
+    struct TestStruct {
+        #[validate(min_length = 1)]
+        name: String,
+        #[validate]
+        shape: Shape,
+        #[validate]
+        pair: (Count, Count),
+    }
+
+    #[derive(Debug, Deserialize, Serialize, Validate)]
+    enum Shape {
+        Point(#[validate(maximum = 10)] i32, #[validate(maximum = 10)] i32),
+        Rect {
+            #[validate(maximum = 10)]
+            width: i32,
+            #[validate(maximum = 10)]
+            height: i32,
+        },
+    }
+
+    #[derive(Debug, Deserialize, Serialize, Validate)]
+    struct Count(#[validate(maximum = 100)] i32);
+
+    const SOURCE: &str = r#"(
+        name: "abc",
+        shape: Rect(width: 1, height: 2),
+        pair: (Count(1), Count(2)),
+    )"#;
+
+    #[test]
+    fn ron_str_is_ok() {
+        let s = TestStruct::from_ron_str(SOURCE).unwrap();
+
+        assert_eq!(s.name, "abc");
+        assert_eq!(s.pair.1 .0, 2);
+    }
+
+    #[test]
+    fn ron_slice_and_reader_is_ok() {
+        assert!(TestStruct::from_ron_slice(SOURCE.as_bytes()).is_ok());
+        assert!(TestStruct::from_ron_reader(SOURCE.as_bytes()).is_ok());
+    }
+
+    #[test]
+    fn ron_str_validation_error() {
+        let err = TestStruct::from_ron_str(
+            r#"(name: "", shape: Rect(width: 1, height: 20), pair: (Count(1), Count(200)))"#,
+        )
+        .unwrap_err();
+
+        assert_eq!(
+            serde_json::to_value(err.as_validation_errors().unwrap()).unwrap(),
+            json!({
+                "errors": [],
+                "properties": {
+                    "name": {
+                        "errors": ["The length of the value must be `>= 1`."]
+                    },
+                    "shape": {
+                        "errors": [],
+                        "properties": {
+                            "height": {
+                                "errors": ["The number must be `<= 10`."]
+                            }
+                        }
+                    },
+                    "pair": {
+                        "errors": [],
+                        "items": {
+                            "1": {
+                                "errors": ["The number must be `<= 100`."]
+                            }
+                        }
+                    }
+                }
+            })
+        );
+    }
+
+    #[test]
+    fn ron_str_tuple_variant_validation_error() {
+        let err = TestStruct::from_ron_str(
+            r#"(name: "abc", shape: Point(1, 11), pair: (Count(1), Count(2)))"#,
+        )
+        .unwrap_err();
+
+        assert_eq!(
+            serde_json::to_value(err.as_validation_errors().unwrap()).unwrap()["properties"]
+                ["shape"],
+            json!({
+                "errors": [],
+                "items": {
+                    "1": {
+                        "errors": ["The number must be `<= 10`."]
+                    }
+                }
+            })
+        );
+    }
+
+    #[test]
+    fn ron_deserialize_error() {
+        let err = TestStruct::from_ron_str(r#"(name: "abc", shape: Square(1))"#).unwrap_err();
+
+        assert!(err.is_serde_error());
+    }
+
+    #[test]
+    fn ron_string_and_writer() {
+        let s = TestStruct::from_ron_str(SOURCE).unwrap();
+        let string = s.to_ron_string().unwrap();
+        let mut buffer = Vec::new();
+        s.to_ron_writer(&mut buffer).unwrap();
+
+        assert_eq!(
+            string,
+            r#"(name:"abc",shape:Rect(width:1,height:2),pair:((1),(2)))"#
+        );
+        assert_eq!(String::from_utf8(buffer).unwrap(), string);
+        assert!(TestStruct::from_ron_str(&string).is_ok());
+    }
+
+    #[test]
+    fn ron_string_validated_error() {
+        let s = TestStruct {
+            name: "abc".to_string(),
+            shape: Shape::Point(1, 2),
+            pair: (Count(1), Count(101)),
+        };
+        let mut buffer = Vec::new();
+
+        assert!(s
+            .to_ron_string_validated()
+            .unwrap_err()
+            .is_validation_errors());
+        assert!(s
+            .to_ron_writer_pretty_validated(&mut buffer)
+            .unwrap_err()
+            .is_validation_errors());
+        assert!(buffer.is_empty());
+    }
+}
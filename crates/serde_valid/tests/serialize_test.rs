@@ -1,7 +1,118 @@
+use serde::Serialize;
 use serde_json::json;
-use serde_valid::json::ToJsonString;
+use serde_valid::json::{ToJsonString, ToJsonValue, ToJsonWriter};
+use serde_valid::Validate;
+
+#[derive(Debug, Serialize, Validate)]
+struct TestStruct {
+    #[validate(maximum = 100)]
+    val: i32,
+}
 
 #[test]
 fn to_json_string_is_ok() {
     assert!(json!({"val": 10}).to_json_string().is_ok())
 }
+
+#[test]
+fn to_json_string_validated_is_ok() {
+    assert_eq!(
+        TestStruct { val: 10 }.to_json_string_validated().unwrap(),
+        r#"{"val":10}"#
+    );
+}
+
+#[test]
+fn to_json_string_validated_is_err() {
+    let err = TestStruct { val: 1000 }
+        .to_json_string_validated()
+        .unwrap_err();
+
+    assert_eq!(
+        err.as_validation_errors().unwrap().to_string(),
+        json!({
+            "errors": [],
+            "properties": {
+                "val": {
+                    "errors": ["The number must be `<= 100`."]
+                }
+            }
+        })
+        .to_string()
+    );
+}
+
+#[test]
+fn to_json_value_validated() {
+    assert_eq!(
+        TestStruct { val: 10 }.to_json_value_validated().unwrap(),
+        json!({"val": 10})
+    );
+    assert!(TestStruct { val: 1000 }
+        .to_json_value_validated()
+        .unwrap_err()
+        .is_validation_errors());
+}
+
+#[test]
+fn to_json_writer_validated() {
+    let mut buffer = vec![];
+    TestStruct { val: 10 }
+        .to_json_writer_pretty_validated(&mut buffer)
+        .unwrap();
+    assert_eq!(buffer, b"{\n  \"val\": 10\n}");
+
+    let mut buffer = vec![];
+    assert!(TestStruct { val: 1000 }
+        .to_json_writer_validated(&mut buffer)
+        .is_err());
+    assert!(buffer.is_empty());
+}
+
+#[cfg(feature = "toml")]
+mod toml {
+    use super::TestStruct;
+    use serde_valid::toml::{ToTomlString, ToTomlValue, ToTomlWriter};
+
+    #[test]
+    fn to_toml_validated() {
+        assert_eq!(
+            TestStruct { val: 10 }.to_toml_string_validated().unwrap(),
+            "val = 10\n"
+        );
+        assert!(TestStruct { val: 1000 }
+            .to_toml_value_validated()
+            .unwrap_err()
+            .is_validation_errors());
+
+        let mut buffer = vec![];
+        assert!(TestStruct { val: 1000 }
+            .to_toml_writer_validated(&mut buffer)
+            .is_err());
+        assert!(buffer.is_empty());
+    }
+}
+
+#[cfg(feature = "yaml")]
+mod yaml {
+    use super::TestStruct;
+    use serde_valid::yaml::{ToYamlString, ToYamlValue, ToYamlWriter};
+
+    #[test]
+    fn to_yaml_validated() {
+        assert_eq!(
+            TestStruct { val: 10 }.to_yaml_string_validated().unwrap(),
+            "val: 10\n"
+        );
+        assert!(TestStruct { val: 1000 }
+            .to_yaml_value_validated()
+            .unwrap_err()
+            .is_validation_errors());
+
+        let mut buffer = vec![];
+        assert!(TestStruct { val: 1000 }
+            .to_yaml_writer_validated(&mut buffer)
+            .is_err());
+        assert!(buffer.is_empty());
+    }
+}
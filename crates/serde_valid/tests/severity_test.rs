@@ -0,0 +1,183 @@
+use serde_json::json;
+use serde_valid::Validate;
+
+#[test]
+fn severity_warning_is_ok() {
+    #[derive(Validate)]
+    struct TestStruct {
+        #[validate(max_length = 3, severity = "warning")]
+        val: String,
+    }
+
+    let s = TestStruct {
+        val: "abcde".to_string(),
+    };
+
+    assert!(s.validate().is_ok());
+
+    let report = s.validate_report();
+    assert!(report.is_ok());
+    assert!(report.errors.is_none());
+    assert_eq!(
+        report.warnings.unwrap().to_string(),
+        json!({
+            "errors": [],
+            "properties": {
+                "val": {
+                    "errors": ["The length of the value must be `<= 3`."]
+                }
+            }
+        })
+        .to_string()
+    );
+}
+
+#[test]
+fn severity_error_and_warning_are_separated() {
+    #[derive(Validate)]
+    struct TestStruct {
+        #[validate(minimum = 10, severity = "error")]
+        #[validate(maximum = 0, message = "soft limit.", severity = "warning")]
+        val: i32,
+    }
+
+    let s = TestStruct { val: 5 };
+
+    let report = s.validate_report();
+    assert!(!report.is_ok());
+    assert_eq!(
+        report.errors.unwrap().to_string(),
+        json!({
+            "errors": [],
+            "properties": {
+                "val": {
+                    "errors": ["The number must be `>= 10`."]
+                }
+            }
+        })
+        .to_string()
+    );
+    assert_eq!(
+        report.warnings.unwrap().to_string(),
+        json!({
+            "errors": [],
+            "properties": {
+                "val": {
+                    "errors": ["soft limit."]
+                }
+            }
+        })
+        .to_string()
+    );
+    assert!(s.validate().is_err());
+}
+
+#[test]
+fn severity_warning_nested_struct() {
+    #[derive(Validate)]
+    struct TestStruct {
+        #[validate]
+        inner: Vec<TestInnerStruct>,
+    }
+
+    #[derive(Validate)]
+    struct TestInnerStruct {
+        #[validate(max_items = 1, severity = "warning")]
+        val: Vec<i32>,
+    }
+
+    let s = TestStruct {
+        inner: vec![
+            TestInnerStruct { val: vec![1] },
+            TestInnerStruct { val: vec![1, 2] },
+        ],
+    };
+
+    assert!(s.validate().is_ok());
+    assert_eq!(
+        s.validate_report().warnings.unwrap().to_string(),
+        json!({
+            "errors": [],
+            "properties": {
+                "inner": {
+                    "errors": [],
+                    "items": {
+                        "1": {
+                            "errors": [],
+                            "properties": {
+                                "val": {
+                                    "errors": ["The length of the items must be `<= 1`."]
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+        })
+        .to_string()
+    );
+}
+
+#[test]
+fn severity_warning_unnamed_struct() {
+    #[derive(Validate)]
+    struct TestStruct(
+        #[validate(maximum = 10)] i32,
+        #[validate(maximum = 10, severity = "warning")] i32,
+    );
+
+    let s = TestStruct(5, 15);
+
+    assert!(s.validate().is_ok());
+    assert_eq!(
+        s.validate_report().warnings.unwrap().to_string(),
+        json!({
+            "errors": [],
+            "items": {
+                "1": {
+                    "errors": ["The number must be `<= 10`."]
+                }
+            }
+        })
+        .to_string()
+    );
+}
+
+#[test]
+fn severity_warning_enum() {
+    #[derive(Validate)]
+    enum TestEnum {
+        Named {
+            #[validate(min_length = 4, severity = "warning")]
+            val: String,
+        },
+        NewType(#[validate(min_length = 4, severity = "warning")] String),
+    }
+
+    let s = TestEnum::Named {
+        val: "abc".to_string(),
+    };
+    assert!(s.validate().is_ok());
+    assert_eq!(
+        s.validate_report().warnings.unwrap().to_string(),
+        json!({
+            "errors": [],
+            "properties": {
+                "val": {
+                    "errors": ["The length of the value must be `>= 4`."]
+                }
+            }
+        })
+        .to_string()
+    );
+
+    let s = TestEnum::NewType("abc".to_string());
+    assert!(s.validate().is_ok());
+    assert_eq!(
+        s.validate_report().warnings.unwrap().to_string(),
+        json!({
+            "errors": ["The length of the value must be `>= 4`."]
+        })
+        .to_string()
+    );
+}
@@ -61,3 +61,37 @@ fn struct_unnamed_fields_is_err() {
         })
     );
 }
+
+#[test]
+fn struct_tuple_field_is_err() {
+    #[derive(Validate)]
+    struct Count(#[validate(maximum = 10)] i32);
+
+    #[derive(Validate)]
+    struct TestStruct {
+        #[validate]
+        val: (Count, Count, Count),
+    }
+
+    let s = TestStruct {
+        val: (Count(1), Count(20), Count(3)),
+    };
+    let err = s.validate().unwrap_err();
+
+    assert_eq!(
+        serde_json::from_str::<serde_json::Value>(&err.to_string()).unwrap(),
+        json!({
+            "errors": [],
+            "properties": {
+                "val": {
+                    "errors": [],
+                    "items": {
+                        "1": {
+                            "errors": ["The number must be `<= 10`."]
+                        }
+                    }
+                }
+            }
+        })
+    );
+}
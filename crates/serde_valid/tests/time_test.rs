@@ -0,0 +1,136 @@
+#[cfg(feature = "time")]
+mod tests {
+    use serde_json::json;
+    use serde_valid::Validate;
+    use time::{Date, Month, OffsetDateTime, PrimitiveDateTime, Time, UtcOffset};
+
+    #[derive(Debug, Validate)]
+    struct TestStruct {
+        #[validate(minimum = "2020-01-01T00:00:00Z")]
+        #[validate(maximum = "2029-12-31T23:59:59.999+09:00")]
+        created_at: OffsetDateTime,
+        #[validate(exclusive_maximum = "2030-01-01")]
+        birthday: Date,
+        #[validate(minimum = "2020-01-01T12:00:00")]
+        local: PrimitiveDateTime,
+    }
+
+    fn date(year: i32, month: Month, day: u8) -> Date {
+        Date::from_calendar_date(year, month, day).unwrap()
+    }
+
+    #[test]
+    fn time_range_is_ok() {
+        let s = TestStruct {
+            created_at: PrimitiveDateTime::new(date(2020, Month::January, 1), Time::MIDNIGHT)
+                .assume_utc(),
+            birthday: date(2029, Month::December, 31),
+            local: PrimitiveDateTime::new(
+                date(2020, Month::January, 1),
+                Time::from_hms(12, 0, 0).unwrap(),
+            ),
+        };
+
+        assert!(s.validate().is_ok());
+    }
+
+    #[test]
+    fn time_range_is_err() {
+        let s = TestStruct {
+            created_at: PrimitiveDateTime::new(
+                date(2020, Month::January, 1),
+                Time::from_hms(8, 59, 59).unwrap(),
+            )
+            .assume_offset(UtcOffset::from_hms(9, 0, 0).unwrap()),
+            birthday: date(2030, Month::January, 1),
+            local: PrimitiveDateTime::new(
+                date(2020, Month::January, 1),
+                Time::from_hms(11, 59, 59).unwrap(),
+            ),
+        };
+
+        assert_eq!(
+            serde_json::to_value(s.validate().unwrap_err()).unwrap(),
+            json!({
+                "errors": [],
+                "properties": {
+                    "created_at": {
+                        "errors": ["The date must be `>= 2020-01-01T00:00:00Z`."]
+                    },
+                    "birthday": {
+                        "errors": ["The date must be `< 2030-01-01`."]
+                    },
+                    "local": {
+                        "errors": ["The date must be `>= 2020-01-01T12:00:00`."]
+                    }
+                }
+            })
+        );
+    }
+
+    #[test]
+    fn time_relative_time() {
+        use serde_valid::clock::{with_clock, FixedClock};
+
+        #[derive(Debug, Validate)]
+        struct TestStruct {
+            #[validate(past)]
+            birthday: Date,
+            #[validate(future)]
+            #[validate(within = "1h")]
+            expires_at: OffsetDateTime,
+        }
+
+        let now = PrimitiveDateTime::new(
+            date(2024, Month::June, 1),
+            Time::from_hms(12, 0, 0).unwrap(),
+        )
+        .assume_utc();
+        let s = TestStruct {
+            birthday: date(2024, Month::May, 31),
+            expires_at: now - time::Duration::minutes(1),
+        };
+
+        with_clock(FixedClock::new(now.into()), || {
+            assert_eq!(
+                serde_json::to_value(s.validate().unwrap_err()).unwrap(),
+                json!({
+                    "errors": [],
+                    "properties": {
+                        "expires_at": {
+                            "errors": ["The date must be in the future."]
+                        }
+                    }
+                })
+            );
+        });
+    }
+
+    #[test]
+    fn time_older_than() {
+        use serde_valid::clock::{with_clock, FixedClock};
+
+        #[derive(Debug, Validate)]
+        struct TestStruct {
+            #[validate(older_than = "18y")]
+            birthdate: Date,
+        }
+
+        let now =
+            PrimitiveDateTime::new(date(2024, Month::June, 1), Time::from_hms(0, 0, 0).unwrap())
+                .assume_utc();
+
+        with_clock(FixedClock::new(now.into()), || {
+            assert!(TestStruct {
+                birthdate: date(2006, Month::June, 1)
+            }
+            .validate()
+            .is_ok());
+            assert!(TestStruct {
+                birthdate: date(2006, Month::June, 2)
+            }
+            .validate()
+            .is_err());
+        });
+    }
+}
@@ -0,0 +1,190 @@
+#[cfg(feature = "urlencoded")]
+mod tests {
+    use serde::{Deserialize, Serialize};
+    use serde_json::json;
+    use serde_valid::urlencoded::{FromFormSlice, FromQueryStr, ToQueryString};
+    use serde_valid::Validate;
+
+    #[derive(Debug, Deserialize, Serialize, Validate)]
+    struct TestStruct {
+        #[validate(min_length = 1)]
+        name: String,
+        #[validate(max_items = 2)]
+        #[validate(unique_items)]
+        tags: Vec<String>,
+        #[validate]
+        page: Page,
+    }
+
+    #[derive(Debug, Deserialize, Serialize, Validate)]
+    struct Page {
+        #[validate(minimum = 1)]
+        number: u32,
+        #[validate(maximum = 100)]
+        size: u32,
+    }
+
+    #[test]
+    fn query_str_is_ok() {
+        let s = TestStruct::from_query_str("name=taro&tags=a&tags=b&page[number]=1&page[size]=10")
+            .unwrap();
+
+        assert_eq!(s.name, "taro");
+        assert_eq!(s.tags, vec!["a", "b"]);
+        assert_eq!(s.page.size, 10);
+    }
+
+    #[test]
+    fn query_str_indexed_and_bracket_arrays_is_ok() {
+        for query in [
+            "?name=taro&tags[0]=a&tags[1]=b&page[number]=1&page[size]=10",
+            "?name=taro&tags[]=a&tags[]=b&page[number]=1&page[size]=10",
+        ] {
+            assert_eq!(
+                TestStruct::from_query_str(query).unwrap().tags,
+                vec!["a", "b"]
+            );
+        }
+    }
+
+    #[test]
+    fn query_str_single_value_array_is_ok() {
+        #[derive(Debug, Deserialize, Validate)]
+        struct TestStruct {
+            tags: Vec<String>,
+            ids: Option<Vec<u32>>,
+            name: String,
+        }
+
+        let s = TestStruct::from_query_str("tags=a&ids=1&name=taro").unwrap();
+
+        assert_eq!(s.tags, vec!["a"]);
+        assert_eq!(s.ids, Some(vec![1]));
+        assert_eq!(s.name, "taro");
+    }
+
+    #[test]
+    fn query_str_percent_encoded_keys_are_grouped() {
+        #[derive(Debug, Deserialize, Validate)]
+        struct TestStruct {
+            tags: Vec<String>,
+            ids: Vec<u32>,
+        }
+
+        let s = TestStruct::from_query_str("tags%5B%5D=a&tags[]=b&ids=1&%69ds=2").unwrap();
+
+        assert_eq!(s.tags, vec!["a", "b"]);
+        assert_eq!(s.ids, vec![1, 2]);
+    }
+
+    #[test]
+    fn query_str_with_config() {
+        let query = "name=taro&tags=a&page%5Bnumber%5D=1&page%5Bsize%5D=10";
+
+        assert!(TestStruct::from_query_str(query).is_ok());
+        assert!(
+            TestStruct::from_query_str_with_config(query, &serde_qs::Config::new(5, true))
+                .unwrap_err()
+                .is_serde_error()
+        );
+    }
+
+    #[test]
+    fn query_str_validation_error() {
+        let err =
+            TestStruct::from_query_str("name=&tags=a&tags=a&tags=b&page[number]=0&page[size]=10")
+                .unwrap_err();
+
+        assert_eq!(
+            serde_json::to_value(err.as_validation_errors().unwrap()).unwrap(),
+            json!({
+                "errors": [],
+                "properties": {
+                    "name": {
+                        "errors": ["The length of the value must be `>= 1`."]
+                    },
+                    "tags": {
+                        "errors": [
+                            "The length of the items must be `<= 2`.",
+                            "The items must be unique."
+                        ]
+                    },
+                    "page": {
+                        "errors": [],
+                        "properties": {
+                            "number": {
+                                "errors": ["The number must be `>= 1`."]
+                            }
+                        }
+                    }
+                }
+            })
+        );
+    }
+
+    #[test]
+    fn query_str_deserialize_error() {
+        let err = TestStruct::from_query_str("name=taro&page[number]=x").unwrap_err();
+
+        assert!(err.is_serde_error());
+    }
+
+    #[test]
+    fn form_slice_validation_error() {
+        let err = TestStruct::from_form_slice(
+            b"name=Taro+Yamada&tags=a&tags=b&page%5Bnumber%5D=1&page[size]=101",
+        )
+        .unwrap_err();
+
+        assert_eq!(
+            serde_json::to_value(err.as_validation_errors().unwrap()).unwrap(),
+            json!({
+                "errors": [],
+                "properties": {
+                    "page": {
+                        "errors": [],
+                        "properties": {
+                            "size": {
+                                "errors": ["The number must be `<= 100`."]
+                            }
+                        }
+                    }
+                }
+            })
+        );
+    }
+
+    #[test]
+    fn query_string_round_trip() {
+        let s = TestStruct {
+            name: "Taro Yamada".to_string(),
+            tags: vec!["a".to_string(), "b&c".to_string()],
+            page: Page {
+                number: 1,
+                size: 10,
+            },
+        };
+        let query = s.to_query_string_validated().unwrap();
+        let s = TestStruct::from_query_str(&query).unwrap();
+
+        assert_eq!(s.name, "Taro Yamada");
+        assert_eq!(s.tags, vec!["a", "b&c"]);
+    }
+
+    #[test]
+    fn query_string_validated_error() {
+        let s = TestStruct {
+            name: "".to_string(),
+            tags: vec![],
+            page: Page {
+                number: 1,
+                size: 10,
+            },
+        };
+
+        assert!(s
+            .to_query_string_validated()
+            .unwrap_err()
+            .is_validation_errors());
+    }
+}
@@ -0,0 +1,137 @@
+#[cfg(feature = "xml")]
+mod tests {
+    use serde::{Deserialize, Serialize};
+    use serde_json::json;
+    use serde_valid::xml::{FromXmlReader, FromXmlStr, ToXmlString};
+    use serde_valid::Validate;
+
+    #[derive(Debug, Deserialize, Serialize, Validate)]
+    #[serde(rename = "order")]
+    struct Order {
+        #[serde(rename = "@id")]
+        #[validate(min_length = 1)]
+        id: String,
+        #[validate]
+        customer: Customer,
+        #[serde(rename = "item")]
+        #[validate(min_items = 1)]
+        #[validate]
+        items: Vec<Item>,
+    }
+
+    #[derive(Debug, Deserialize, Serialize, Validate)]
+    struct Customer {
+        #[serde(rename = "$text")]
+        #[validate(max_length = 8)]
+        name: String,
+    }
+
+    #[derive(Debug, Deserialize, Serialize, Validate)]
+    struct Item {
+        #[serde(rename = "@quantity")]
+        #[validate(minimum = 1)]
+        quantity: u32,
+        #[serde(rename = "$value")]
+        #[validate(pattern = r"^[A-Z]+-[0-9]+$")]
+        sku: String,
+    }
+
+    const SOURCE: &str = r#"<order id="o-1">
+        <customer>Taro</customer>
+        <item quantity="1">A-1</item>
+        <item quantity="2">B-2</item>
+    </order>"#;
+
+    #[test]
+    fn xml_str_is_ok() {
+        let order = Order::from_xml_str(SOURCE).unwrap();
+
+        assert_eq!(order.id, "o-1");
+        assert_eq!(order.customer.name, "Taro");
+        assert_eq!(order.items[1].sku, "B-2");
+        assert!(Order::from_xml_reader(SOURCE.as_bytes()).is_ok());
+    }
+
+    #[test]
+    fn xml_errors_use_element_and_attribute_names() {
+        let err = Order::from_xml_str(
+            r#"<order id="">
+                <customer>Taro Yamada</customer>
+                <item quantity="1">A-1</item>
+                <item quantity="0">b2</item>
+            </order>"#,
+        )
+        .unwrap_err();
+
+        assert_eq!(
+            serde_json::to_value(err.as_validation_errors().unwrap()).unwrap(),
+            json!({
+                "errors": [],
+                "properties": {
+                    "@id": {
+                        "errors": ["The length of the value must be `>= 1`."]
+                    },
+                    "customer": {
+                        "errors": [],
+                        "properties": {
+                            "$text": {
+                                "errors": ["The length of the value must be `<= 8`."]
+                            }
+                        }
+                    },
+                    "item": {
+                        "errors": [],
+                        "items": {
+                            "1": {
+                                "errors": [],
+                                "properties": {
+                                    "@quantity": {
+                                        "errors": ["The number must be `>= 1`."]
+                                    },
+                                    "$value": {
+                                        "errors": ["The value must match the pattern of \"^[A-Z]+-[0-9]+$\"."]
+                                    }
+                                }
+                            }
+                        }
+                    }
+                }
+            })
+        );
+    }
+
+    #[test]
+    fn xml_deserialize_error() {
+        let err = Order::from_xml_str(r#"<order id="o-1"><customer>Taro</customer>"#).unwrap_err();
+
+        assert!(err.is_serde_error());
+    }
+
+    #[test]
+    fn xml_string_round_trip() {
+        let order = Order::from_xml_str(SOURCE).unwrap();
+        let xml = order.to_xml_string_validated().unwrap();
+
+        assert_eq!(
+            xml,
+            r#"<order id="o-1"><customer>Taro</customer><item quantity="1">A-1</item><item quantity="2">B-2</item></order>"#
+        );
+        assert!(Order::from_xml_str(&xml).is_ok());
+    }
+
+    #[test]
+    fn xml_string_validated_error() {
+        let order = Order {
+            id: "o-1".to_string(),
+            customer: Customer {
+                name: "Taro".to_string(),
+            },
+            items: vec![],
+        };
+
+        assert!(order
+            .to_xml_string_validated()
+            .unwrap_err()
+            .is_validation_errors());
+    }
+}
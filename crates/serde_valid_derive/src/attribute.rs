@@ -95,6 +95,8 @@ enum_str! {
 enum_str! {
     pub enum MetaPathFieldValidation {
         UniqueItems = "unique_items",
+        Past = "past",
+        Future = "future",
     }
 }
 
@@ -118,12 +120,21 @@ enum_str! {
         MaxProperties = "max_properties",
         MultipleOf = "multiple_of",
         Pattern = "pattern",
+        Within = "within",
+        OlderThan = "older_than",
         Enum = "r#enum",
         Enumerate = "enumerate",
         Custom = "custom",
     }
 }
 
+enum_str! {
+    pub enum Severity {
+        Error = "error",
+        Warning = "warning",
+    }
+}
+
 enum_str! {
     pub enum MetaPathCustomMessage {
     }
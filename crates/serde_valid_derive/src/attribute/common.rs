@@ -1,2 +1,3 @@
 pub mod lit;
 pub mod message_format;
+pub mod severity;
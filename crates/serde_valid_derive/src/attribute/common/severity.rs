@@ -0,0 +1,18 @@
+use crate::attribute::Severity;
+use std::str::FromStr;
+
+use super::lit::{get_lit, get_str};
+
+pub fn is_severity_meta(meta: &syn::Meta) -> bool {
+    meta.path().is_ident("severity")
+}
+
+pub fn extract_severity(meta: &syn::Meta) -> Result<Severity, crate::Errors> {
+    let syn::Meta::NameValue(name_value) = meta else {
+        return Err(vec![crate::Error::severity_need_value(meta)]);
+    };
+    let severity = get_str(get_lit(&name_value.value)?)?;
+
+    Severity::from_str(&severity.value())
+        .map_err(|_| vec![crate::Error::unknown_severity(severity)])
+}
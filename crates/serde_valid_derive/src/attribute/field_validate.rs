@@ -4,6 +4,7 @@ mod generic;
 mod meta;
 mod numeric;
 mod object;
+mod relative_time;
 mod string;
 
 pub use field::FieldValidators;
@@ -13,24 +13,37 @@ pub fn extract_generic_validate_validator(
     let field_key = field.key();
     let rename = rename_map.get(field_name).unwrap_or(&field_key);
     let errors = field.errors_variable();
+    let warnings = field.warnings_variable();
 
-    Ok(WithWarnings::new(quote!(
-        if let Err(__inner_errors) = #field_ident.validate() {
+    let push_errors = |errors_variable: &proc_macro2::TokenStream| {
+        quote!(
             match __inner_errors {
                 ::serde_valid::validation::Errors::Object(__object_errors) => {
-                    #errors.entry(#rename).or_default().push(
+                    #errors_variable.entry(#rename).or_default().push(
                         ::serde_valid::validation::Error::Properties(__object_errors)
                     );
                 }
                 ::serde_valid::validation::Errors::Array(__array_errors) => {
-                    #errors.entry(#rename).or_default().push(
+                    #errors_variable.entry(#rename).or_default().push(
                         ::serde_valid::validation::Error::Items(__array_errors)
                     );
                 }
                 ::serde_valid::validation::Errors::NewType(__new_type_errors) => {
-                    #errors.entry(#rename).or_default().extend(__new_type_errors);
+                    #errors_variable.entry(#rename).or_default().extend(__new_type_errors);
                 }
             }
+        )
+    };
+    let push_inner_errors = push_errors(&errors);
+    let push_inner_warnings = push_errors(&warnings);
+
+    Ok(WithWarnings::new(quote!(
+        let __inner_report = #field_ident.validate_report();
+        if let Some(__inner_errors) = __inner_report.errors {
+            #push_inner_errors
+        }
+        if let Some(__inner_errors) = __inner_report.warnings {
+            #push_inner_warnings
         }
     )))
 }
@@ -3,14 +3,16 @@ mod meta_name_value;
 mod meta_path;
 
 use crate::attribute::common::message_format::{
-    default_message_format, extract_custom_message_format,
+    default_message_format, extract_custom_message_format, MessageFormat,
 };
+use crate::attribute::common::severity::{extract_severity, is_severity_meta};
 use crate::attribute::{
-    MetaListFieldValidation, MetaNameValueFieldValidation, MetaPathFieldValidation, Validator,
+    MetaListFieldValidation, MetaNameValueFieldValidation, MetaPathFieldValidation, Severity,
+    Validator,
 };
 use crate::serde::rename::RenameMap;
-use crate::types::Field;
 use crate::types::SingleIdentPath;
+use crate::types::{Field, WarningField};
 use crate::warning::WithWarnings;
 use meta_list::extract_field_validator_from_meta_list;
 use meta_name_value::extract_field_validator_from_meta_name_value;
@@ -50,19 +52,45 @@ fn inner_extract_field_validator(
             )]
         })?;
 
+    if nested.is_empty() {
+        Err(vec![crate::Error::field_validation_type_required(
+            attribute,
+        )])?
+    }
+
+    let (severity_metas, custom_message_metas): (Vec<_>, Vec<_>) = nested
+        .iter()
+        .skip(1)
+        .partition(|meta| is_severity_meta(meta));
+
+    let severity = match severity_metas.as_slice() {
+        [] => Severity::Error,
+        [meta, tails @ ..] => {
+            for tail in tails {
+                errors.push(crate::Error::too_many_list_items(tail));
+            }
+            match extract_severity(meta) {
+                Ok(severity) => severity,
+                Err(severity_errors) => {
+                    errors.extend(severity_errors);
+                    Severity::Error
+                }
+            }
+        }
+    };
+
     let WithWarnings {
         data: message_format,
         mut warnings,
-    } = match nested.len() {
-        0 => Err(vec![crate::Error::field_validation_type_required(
-            attribute,
-        )])?,
-        1 => None,
-        2 => match extract_custom_message_format(&nested[1]) {
+    } = match custom_message_metas.as_slice() {
+        [] => None,
+        [custom_message_meta] => match extract_custom_message_format(custom_message_meta) {
             Ok(custom_message) => {
                 if nested[0].path().is_ident("custom") {
                     errors.push(
-                        crate::Error::validate_custom_does_not_support_custom_message(&nested[1]),
+                        crate::Error::validate_custom_does_not_support_custom_message(
+                            custom_message_meta,
+                        ),
                     );
                     None
                 } else {
@@ -74,8 +102,8 @@ fn inner_extract_field_validator(
                 None
             }
         },
-        _ => {
-            for meta in nested.iter().skip(2) {
+        [_, tails @ ..] => {
+            for meta in tails {
                 errors.push(crate::Error::too_many_list_items(meta));
             }
             None
@@ -96,10 +124,59 @@ fn inner_extract_field_validator(
         .ident()
         .to_string();
 
-    let validator = match (
-        MetaPathFieldValidation::from_str(&validation_name),
-        MetaListFieldValidation::from_str(&validation_name),
-        MetaNameValueFieldValidation::from_str(&validation_name),
+    let validator = match severity {
+        Severity::Error => extract_field_validator_by_name(
+            field,
+            &validation_name,
+            validation_path,
+            meta,
+            message_format,
+            rename_map,
+        ),
+        Severity::Warning => extract_field_validator_by_name(
+            &WarningField::new(field),
+            &validation_name,
+            validation_path,
+            meta,
+            message_format,
+            rename_map,
+        ),
+    }
+    .map(|validator| {
+        warnings.extend(validator.warnings);
+        validator.data
+    });
+
+    match validator {
+        Ok(validator) => {
+            if errors.is_empty() {
+                Ok(WithWarnings {
+                    data: validator,
+                    warnings,
+                })
+            } else {
+                Err(errors)
+            }
+        }
+        Err(validator_errors) => {
+            errors.extend(validator_errors);
+            Err(errors)
+        }
+    }
+}
+
+fn extract_field_validator_by_name(
+    field: &impl Field,
+    validation_name: &str,
+    validation_path: &syn::Path,
+    meta: &syn::Meta,
+    message_format: MessageFormat,
+    rename_map: &RenameMap,
+) -> Result<WithWarnings<Validator>, crate::Errors> {
+    match (
+        MetaPathFieldValidation::from_str(validation_name),
+        MetaListFieldValidation::from_str(validation_name),
+        MetaNameValueFieldValidation::from_str(validation_name),
         meta,
     ) {
         (Ok(validation_type), _, _, syn::Meta::Path(validation)) => {
@@ -134,43 +211,22 @@ fn inner_extract_field_validator(
 
         (Ok(_), _, _, _) => Err(vec![crate::Error::meta_path_validation_need_value(
             validation_path,
-            &validation_name,
+            validation_name,
         )]),
 
         (_, Ok(_), _, _) => Err(vec![crate::Error::meta_list_validation_need_value(
             validation_path,
-            &validation_name,
+            validation_name,
         )]),
 
         (_, _, Ok(_), _) => Err(vec![crate::Error::meta_name_value_validation_need_value(
             validation_path,
-            &validation_name,
+            validation_name,
         )]),
 
         _ => Err(vec![crate::Error::field_validation_type_unknown(
             validation_path,
-            &validation_name,
+            validation_name,
         )]),
     }
-    .map(|validator| {
-        warnings.extend(validator.warnings);
-        validator.data
-    });
-
-    match validator {
-        Ok(validator) => {
-            if errors.is_empty() {
-                Ok(WithWarnings {
-                    data: validator,
-                    warnings,
-                })
-            } else {
-                Err(errors)
-            }
-        }
-        Err(validator_errors) => {
-            errors.extend(validator_errors);
-            Err(errors)
-        }
-    }
 }
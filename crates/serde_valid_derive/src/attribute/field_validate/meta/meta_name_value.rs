@@ -17,6 +17,9 @@ use crate::attribute::field_validate::numeric::{
 use crate::attribute::field_validate::object::{
     extract_object_max_properties_validator, extract_object_min_properties_validator,
 };
+use crate::attribute::field_validate::relative_time::{
+    extract_relative_time_older_than_validator, extract_relative_time_within_validator,
+};
 use crate::attribute::field_validate::string::{
     extract_string_max_length_validator, extract_string_min_length_validator,
     extract_string_pattern_validator,
@@ -115,6 +118,20 @@ pub fn extract_field_validator_from_meta_name_value(
             extract_string_pattern_validator(field, validation_value, message_format, rename_map)
                 .map(WithWarnings::new)
         }
+        MetaNameValueFieldValidation::Within => extract_relative_time_within_validator(
+            field,
+            &validation.value,
+            message_format,
+            rename_map,
+        )
+        .map(WithWarnings::new),
+        MetaNameValueFieldValidation::OlderThan => extract_relative_time_older_than_validator(
+            field,
+            &validation.value,
+            message_format,
+            rename_map,
+        )
+        .map(WithWarnings::new),
         MetaNameValueFieldValidation::Enum => extract_generic_enum_validator_from_name_value(
             field,
             validation,
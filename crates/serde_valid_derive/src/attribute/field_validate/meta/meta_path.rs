@@ -1,5 +1,8 @@
 use crate::attribute::common::message_format::MessageFormat;
 use crate::attribute::field_validate::array::extract_array_unique_items_validator;
+use crate::attribute::field_validate::relative_time::{
+    extract_relative_time_future_validator, extract_relative_time_past_validator,
+};
 use crate::attribute::{MetaPathFieldValidation, Validator};
 use crate::serde::rename::RenameMap;
 use crate::types::Field;
@@ -16,5 +19,11 @@ pub fn extract_field_validator_from_meta_path(
         MetaPathFieldValidation::UniqueItems => Ok(WithWarnings::new(
             extract_array_unique_items_validator(field, message_format, rename_map),
         )),
+        MetaPathFieldValidation::Past => Ok(WithWarnings::new(
+            extract_relative_time_past_validator(field, message_format, rename_map),
+        )),
+        MetaPathFieldValidation::Future => Ok(WithWarnings::new(
+            extract_relative_time_future_validator(field, message_format, rename_map),
+        )),
     }
 }
@@ -4,6 +4,65 @@ use crate::serde::rename::RenameMap;
 use crate::types::Field;
 use proc_macro2::TokenStream;
 use quote::quote;
+use serde_valid_literal::{Date, DateTime, NaiveDateTime};
+
+/// Limit of the range validation.
+///
+/// The str literal is the date and time of RFC 3339 for the `chrono` and `time` features,
+/// which is parsed here to be checked at compile time.
+fn range_limit(validation_value: &syn::Expr) -> Result<TokenStream, crate::Errors> {
+    let syn::Expr::Lit(syn::ExprLit {
+        lit: syn::Lit::Str(lit),
+        ..
+    }) = validation_value
+    else {
+        return Ok(quote!(#validation_value));
+    };
+    let value = lit.value();
+
+    if let Ok(date_time) = value.parse::<DateTime>() {
+        let naive = naive_date_time_tokens(&date_time.naive());
+        let offset_seconds = date_time.offset_seconds();
+        return Ok(quote!(
+            ::serde_valid::validation::DateTime::new_unchecked(#naive, #offset_seconds)
+        ));
+    }
+    if let Ok(date_time) = value.parse::<NaiveDateTime>() {
+        return Ok(naive_date_time_tokens(&date_time));
+    }
+    if let Ok(date) = value.parse::<Date>() {
+        return Ok(date_tokens(&date));
+    }
+
+    // the error of the longest format which the literal looks like.
+    let error = match value.parse::<DateTime>() {
+        Err(error) if value.len() > "2020-01-01".len() => error,
+        _ => value.parse::<Date>().unwrap_err(),
+    };
+    Err(vec![crate::Error::date_time_literal_parse_error(
+        lit, &error,
+    )])
+}
+
+fn date_tokens(date: &Date) -> TokenStream {
+    let (year, month, day) = (date.year(), date.month(), date.day());
+    quote!(::serde_valid::validation::Date::new_unchecked(#year, #month, #day))
+}
+
+fn naive_date_time_tokens(date_time: &NaiveDateTime) -> TokenStream {
+    let date = date_tokens(&date_time.date());
+    let (hour, minute, second, nanosecond) = (
+        date_time.hour(),
+        date_time.minute(),
+        date_time.second(),
+        date_time.nanosecond(),
+    );
+    quote!(
+        ::serde_valid::validation::NaiveDateTime::new_unchecked(
+            #date, #hour, #minute, #second, #nanosecond
+        )
+    )
+}
 
 /// Range validation.
 ///
@@ -35,6 +94,7 @@ macro_rules! extract_numeric_range_validator{
             let field_key = field.key();
             let rename = rename_map.get(field_name).unwrap_or(&field_key);
             let errors = field.errors_variable();
+            let validation_value = range_limit(validation_value)?;
 
             Ok(quote!(
                 if let Err(__composited_error_params) = ::serde_valid::validation::$ValidateCompositedTrait::$validate_composited_method(
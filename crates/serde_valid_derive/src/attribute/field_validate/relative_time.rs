@@ -0,0 +1,9 @@
+mod older_than;
+mod past_future;
+mod within;
+
+pub use older_than::extract_relative_time_older_than_validator;
+pub use past_future::{
+    extract_relative_time_future_validator, extract_relative_time_past_validator,
+};
+pub use within::extract_relative_time_within_validator;
@@ -0,0 +1,81 @@
+use crate::attribute::common::message_format::MessageFormat;
+use crate::attribute::Validator;
+use crate::serde::rename::RenameMap;
+use crate::types::Field;
+use proc_macro2::TokenStream;
+use quote::quote;
+use serde_valid_literal::Period;
+
+/// Older than validation of the dates and times, like the birthdate of the age over 18 years,
+/// comparing with the current time of `::serde_valid::clock::now()`.
+///
+/// The str literal like `"18y"` is parsed here to be checked at compile time,
+/// and the other expressions are converted into `::serde_valid::validation::Period`.
+pub fn extract_relative_time_older_than_validator(
+    field: &impl Field,
+    validation_value: &syn::Expr,
+    message_format: MessageFormat,
+    rename_map: &RenameMap,
+) -> Result<Validator, crate::Errors> {
+    inner_extract_relative_time_older_than_validator(
+        field,
+        validation_value,
+        message_format,
+        rename_map,
+    )
+}
+
+fn inner_extract_relative_time_older_than_validator(
+    field: &impl Field,
+    validation_value: &syn::Expr,
+    message_format: MessageFormat,
+    rename_map: &RenameMap,
+) -> Result<TokenStream, crate::Errors> {
+    let field_name = field.name();
+    let field_ident = field.ident();
+    let field_key = field.key();
+    let rename = rename_map.get(field_name).unwrap_or(&field_key);
+    let errors = field.errors_variable();
+    let older_than = older_than_limit(validation_value)?;
+
+    Ok(quote!(
+        if let Err(__composited_error_params) = ::serde_valid::validation::ValidateCompositedOlderThan::validate_composited_older_than(
+            #field_ident,
+            ::serde_valid::clock::now(),
+            #older_than,
+        ) {
+            use ::serde_valid::validation::IntoError;
+
+            #errors
+                .entry(#rename)
+                .or_default()
+                .push(__composited_error_params.into_error_by(#message_format));
+        }
+    ))
+}
+
+fn older_than_limit(validation_value: &syn::Expr) -> Result<TokenStream, crate::Errors> {
+    let syn::Expr::Lit(syn::ExprLit {
+        lit: syn::Lit::Str(lit),
+        ..
+    }) = validation_value
+    else {
+        return Ok(quote!(::serde_valid::validation::Period::from(#validation_value)));
+    };
+
+    let period = lit
+        .value()
+        .parse::<Period>()
+        .map_err(|error| vec![crate::Error::period_literal_parse_error(lit, &error)])?;
+    let months = period.months();
+    let (seconds, nanoseconds) = (
+        period.duration().as_secs(),
+        period.duration().subsec_nanos(),
+    );
+    Ok(quote!(
+        ::serde_valid::validation::Period::new(
+            #months,
+            ::std::time::Duration::new(#seconds, #nanoseconds),
+        )
+    ))
+}
@@ -0,0 +1,64 @@
+use crate::attribute::common::message_format::MessageFormat;
+use crate::attribute::Validator;
+use crate::serde::rename::RenameMap;
+use crate::types::Field;
+use proc_macro2::TokenStream;
+use quote::quote;
+
+/// Past and future validations of the dates and times,
+/// comparing with the current time of `::serde_valid::clock::now()`.
+macro_rules! extract_relative_time_validator {
+    (
+        $extract_validator:ident,
+        $inner_extract_validator:ident,
+        $ValidateCompositedTrait:ident,
+        $validate_composited_method:ident
+    ) => {
+        pub fn $extract_validator(
+            field: &impl Field,
+            message_format: MessageFormat,
+            rename_map: &RenameMap,
+        ) -> Validator {
+            $inner_extract_validator(field, message_format, rename_map)
+        }
+
+        fn $inner_extract_validator(
+            field: &impl Field,
+            message_format: MessageFormat,
+            rename_map: &RenameMap,
+        ) -> TokenStream {
+            let field_name = field.name();
+            let field_ident = field.ident();
+            let field_key = field.key();
+            let rename = rename_map.get(field_name).unwrap_or(&field_key);
+            let errors = field.errors_variable();
+
+            quote!(
+                if let Err(__composited_error_params) = ::serde_valid::validation::$ValidateCompositedTrait::$validate_composited_method(
+                    #field_ident,
+                    ::serde_valid::clock::now(),
+                ) {
+                    use ::serde_valid::validation::IntoError;
+
+                    #errors
+                        .entry(#rename)
+                        .or_default()
+                        .push(__composited_error_params.into_error_by(#message_format));
+                }
+            )
+        }
+    };
+}
+
+extract_relative_time_validator!(
+    extract_relative_time_past_validator,
+    inner_extract_relative_time_past_validator,
+    ValidateCompositedPast,
+    validate_composited_past
+);
+extract_relative_time_validator!(
+    extract_relative_time_future_validator,
+    inner_extract_relative_time_future_validator,
+    ValidateCompositedFuture,
+    validate_composited_future
+);
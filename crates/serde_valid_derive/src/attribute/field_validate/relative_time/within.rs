@@ -0,0 +1,73 @@
+use crate::attribute::common::message_format::MessageFormat;
+use crate::attribute::Validator;
+use crate::serde::rename::RenameMap;
+use crate::types::Field;
+use proc_macro2::TokenStream;
+use quote::quote;
+use serde_valid_literal::Duration;
+
+/// Within validation of the dates and times,
+/// comparing with the current time of `::serde_valid::clock::now()`.
+///
+/// The str literal like `"30d"` is parsed here to be checked at compile time,
+/// and the other expressions are used as `std::time::Duration` as they are.
+pub fn extract_relative_time_within_validator(
+    field: &impl Field,
+    validation_value: &syn::Expr,
+    message_format: MessageFormat,
+    rename_map: &RenameMap,
+) -> Result<Validator, crate::Errors> {
+    inner_extract_relative_time_within_validator(
+        field,
+        validation_value,
+        message_format,
+        rename_map,
+    )
+}
+
+fn inner_extract_relative_time_within_validator(
+    field: &impl Field,
+    validation_value: &syn::Expr,
+    message_format: MessageFormat,
+    rename_map: &RenameMap,
+) -> Result<TokenStream, crate::Errors> {
+    let field_name = field.name();
+    let field_ident = field.ident();
+    let field_key = field.key();
+    let rename = rename_map.get(field_name).unwrap_or(&field_key);
+    let errors = field.errors_variable();
+    let within = within_limit(validation_value)?;
+
+    Ok(quote!(
+        if let Err(__composited_error_params) = ::serde_valid::validation::ValidateCompositedWithin::validate_composited_within(
+            #field_ident,
+            ::serde_valid::clock::now(),
+            #within,
+        ) {
+            use ::serde_valid::validation::IntoError;
+
+            #errors
+                .entry(#rename)
+                .or_default()
+                .push(__composited_error_params.into_error_by(#message_format));
+        }
+    ))
+}
+
+fn within_limit(validation_value: &syn::Expr) -> Result<TokenStream, crate::Errors> {
+    let syn::Expr::Lit(syn::ExprLit {
+        lit: syn::Lit::Str(lit),
+        ..
+    }) = validation_value
+    else {
+        return Ok(quote!(#validation_value));
+    };
+
+    let duration = lit
+        .value()
+        .parse::<Duration>()
+        .map_err(|error| vec![crate::Error::duration_literal_parse_error(lit, &error)])?
+        .as_std();
+    let (seconds, nanoseconds) = (duration.as_secs(), duration.subsec_nanos());
+    Ok(quote!(::std::time::Duration::new(#seconds, #nanoseconds)))
+}
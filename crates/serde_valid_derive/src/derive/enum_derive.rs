@@ -72,10 +72,14 @@ pub fn expand_enum_validate_derive(
         Ok(quote!(
             impl #impl_generics ::serde_valid::Validate for #ident #type_generics #where_clause {
                 fn validate(&self) -> std::result::Result<(), ::serde_valid::validation::Errors> {
+                    self.validate_report().into_result()
+                }
+
+                fn validate_report(&self) -> ::serde_valid::validation::Report {
                     #( #warnings )*
                     #validations_and_rules
 
-                    Ok(())
+                    ::serde_valid::validation::Report::default()
                 }
             }
         ))
@@ -138,13 +142,28 @@ fn expand_enum_variant_named_fields_validation(
                 if let #ident::#variant_ident{#fields_idents} = &self {
                     let mut __rule_vec_errors = ::serde_valid::validation::VecErrors::new();
                     let mut __property_vec_errors_map = ::serde_valid::validation::PropertyVecErrorsMap::new();
+                    let mut __property_vec_warnings_map = ::serde_valid::validation::PropertyVecErrorsMap::new();
 
                     #validates
                     #enum_validates
 
-                    if !(__rule_vec_errors.is_empty() && __property_vec_errors_map.is_empty()) {
-                        Err(#variant_errors)?
-                    }
+                    let __errors = if __rule_vec_errors.is_empty() && __property_vec_errors_map.is_empty() {
+                        None
+                    } else {
+                        Some(#variant_errors)
+                    };
+                    let __warnings = {
+                        let __rule_vec_errors = ::serde_valid::validation::VecErrors::new();
+                        let __property_vec_errors_map = __property_vec_warnings_map;
+
+                        if __property_vec_errors_map.is_empty() {
+                            None
+                        } else {
+                            Some(#variant_errors)
+                        }
+                    };
+
+                    return ::serde_valid::validation::Report::new(__errors, __warnings);
                 }
             ),
             warnings,
@@ -211,13 +230,29 @@ fn expand_enum_variant_unnamed_fields_varidation(
                 if let #ident::#variant_ident(#fields_idents) = &self {
                     let mut __rule_vec_errors = ::serde_valid::validation::VecErrors::new();
                     let mut __item_vec_errors_map = ::serde_valid::validation::ItemVecErrorsMap::new();
+                    let mut __item_vec_warnings_map = ::serde_valid::validation::ItemVecErrorsMap::new();
 
                     #enum_validates
                     #validates
 
-                    if !(__rule_vec_errors.is_empty() && __item_vec_errors_map.is_empty()) {
-                        Err(#variant_errors)?
-                    }
+                    let __errors = if __rule_vec_errors.is_empty() && __item_vec_errors_map.is_empty() {
+                        None
+                    } else {
+                        Some(#variant_errors)
+                    };
+                    let __warnings = {
+                        let __rule_vec_errors = ::serde_valid::validation::VecErrors::new();
+                        #[allow(unused_mut)]
+                        let mut __item_vec_errors_map = __item_vec_warnings_map;
+
+                        if __item_vec_errors_map.is_empty() {
+                            None
+                        } else {
+                            Some(#variant_errors)
+                        }
+                    };
+
+                    return ::serde_valid::validation::Report::new(__errors, __warnings);
                 }
             ),
             warnings,
@@ -62,17 +62,35 @@ pub fn expand_unnamed_struct_derive(
             #(#warnings)*
             impl #impl_generics ::serde_valid::Validate for #ident #type_generics #where_clause {
                 fn validate(&self) -> std::result::Result<(), ::serde_valid::validation::Errors> {
+                    self.validate_report().into_result()
+                }
+
+                fn validate_report(&self) -> ::serde_valid::validation::Report {
                     let mut __rule_vec_errors = ::serde_valid::validation::VecErrors::new();
                     let mut __item_vec_errors_map = ::serde_valid::validation::ItemVecErrorsMap::new();
+                    let mut __item_vec_warnings_map = ::serde_valid::validation::ItemVecErrorsMap::new();
 
                     #field_validates
                     #struct_validations
 
-                    if __rule_vec_errors.is_empty() && __item_vec_errors_map.is_empty() {
-                        Ok(())
+                    let __errors = if __rule_vec_errors.is_empty() && __item_vec_errors_map.is_empty() {
+                        None
                     } else {
-                        Err(#fields_errors)
-                    }
+                        Some(#fields_errors)
+                    };
+                    let __warnings = {
+                        let __rule_vec_errors = ::serde_valid::validation::VecErrors::new();
+                        #[allow(unused_mut)]
+                        let mut __item_vec_errors_map = __item_vec_warnings_map;
+
+                        if __item_vec_errors_map.is_empty() {
+                            None
+                        } else {
+                            Some(#fields_errors)
+                        }
+                    };
+
+                    ::serde_valid::validation::Report::new(__errors, __warnings)
                 }
             }
         ))
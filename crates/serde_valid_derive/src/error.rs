@@ -1,7 +1,7 @@
 use crate::attribute::{
     MetaListCustomMessage, MetaListFieldValidation, MetaListStructValidation,
     MetaNameValueCustomMessage, MetaNameValueFieldValidation, MetaNameValueStructValidation,
-    MetaPathCustomMessage, MetaPathFieldValidation, MetaPathStructValidation,
+    MetaPathCustomMessage, MetaPathFieldValidation, MetaPathStructValidation, Severity,
 };
 use itertools::Itertools;
 use proc_macro2::TokenStream;
@@ -334,6 +334,25 @@ impl Error {
         )
     }
 
+    pub fn severity_need_value(meta: &syn::Meta) -> Self {
+        Self::new(
+            meta.span(),
+            "#[validate(..., severity = ???)] needs severity value.",
+        )
+    }
+
+    pub fn unknown_severity(lit: &syn::LitStr) -> Self {
+        let candidates = Severity::iter().map(|x| x.name()).collect::<Vec<_>>();
+
+        Self::new(
+            lit.span(),
+            format!(
+                "`{}` is unknown severity. Is it one of the following?\n{candidates:#?}",
+                lit.value()
+            ),
+        )
+    }
+
     pub fn validate_enum_need_array(path: impl Spanned) -> Self {
         Self::new(
             path.span(),
@@ -467,6 +486,42 @@ impl Error {
         Self::new(lit.span(), "Allow str literal only.")
     }
 
+    pub fn date_time_literal_parse_error(
+        lit: &syn::LitStr,
+        error: &serde_valid_literal::ParseDateTimeError,
+    ) -> Self {
+        Self::new(
+            lit.span(),
+            format!(
+                "Invalid date time literal: {error}. Allow RFC 3339 like \"2020-01-01\", \"2020-01-01T00:00:00\" or \"2020-01-01T00:00:00Z\"."
+            ),
+        )
+    }
+
+    pub fn duration_literal_parse_error(
+        lit: &syn::LitStr,
+        error: &serde_valid_literal::ParseDurationError,
+    ) -> Self {
+        Self::new(
+            lit.span(),
+            format!(
+                "Invalid duration literal: {error}. Allow the units of `w`, `d`, `h`, `m`, `s`, `ms`, `us` and `ns` like \"30d\" or \"1h30m\"."
+            ),
+        )
+    }
+
+    pub fn period_literal_parse_error(
+        lit: &syn::LitStr,
+        error: &serde_valid_literal::ParsePeriodError,
+    ) -> Self {
+        Self::new(
+            lit.span(),
+            format!(
+                "Invalid period literal: {error}. Allow the units of `y`, `mo`, `w`, `d`, `h`, `m`, `s`, `ms`, `us` and `ns` like \"18y\" or \"1y6mo\"."
+            ),
+        )
+    }
+
     pub fn too_many_list_items(nested_meta: &syn::Meta) -> Self {
         Self::new(nested_meta.span(), "Too many list items.")
     }
@@ -1,8 +1,10 @@
 mod named;
 mod unnamed;
+mod warning;
 
 pub use named::NamedField;
 pub use unnamed::UnnamedField;
+pub use warning::WarningField;
 
 pub trait Field {
     fn name(&self) -> &String;
@@ -13,6 +15,8 @@ pub trait Field {
 
     fn errors_variable(&self) -> proc_macro2::TokenStream;
 
+    fn warnings_variable(&self) -> proc_macro2::TokenStream;
+
     fn getter_token(&self) -> proc_macro2::TokenStream;
 
     fn attrs(&self) -> &Vec<syn::Attribute>;
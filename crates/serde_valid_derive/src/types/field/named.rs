@@ -38,6 +38,10 @@ impl Field for NamedField<'_> {
         quote!(__property_vec_errors_map)
     }
 
+    fn warnings_variable(&self) -> proc_macro2::TokenStream {
+        quote!(__property_vec_warnings_map)
+    }
+
     fn getter_token(&self) -> proc_macro2::TokenStream {
         let ident = self.ident();
         quote!(#ident)
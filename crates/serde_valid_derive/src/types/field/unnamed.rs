@@ -44,6 +44,10 @@ impl Field for UnnamedField<'_> {
         quote!(__item_vec_errors_map)
     }
 
+    fn warnings_variable(&self) -> proc_macro2::TokenStream {
+        quote!(__item_vec_warnings_map)
+    }
+
     fn getter_token(&self) -> proc_macro2::TokenStream {
         let index = syn::Index::from(self.index);
         quote!(#index)
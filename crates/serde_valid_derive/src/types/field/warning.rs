@@ -0,0 +1,51 @@
+use super::Field;
+
+/// Field whose validation errors are reported as warnings.
+#[derive(Debug, Clone)]
+pub struct WarningField<'a, F: Field> {
+    field: &'a F,
+}
+
+impl<'a, F: Field> WarningField<'a, F> {
+    pub fn new(field: &'a F) -> Self {
+        Self { field }
+    }
+}
+
+impl<F: Field> Field for WarningField<'_, F> {
+    fn name(&self) -> &String {
+        self.field.name()
+    }
+
+    fn ident(&self) -> &syn::Ident {
+        self.field.ident()
+    }
+
+    fn key(&self) -> proc_macro2::TokenStream {
+        self.field.key()
+    }
+
+    fn errors_variable(&self) -> proc_macro2::TokenStream {
+        self.field.warnings_variable()
+    }
+
+    fn warnings_variable(&self) -> proc_macro2::TokenStream {
+        self.field.warnings_variable()
+    }
+
+    fn getter_token(&self) -> proc_macro2::TokenStream {
+        self.field.getter_token()
+    }
+
+    fn attrs(&self) -> &Vec<syn::Attribute> {
+        self.field.attrs()
+    }
+
+    fn vis(&self) -> &syn::Visibility {
+        self.field.vis()
+    }
+
+    fn ty(&self) -> &syn::Type {
+        self.field.ty()
+    }
+}
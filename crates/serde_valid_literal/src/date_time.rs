@@ -0,0 +1,363 @@
+/// Date literal of [RFC 3339](https://datatracker.ietf.org/doc/html/rfc3339), like `2020-01-01`.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Date {
+    year: i32,
+    month: u8,
+    day: u8,
+}
+
+impl Date {
+    /// Create the date without checking it, which is used by the derive macro
+    /// after parsing the literal.
+    ///
+    /// The invalid date fails the range validations instead of panicking.
+    #[doc(hidden)]
+    pub const fn new_unchecked(year: i32, month: u8, day: u8) -> Self {
+        Self { year, month, day }
+    }
+
+    pub fn year(&self) -> i32 {
+        self.year
+    }
+
+    pub fn month(&self) -> u8 {
+        self.month
+    }
+
+    pub fn day(&self) -> u8 {
+        self.day
+    }
+}
+
+impl std::str::FromStr for Date {
+    type Err = ParseDateTimeError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut parser = Parser::new(s);
+        let date = parser.date()?;
+        parser.end()?;
+        Ok(date)
+    }
+}
+
+impl std::fmt::Display for Date {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{:04}-{:02}-{:02}", self.year, self.month, self.day)
+    }
+}
+
+/// Date and time literal without the offset, like `2020-01-01T00:00:00`.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct NaiveDateTime {
+    date: Date,
+    hour: u8,
+    minute: u8,
+    second: u8,
+    nanosecond: u32,
+}
+
+impl NaiveDateTime {
+    /// Create the date and time without checking it, which is used by the derive macro
+    /// after parsing the literal.
+    ///
+    /// The invalid date and time fails the range validations instead of panicking.
+    #[doc(hidden)]
+    pub const fn new_unchecked(
+        date: Date,
+        hour: u8,
+        minute: u8,
+        second: u8,
+        nanosecond: u32,
+    ) -> Self {
+        Self {
+            date,
+            hour,
+            minute,
+            second,
+            nanosecond,
+        }
+    }
+
+    pub fn date(&self) -> Date {
+        self.date
+    }
+
+    pub fn hour(&self) -> u8 {
+        self.hour
+    }
+
+    pub fn minute(&self) -> u8 {
+        self.minute
+    }
+
+    pub fn second(&self) -> u8 {
+        self.second
+    }
+
+    pub fn nanosecond(&self) -> u32 {
+        self.nanosecond
+    }
+}
+
+impl std::str::FromStr for NaiveDateTime {
+    type Err = ParseDateTimeError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut parser = Parser::new(s);
+        let date_time = parser.naive_date_time()?;
+        parser.end()?;
+        Ok(date_time)
+    }
+}
+
+impl std::fmt::Display for NaiveDateTime {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}T{:02}:{:02}:{:02}",
+            self.date, self.hour, self.minute, self.second
+        )?;
+        if self.nanosecond != 0 {
+            let fraction = format!("{:09}", self.nanosecond);
+            write!(f, ".{}", fraction.trim_end_matches('0'))?;
+        }
+        Ok(())
+    }
+}
+
+/// Date and time literal of [RFC 3339](https://datatracker.ietf.org/doc/html/rfc3339) with the offset,
+/// like `2020-01-01T00:00:00Z` or `2020-01-01T09:00:00+09:00`.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct DateTime {
+    naive: NaiveDateTime,
+    offset_seconds: i32,
+}
+
+impl DateTime {
+    /// Create the date and time without checking it, which is used by the derive macro
+    /// after parsing the literal.
+    ///
+    /// The invalid date and time fails the range validations instead of panicking.
+    #[doc(hidden)]
+    pub const fn new_unchecked(naive: NaiveDateTime, offset_seconds: i32) -> Self {
+        Self {
+            naive,
+            offset_seconds,
+        }
+    }
+
+    /// Local date and time in the offset.
+    pub fn naive(&self) -> NaiveDateTime {
+        self.naive
+    }
+
+    /// Offset from UTC in seconds, positive to the east.
+    pub fn offset_seconds(&self) -> i32 {
+        self.offset_seconds
+    }
+}
+
+impl std::str::FromStr for DateTime {
+    type Err = ParseDateTimeError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut parser = Parser::new(s);
+        let naive = parser.naive_date_time()?;
+        let offset_seconds = parser.offset()?;
+        parser.end()?;
+        Ok(Self {
+            naive,
+            offset_seconds,
+        })
+    }
+}
+
+impl std::fmt::Display for DateTime {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.naive)?;
+        match self.offset_seconds {
+            0 => write!(f, "Z"),
+            offset => {
+                let sign = if offset < 0 { '-' } else { '+' };
+                let offset = offset.unsigned_abs() / 60;
+                write!(f, "{sign}{:02}:{:02}", offset / 60, offset % 60)
+            }
+        }
+    }
+}
+
+/// Error of parsing the date and time literal.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseDateTimeError(&'static str);
+
+impl std::fmt::Display for ParseDateTimeError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.0)
+    }
+}
+
+impl std::error::Error for ParseDateTimeError {}
+
+struct Parser<'a> {
+    rest: &'a str,
+}
+
+impl<'a> Parser<'a> {
+    fn new(s: &'a str) -> Self {
+        Self { rest: s }
+    }
+
+    fn date(&mut self) -> Result<Date, ParseDateTimeError> {
+        let year = self.digits(4, "invalid year")? as i32;
+        self.char(&['-'], "expected `-` after the year")?;
+        let month = self.digits(2, "invalid month")? as u8;
+        self.char(&['-'], "expected `-` after the month")?;
+        let day = self.digits(2, "invalid day")? as u8;
+
+        if !(1..=12).contains(&month) {
+            return Err(ParseDateTimeError("month out of range"));
+        }
+        if day < 1 || u32::from(day) > days_in_month(i64::from(year), u32::from(month)) {
+            return Err(ParseDateTimeError("day out of range"));
+        }
+        Ok(Date { year, month, day })
+    }
+
+    fn naive_date_time(&mut self) -> Result<NaiveDateTime, ParseDateTimeError> {
+        let date = self.date()?;
+        self.char(&['T', 't', ' '], "expected `T` after the date")?;
+        let hour = self.digits(2, "invalid hour")? as u8;
+        self.char(&[':'], "expected `:` after the hour")?;
+        let minute = self.digits(2, "invalid minute")? as u8;
+        self.char(&[':'], "expected `:` after the minute")?;
+        let second = self.digits(2, "invalid second")? as u8;
+
+        let mut nanosecond = 0;
+        if self.char(&['.'], "").is_ok() {
+            let length = self.rest.bytes().take_while(u8::is_ascii_digit).count();
+            if length == 0 || length > 9 {
+                return Err(ParseDateTimeError("invalid fraction of the second"));
+            }
+            nanosecond = self.digits(length, "invalid fraction of the second")?
+                * 10u32.pow(9 - length as u32);
+        }
+
+        if hour > 23 {
+            return Err(ParseDateTimeError("hour out of range"));
+        }
+        if minute > 59 {
+            return Err(ParseDateTimeError("minute out of range"));
+        }
+        if second > 59 {
+            return Err(ParseDateTimeError("second out of range"));
+        }
+        Ok(NaiveDateTime {
+            date,
+            hour,
+            minute,
+            second,
+            nanosecond,
+        })
+    }
+
+    fn offset(&mut self) -> Result<i32, ParseDateTimeError> {
+        let sign = match self.char(
+            &['Z', 'z', '+', '-'],
+            "expected the offset like `Z` or `+09:00`",
+        )? {
+            'Z' | 'z' => return Ok(0),
+            '+' => 1,
+            _ => -1,
+        };
+        let hour = self.digits(2, "invalid hour of the offset")? as i32;
+        self.char(&[':'], "expected `:` in the offset")?;
+        let minute = self.digits(2, "invalid minute of the offset")? as i32;
+
+        if hour > 23 || minute > 59 {
+            return Err(ParseDateTimeError("offset out of range"));
+        }
+        Ok(sign * (hour * 3600 + minute * 60))
+    }
+
+    fn end(&self) -> Result<(), ParseDateTimeError> {
+        if self.rest.is_empty() {
+            Ok(())
+        } else {
+            Err(ParseDateTimeError("unexpected trailing characters"))
+        }
+    }
+
+    fn digits(&mut self, length: usize, message: &'static str) -> Result<u32, ParseDateTimeError> {
+        match self.rest.get(..length) {
+            Some(digits) if digits.bytes().all(|byte| byte.is_ascii_digit()) => {
+                self.rest = &self.rest[length..];
+                digits.parse().map_err(|_| ParseDateTimeError(message))
+            }
+            _ => Err(ParseDateTimeError(message)),
+        }
+    }
+
+    fn char(&mut self, chars: &[char], message: &'static str) -> Result<char, ParseDateTimeError> {
+        match self.rest.chars().next() {
+            Some(c) if chars.contains(&c) => {
+                self.rest = &self.rest[c.len_utf8()..];
+                Ok(c)
+            }
+            _ => Err(ParseDateTimeError(message)),
+        }
+    }
+}
+
+/// Days in the month of the proleptic Gregorian calendar, which is shared with `serde_valid`.
+#[doc(hidden)]
+pub fn days_in_month(year: i64, month: u32) -> u32 {
+    match month {
+        2 if year % 4 == 0 && (year % 100 != 0 || year % 400 == 0) => 29,
+        2 => 28,
+        4 | 6 | 9 | 11 => 30,
+        _ => 31,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_date() {
+        let date = "2020-02-29".parse::<Date>().unwrap();
+
+        assert_eq!((date.year(), date.month(), date.day()), (2020, 2, 29));
+        assert!("2021-02-29".parse::<Date>().is_err());
+        assert!("2020-13-01".parse::<Date>().is_err());
+        assert!("2020-1-01".parse::<Date>().is_err());
+    }
+
+    #[test]
+    fn test_parse_date_time() {
+        let date_time = "2020-01-01T09:30:00.25+09:00".parse::<DateTime>().unwrap();
+
+        assert_eq!(date_time.naive().hour(), 9);
+        assert_eq!(date_time.naive().nanosecond(), 250_000_000);
+        assert_eq!(date_time.offset_seconds(), 9 * 3600);
+        assert_eq!(date_time.to_string(), "2020-01-01T09:30:00.25+09:00");
+
+        assert_eq!(
+            "2020-01-01t00:00:00z"
+                .parse::<DateTime>()
+                .unwrap()
+                .to_string(),
+            "2020-01-01T00:00:00Z"
+        );
+        assert!("2020-01-01T00:00:00".parse::<DateTime>().is_err());
+        assert!("2020-01-01T24:00:00Z".parse::<DateTime>().is_err());
+    }
+
+    #[test]
+    fn test_parse_naive_date_time() {
+        let date_time = "2020-01-01T12:34:56".parse::<NaiveDateTime>().unwrap();
+
+        assert_eq!(date_time.to_string(), "2020-01-01T12:34:56");
+        assert!("2020-01-01T12:34:56Z".parse::<NaiveDateTime>().is_err());
+    }
+}
@@ -0,0 +1,157 @@
+/// Duration literal, like `30d`, `1h30m` or `500ms`.
+///
+/// The units are `w`, `d`, `h`, `m`, `s`, `ms`, `us` and `ns`.
+/// Years and months are not supported because their lengths vary, which [`Period`](crate::Period) supports.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Duration(std::time::Duration);
+
+/// Units in the order of matching, where `ms` must be tried before `m`.
+const UNITS: [(&str, u128); 8] = [
+    ("w", 7 * 24 * 3600 * NANOS_PER_SECOND),
+    ("d", 24 * 3600 * NANOS_PER_SECOND),
+    ("h", 3600 * NANOS_PER_SECOND),
+    ("ms", 1_000_000),
+    ("us", 1_000),
+    ("ns", 1),
+    ("m", 60 * NANOS_PER_SECOND),
+    ("s", NANOS_PER_SECOND),
+];
+
+/// Units in the order of displaying, from the largest one except `w`.
+const DISPLAY_UNITS: [(&str, u128); 7] = [
+    ("d", 24 * 3600 * NANOS_PER_SECOND),
+    ("h", 3600 * NANOS_PER_SECOND),
+    ("m", 60 * NANOS_PER_SECOND),
+    ("s", NANOS_PER_SECOND),
+    ("ms", 1_000_000),
+    ("us", 1_000),
+    ("ns", 1),
+];
+
+const NANOS_PER_SECOND: u128 = 1_000_000_000;
+
+impl Duration {
+    pub fn as_std(&self) -> std::time::Duration {
+        self.0
+    }
+}
+
+impl From<std::time::Duration> for Duration {
+    fn from(duration: std::time::Duration) -> Self {
+        Self(duration)
+    }
+}
+
+impl From<Duration> for std::time::Duration {
+    fn from(duration: Duration) -> Self {
+        duration.0
+    }
+}
+
+impl std::str::FromStr for Duration {
+    type Err = ParseDurationError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if s.is_empty() {
+            return Err(ParseDurationError("empty duration"));
+        }
+
+        let mut rest = s;
+        let mut nanos: u128 = 0;
+        while !rest.is_empty() {
+            let digits = rest.bytes().take_while(u8::is_ascii_digit).count();
+            if digits == 0 {
+                return Err(ParseDurationError("expected a number"));
+            }
+            let value = rest[..digits]
+                .parse::<u128>()
+                .map_err(|_| ParseDurationError("number out of range"))?;
+            rest = &rest[digits..];
+
+            let (unit, unit_nanos) = UNITS
+                .iter()
+                .find(|(unit, _)| rest.starts_with(unit))
+                .ok_or(ParseDurationError(
+                    "expected a unit of `w`, `d`, `h`, `m`, `s`, `ms`, `us` or `ns`",
+                ))?;
+            rest = &rest[unit.len()..];
+
+            nanos = value
+                .checked_mul(*unit_nanos)
+                .and_then(|value| nanos.checked_add(value))
+                .ok_or(ParseDurationError("duration out of range"))?;
+        }
+
+        let seconds = u64::try_from(nanos / NANOS_PER_SECOND)
+            .map_err(|_| ParseDurationError("duration out of range"))?;
+        Ok(Self(std::time::Duration::new(
+            seconds,
+            (nanos % NANOS_PER_SECOND) as u32,
+        )))
+    }
+}
+
+impl std::fmt::Display for Duration {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let mut rest = self.0.as_nanos();
+        if rest == 0 {
+            return f.write_str("0s");
+        }
+
+        for (unit, unit_nanos) in DISPLAY_UNITS {
+            if rest >= unit_nanos {
+                write!(f, "{}{unit}", rest / unit_nanos)?;
+                rest %= unit_nanos;
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Error of parsing the duration literal.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseDurationError(pub(crate) &'static str);
+
+impl std::fmt::Display for ParseDurationError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.0)
+    }
+}
+
+impl std::error::Error for ParseDurationError {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_duration() {
+        let duration = "1d12h".parse::<Duration>().unwrap();
+
+        assert_eq!(duration.as_std().as_secs(), 36 * 3600);
+        assert_eq!(
+            "2w".parse::<Duration>().unwrap().as_std().as_secs(),
+            14 * 24 * 3600
+        );
+        assert_eq!(
+            "1m500ms".parse::<Duration>().unwrap().as_std().as_millis(),
+            60_500
+        );
+        assert!("".parse::<Duration>().is_err());
+        assert!("30".parse::<Duration>().is_err());
+        assert!("d".parse::<Duration>().is_err());
+        assert!("1y".parse::<Duration>().is_err());
+    }
+
+    #[test]
+    fn test_display_duration() {
+        assert_eq!("30d".parse::<Duration>().unwrap().to_string(), "30d");
+        assert_eq!("2w".parse::<Duration>().unwrap().to_string(), "14d");
+        assert_eq!("90s".parse::<Duration>().unwrap().to_string(), "1m30s");
+        assert_eq!(
+            "1500us".parse::<Duration>().unwrap().to_string(),
+            "1ms500us"
+        );
+        assert_eq!(Duration::from(std::time::Duration::ZERO).to_string(), "0s");
+    }
+}
@@ -1,7 +1,15 @@
+mod date_time;
+mod duration;
 mod literal;
 mod number;
 mod pattern;
+mod period;
+mod range_limit;
 
+pub use date_time::{days_in_month, Date, DateTime, NaiveDateTime, ParseDateTimeError};
+pub use duration::{Duration, ParseDurationError};
 pub use literal::Literal;
 pub use number::Number;
 pub use pattern::Pattern;
+pub use period::{ParsePeriodError, Period};
+pub use range_limit::RangeLimit;
@@ -0,0 +1,154 @@
+use crate::{Duration, ParseDurationError};
+
+/// Period literal of the calendar, like `18y`, `1y6mo` or `30d`.
+///
+/// The years `y` and the months `mo` follow the calendar,
+/// and the other units are the ones of [`Duration`].
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Period {
+    months: u32,
+    duration: Duration,
+}
+
+impl Period {
+    pub fn new(months: u32, duration: std::time::Duration) -> Self {
+        Self {
+            months,
+            duration: duration.into(),
+        }
+    }
+
+    pub fn years(years: u32) -> Self {
+        Self::new(years.saturating_mul(12), std::time::Duration::ZERO)
+    }
+
+    /// Calendar months of the period, where a year is 12 months.
+    pub fn months(&self) -> u32 {
+        self.months
+    }
+
+    /// Exact duration of the period after the months.
+    pub fn duration(&self) -> std::time::Duration {
+        self.duration.as_std()
+    }
+}
+
+impl From<std::time::Duration> for Period {
+    fn from(duration: std::time::Duration) -> Self {
+        Self::new(0, duration)
+    }
+}
+
+impl std::str::FromStr for Period {
+    type Err = ParsePeriodError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if s.is_empty() {
+            return Err(ParsePeriodError("empty period"));
+        }
+
+        let mut rest = s;
+        let mut months: u32 = 0;
+        let mut duration = String::new();
+        while !rest.is_empty() {
+            let digits = rest.bytes().take_while(u8::is_ascii_digit).count();
+            if digits == 0 {
+                return Err(ParsePeriodError("expected a number"));
+            }
+            let units = rest[digits..]
+                .bytes()
+                .take_while(u8::is_ascii_alphabetic)
+                .count();
+            let (value, unit) = rest[..digits + units].split_at(digits);
+
+            let unit_months = match unit {
+                "y" => Some(12),
+                "mo" => Some(1),
+                _ => None,
+            };
+            match unit_months {
+                Some(unit_months) => {
+                    months = value
+                        .parse::<u32>()
+                        .ok()
+                        .and_then(|value| value.checked_mul(unit_months))
+                        .and_then(|value| months.checked_add(value))
+                        .ok_or(ParsePeriodError("period out of range"))?;
+                }
+                None => duration.push_str(&rest[..digits + units]),
+            }
+            rest = &rest[digits + units..];
+        }
+
+        let duration = match duration.as_str() {
+            "" => Duration::from(std::time::Duration::ZERO),
+            duration => duration.parse()?,
+        };
+        Ok(Self { months, duration })
+    }
+}
+
+impl std::fmt::Display for Period {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let (years, months) = (self.months / 12, self.months % 12);
+        if years > 0 {
+            write!(f, "{years}y")?;
+        }
+        if months > 0 {
+            write!(f, "{months}mo")?;
+        }
+        if self.months == 0 || !self.duration.as_std().is_zero() {
+            write!(f, "{}", self.duration)?;
+        }
+        Ok(())
+    }
+}
+
+/// Error of parsing the period literal.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParsePeriodError(&'static str);
+
+impl From<ParseDurationError> for ParsePeriodError {
+    fn from(error: ParseDurationError) -> Self {
+        Self(error.0)
+    }
+}
+
+impl std::fmt::Display for ParsePeriodError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.0)
+    }
+}
+
+impl std::error::Error for ParsePeriodError {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_period() {
+        let period = "1y6mo2d".parse::<Period>().unwrap();
+
+        assert_eq!(period.months(), 18);
+        assert_eq!(period.duration().as_secs(), 2 * 24 * 3600);
+        assert_eq!("18y".parse::<Period>().unwrap(), Period::years(18));
+        assert_eq!(
+            "90m".parse::<Period>().unwrap().duration().as_secs(),
+            90 * 60
+        );
+        assert!("".parse::<Period>().is_err());
+        assert!("18".parse::<Period>().is_err());
+        assert!("y".parse::<Period>().is_err());
+        assert!("1x".parse::<Period>().is_err());
+    }
+
+    #[test]
+    fn test_display_period() {
+        assert_eq!("18y".parse::<Period>().unwrap().to_string(), "18y");
+        assert_eq!("18mo".parse::<Period>().unwrap().to_string(), "1y6mo");
+        assert_eq!("1y30d".parse::<Period>().unwrap().to_string(), "1y30d");
+        assert_eq!("2w".parse::<Period>().unwrap().to_string(), "14d");
+        assert_eq!(Period::from(std::time::Duration::ZERO).to_string(), "0s");
+    }
+}
@@ -0,0 +1,51 @@
+use crate::{Date, DateTime, NaiveDateTime, Number};
+
+/// Limit of the range validations, like `minimum` and `exclusive_maximum`.
+#[derive(Debug, Copy, Clone, PartialEq, PartialOrd)]
+pub enum RangeLimit {
+    Number(Number),
+    /// Limit of the date, used by the `chrono` and `time` features.
+    Date(Date),
+    /// Limit of the date and time without the offset, used by the `chrono` and `time` features.
+    NaiveDateTime(NaiveDateTime),
+    /// Limit of the date and time with the offset, used by the `chrono` and `time` features.
+    DateTime(DateTime),
+}
+
+impl std::fmt::Display for RangeLimit {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            RangeLimit::Number(number) => write!(f, "{number}"),
+            RangeLimit::Date(date) => write!(f, "{date}"),
+            RangeLimit::NaiveDateTime(date_time) => write!(f, "{date_time}"),
+            RangeLimit::DateTime(date_time) => write!(f, "{date_time}"),
+        }
+    }
+}
+
+impl<T> From<T> for RangeLimit
+where
+    T: Into<Number>,
+{
+    fn from(number: T) -> Self {
+        RangeLimit::Number(number.into())
+    }
+}
+
+impl From<Date> for RangeLimit {
+    fn from(date: Date) -> Self {
+        RangeLimit::Date(date)
+    }
+}
+
+impl From<NaiveDateTime> for RangeLimit {
+    fn from(date_time: NaiveDateTime) -> Self {
+        RangeLimit::NaiveDateTime(date_time)
+    }
+}
+
+impl From<DateTime> for RangeLimit {
+    fn from(date_time: DateTime) -> Self {
+        RangeLimit::DateTime(date_time)
+    }
+}